@@ -1,3 +1,5 @@
+use crate::events::{AccessControlEvents, Events};
+use crate::role::Role;
 use soroban_sdk::{contracterror, contracttype, panic_with_error, Address, Env};
 use utils::bump::bump_instance;
 
@@ -7,17 +9,23 @@ use utils::bump::bump_instance;
 pub enum AccessControlError {
     AdminNotFound = 101,
     UserNotAdmin = 102,
+    UserHasNoRole = 103,
 }
 
 #[derive(Clone)]
 #[contracttype]
-enum DataKey {
+pub(crate) enum DataKey {
     Admin,
     FutureAdmin,
+    RoleMember(Role, Address),
+    TransferOwnershipDeadline,
+    TransferDelay,
+    // decreased delay along with timestamp it takes effect at
+    FutureTransferDelay,
 }
 
 #[derive(Clone)]
-pub struct AccessControl(Env);
+pub struct AccessControl(pub(crate) Env);
 
 impl AccessControl {
     pub fn new(env: &Env) -> AccessControl {
@@ -34,6 +42,11 @@ pub trait AccessControlTrait {
     fn get_future_admin(&self) -> Option<Address>;
     fn set_future_admin(&self, admin: &Address);
     fn perform_admin_check(&self) -> Result<Address, AccessControlError>;
+    fn has_role(&self, role: Role, user: &Address) -> bool;
+    fn grant_role(&self, role: Role, user: &Address);
+    fn revoke_role(&self, role: Role, user: &Address);
+    fn check_role(&self, role: Role, user: &Address);
+    fn require_role(&self, role: Role, user: &Address);
 }
 
 impl AccessControlTrait for AccessControl {
@@ -87,4 +100,54 @@ impl AccessControlTrait for AccessControl {
         }
         self.get_admin().ok_or(AccessControlError::AdminNotFound)
     }
+
+    fn has_role(&self, role: Role, user: &Address) -> bool {
+        if self.get_admin() == Some(user.clone()) {
+            return true;
+        }
+        if role == Role::Owner {
+            return false;
+        }
+        bump_instance(&self.0);
+        self.0
+            .storage()
+            .instance()
+            .has(&DataKey::RoleMember(role, user.clone()))
+    }
+
+    fn grant_role(&self, role: Role, user: &Address) {
+        if role == Role::Owner {
+            panic!("owner role can't be granted")
+        }
+        bump_instance(&self.0);
+        self.0
+            .storage()
+            .instance()
+            .set(&DataKey::RoleMember(role, user.clone()), &true);
+        Events::new(&self.0).role_granted(role, user.clone());
+    }
+
+    fn revoke_role(&self, role: Role, user: &Address) {
+        if role == Role::Owner {
+            panic!("owner role can't be revoked")
+        }
+        bump_instance(&self.0);
+        self.0
+            .storage()
+            .instance()
+            .remove(&DataKey::RoleMember(role, user.clone()));
+        Events::new(&self.0).role_revoked(role, user.clone());
+    }
+
+    fn check_role(&self, role: Role, user: &Address) {
+        self.perform_admin_check().expect("Cant check role");
+        if !self.has_role(role, user) {
+            panic_with_error!(&self.0, AccessControlError::UserHasNoRole);
+        }
+    }
+
+    fn require_role(&self, role: Role, user: &Address) {
+        user.require_auth();
+        self.check_role(role, user);
+    }
 }
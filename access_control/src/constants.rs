@@ -0,0 +1,2 @@
+pub const DEFAULT_TRANSFER_DELAY: u64 = 3 * 86400; // admin transfer can be applied after 3 days
+pub const MIN_TRANSFER_DELAY: u64 = 86400; // admin transfer delay can't be lower than 1 day
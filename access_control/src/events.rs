@@ -0,0 +1,80 @@
+use crate::role::Role;
+use soroban_sdk::{Address, Env, Symbol};
+
+#[derive(Clone)]
+pub(crate) struct Events(Env);
+
+impl Events {
+    #[inline(always)]
+    pub(crate) fn env(&self) -> &Env {
+        &self.0
+    }
+
+    #[inline(always)]
+    pub(crate) fn new(env: &Env) -> Events {
+        Events(env.clone())
+    }
+}
+
+pub(crate) trait AccessControlEvents {
+    fn role_granted(&self, role: Role, user: Address);
+
+    fn role_revoked(&self, role: Role, user: Address);
+
+    fn commit_transfer_ownership(&self, new_admin: Address, deadline: u64);
+
+    fn apply_transfer_ownership(&self, new_admin: Address);
+
+    fn revert_transfer_ownership(&self);
+
+    fn set_transfer_delay(&self, delay: u64);
+
+    fn commit_transfer_delay(&self, delay: u64, effective_at: u64);
+}
+
+impl AccessControlEvents for Events {
+    fn role_granted(&self, role: Role, user: Address) {
+        self.env()
+            .events()
+            .publish((Symbol::new(self.env(), "role_granted"), role), (user,));
+    }
+
+    fn role_revoked(&self, role: Role, user: Address) {
+        self.env()
+            .events()
+            .publish((Symbol::new(self.env(), "role_revoked"), role), (user,));
+    }
+
+    fn commit_transfer_ownership(&self, new_admin: Address, deadline: u64) {
+        self.env().events().publish(
+            (Symbol::new(self.env(), "commit_transfer_ownership"),),
+            (new_admin, deadline),
+        );
+    }
+
+    fn apply_transfer_ownership(&self, new_admin: Address) {
+        self.env().events().publish(
+            (Symbol::new(self.env(), "apply_transfer_ownership"),),
+            (new_admin,),
+        );
+    }
+
+    fn revert_transfer_ownership(&self) {
+        self.env()
+            .events()
+            .publish((Symbol::new(self.env(), "revert_transfer_ownership"),), ());
+    }
+
+    fn set_transfer_delay(&self, delay: u64) {
+        self.env()
+            .events()
+            .publish((Symbol::new(self.env(), "set_transfer_delay"),), (delay,));
+    }
+
+    fn commit_transfer_delay(&self, delay: u64, effective_at: u64) {
+        self.env().events().publish(
+            (Symbol::new(self.env(), "commit_transfer_delay"),),
+            (delay, effective_at),
+        );
+    }
+}
@@ -0,0 +1,197 @@
+use crate::access::{AccessControl, AccessControlTrait};
+use crate::role::Role;
+use crate::transfer::TransferOwnershipTrait;
+use soroban_sdk::{Address, Env};
+
+// ownership transfer entrypoints, implemented with generate_transferable_contract!
+pub trait TransferableContract {
+    // Commit ownership transfer to new admin. It can be applied once transfer delay passes
+    fn commit_transfer_ownership(e: Env, admin: Address, new_admin: Address);
+
+    // Apply committed ownership transfer
+    fn apply_transfer_ownership(e: Env, admin: Address);
+
+    // Cancel committed ownership transfer
+    fn revert_transfer_ownership(e: Env, admin: Address);
+
+    // Get address ownership is being transferred to, if there is active transfer
+    fn get_future_admin(e: Env) -> Option<Address>;
+
+    // Get timestamp after which committed transfer can be applied. Zero if there is no active transfer
+    fn get_transfer_ownership_deadline(e: Env) -> u64;
+
+    // Set delay between ownership transfer commit and apply, one day at least.
+    // Decreased delay takes effect once the current delay has passed
+    fn set_transfer_delay(e: Env, admin: Address, delay: u64);
+
+    // Get delay between ownership transfer commit and apply
+    fn get_transfer_delay(e: Env) -> u64;
+}
+
+// roles entrypoints, implemented with generate_roles_interface!
+pub trait RolesInterface {
+    // Grant role to user. Only owner is able to grant roles
+    fn grant_role(e: Env, admin: Address, role: Role, user: Address);
+
+    // Revoke role from user. Only owner is able to revoke roles
+    fn revoke_role(e: Env, admin: Address, role: Role, user: Address);
+
+    // Give up own role
+    fn renounce_role(e: Env, user: Address, role: Role);
+
+    // Check if user has role. Owner has every role
+    fn has_role(e: Env, role: Role, user: Address) -> bool;
+}
+
+// shared entrypoint bodies for TransferableContract & RolesInterface implementations
+
+pub fn commit_transfer_ownership(e: &Env, admin: &Address, new_admin: &Address) {
+    admin.require_auth();
+    let access_control = AccessControl::new(e);
+    access_control.check_admin(admin);
+    access_control.commit_transfer(new_admin);
+}
+
+pub fn apply_transfer_ownership(e: &Env, admin: &Address) {
+    admin.require_auth();
+    let access_control = AccessControl::new(e);
+    access_control.check_admin(admin);
+    access_control.apply_transfer();
+}
+
+pub fn revert_transfer_ownership(e: &Env, admin: &Address) {
+    admin.require_auth();
+    let access_control = AccessControl::new(e);
+    access_control.check_admin(admin);
+    access_control.revert_transfer();
+}
+
+pub fn get_future_admin(e: &Env) -> Option<Address> {
+    let access_control = AccessControl::new(e);
+    match access_control.get_transfer_ownership_deadline() {
+        0 => None,
+        _ => access_control.get_future_admin(),
+    }
+}
+
+pub fn get_transfer_ownership_deadline(e: &Env) -> u64 {
+    AccessControl::new(e).get_transfer_ownership_deadline()
+}
+
+pub fn set_transfer_delay(e: &Env, admin: &Address, delay: u64) {
+    admin.require_auth();
+    let access_control = AccessControl::new(e);
+    access_control.check_admin(admin);
+    access_control.set_transfer_delay(delay);
+}
+
+pub fn get_transfer_delay(e: &Env) -> u64 {
+    AccessControl::new(e).get_transfer_delay()
+}
+
+pub fn grant_role(e: &Env, admin: &Address, role: Role, user: &Address) {
+    let access_control = AccessControl::new(e);
+    admin.require_auth();
+    access_control.check_admin(admin);
+    access_control.grant_role(role, user);
+}
+
+pub fn revoke_role(e: &Env, admin: &Address, role: Role, user: &Address) {
+    let access_control = AccessControl::new(e);
+    admin.require_auth();
+    access_control.check_admin(admin);
+    access_control.revoke_role(role, user);
+}
+
+pub fn renounce_role(e: &Env, user: &Address, role: Role) {
+    let access_control = AccessControl::new(e);
+    user.require_auth();
+    access_control.check_role(role, user);
+    access_control.revoke_role(role, user);
+}
+
+pub fn has_role(e: &Env, role: Role, user: &Address) -> bool {
+    AccessControl::new(e).has_role(role, user)
+}
+
+#[macro_export]
+macro_rules! generate_transferable_contract {
+    ($contract:ident) => {
+        #[soroban_sdk::contractimpl]
+        impl $crate::interface::TransferableContract for $contract {
+            fn commit_transfer_ownership(
+                e: soroban_sdk::Env,
+                admin: soroban_sdk::Address,
+                new_admin: soroban_sdk::Address,
+            ) {
+                $crate::interface::commit_transfer_ownership(&e, &admin, &new_admin)
+            }
+
+            fn apply_transfer_ownership(e: soroban_sdk::Env, admin: soroban_sdk::Address) {
+                $crate::interface::apply_transfer_ownership(&e, &admin)
+            }
+
+            fn revert_transfer_ownership(e: soroban_sdk::Env, admin: soroban_sdk::Address) {
+                $crate::interface::revert_transfer_ownership(&e, &admin)
+            }
+
+            fn get_future_admin(e: soroban_sdk::Env) -> Option<soroban_sdk::Address> {
+                $crate::interface::get_future_admin(&e)
+            }
+
+            fn get_transfer_ownership_deadline(e: soroban_sdk::Env) -> u64 {
+                $crate::interface::get_transfer_ownership_deadline(&e)
+            }
+
+            fn set_transfer_delay(e: soroban_sdk::Env, admin: soroban_sdk::Address, delay: u64) {
+                $crate::interface::set_transfer_delay(&e, &admin, delay)
+            }
+
+            fn get_transfer_delay(e: soroban_sdk::Env) -> u64 {
+                $crate::interface::get_transfer_delay(&e)
+            }
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! generate_roles_interface {
+    ($contract:ident) => {
+        #[soroban_sdk::contractimpl]
+        impl $crate::interface::RolesInterface for $contract {
+            fn grant_role(
+                e: soroban_sdk::Env,
+                admin: soroban_sdk::Address,
+                role: $crate::role::Role,
+                user: soroban_sdk::Address,
+            ) {
+                $crate::interface::grant_role(&e, &admin, role, &user)
+            }
+
+            fn revoke_role(
+                e: soroban_sdk::Env,
+                admin: soroban_sdk::Address,
+                role: $crate::role::Role,
+                user: soroban_sdk::Address,
+            ) {
+                $crate::interface::revoke_role(&e, &admin, role, &user)
+            }
+
+            fn renounce_role(
+                e: soroban_sdk::Env,
+                user: soroban_sdk::Address,
+                role: $crate::role::Role,
+            ) {
+                $crate::interface::renounce_role(&e, &user, role)
+            }
+
+            fn has_role(
+                e: soroban_sdk::Env,
+                role: $crate::role::Role,
+                user: soroban_sdk::Address,
+            ) -> bool {
+                $crate::interface::has_role(&e, role, &user)
+            }
+        }
+    };
+}
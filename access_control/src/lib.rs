@@ -1,3 +1,8 @@
 #![no_std]
 
 pub mod access;
+pub mod constants;
+mod events;
+pub mod interface;
+pub mod role;
+pub mod transfer;
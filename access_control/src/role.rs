@@ -0,0 +1,14 @@
+use soroban_sdk::contracttype;
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum Role {
+    // Contract admin. Has every other role implicitly and can't be granted
+    Owner = 0,
+    RewardsAdmin = 1,
+    OperationsAdmin = 2,
+    EmergencyPauseAdmin = 3,
+    // Able to queue and cancel timelock operations
+    Proposer = 4,
+}
@@ -0,0 +1,118 @@
+use crate::access::{AccessControl, AccessControlTrait, DataKey};
+use crate::constants::{DEFAULT_TRANSFER_DELAY, MIN_TRANSFER_DELAY};
+use crate::events::{AccessControlEvents, Events};
+use soroban_sdk::Address;
+use utils::bump::bump_instance;
+
+pub trait TransferOwnershipTrait {
+    fn get_transfer_delay(&self) -> u64;
+    fn get_future_transfer_delay(&self) -> Option<(u64, u64)>;
+    fn set_transfer_delay(&self, delay: u64);
+    fn get_transfer_ownership_deadline(&self) -> u64;
+    fn commit_transfer(&self, new_admin: &Address);
+    fn apply_transfer(&self) -> Address;
+    fn revert_transfer(&self);
+}
+
+impl TransferOwnershipTrait for AccessControl {
+    fn get_transfer_delay(&self) -> u64 {
+        // decreased delay takes effect once previous delay has passed
+        if let Some((delay, effective_at)) = self.get_future_transfer_delay() {
+            if self.0.ledger().timestamp() >= effective_at {
+                return delay;
+            }
+        }
+        bump_instance(&self.0);
+        self.0
+            .storage()
+            .instance()
+            .get(&DataKey::TransferDelay)
+            .unwrap_or(DEFAULT_TRANSFER_DELAY)
+    }
+
+    fn get_future_transfer_delay(&self) -> Option<(u64, u64)> {
+        bump_instance(&self.0);
+        self.0
+            .storage()
+            .instance()
+            .get(&DataKey::FutureTransferDelay)
+    }
+
+    // delay increase is applied immediately, decrease has to wait out the current delay
+    fn set_transfer_delay(&self, delay: u64) {
+        if self.get_transfer_ownership_deadline() != 0 {
+            panic!("active transfer")
+        }
+        if delay < MIN_TRANSFER_DELAY {
+            panic!("transfer delay is too small")
+        }
+
+        let current_delay = self.get_transfer_delay();
+        bump_instance(&self.0);
+        let storage = self.0.storage().instance();
+        storage.set(&DataKey::TransferDelay, &current_delay);
+        storage.remove(&DataKey::FutureTransferDelay);
+        if delay >= current_delay {
+            storage.set(&DataKey::TransferDelay, &delay);
+            Events::new(&self.0).set_transfer_delay(delay);
+        } else {
+            let effective_at = self.0.ledger().timestamp() + current_delay;
+            storage.set(&DataKey::FutureTransferDelay, &(delay, effective_at));
+            Events::new(&self.0).commit_transfer_delay(delay, effective_at);
+        }
+    }
+
+    fn get_transfer_ownership_deadline(&self) -> u64 {
+        bump_instance(&self.0);
+        self.0
+            .storage()
+            .instance()
+            .get(&DataKey::TransferOwnershipDeadline)
+            .unwrap_or(0)
+    }
+
+    fn commit_transfer(&self, new_admin: &Address) {
+        if self.get_transfer_ownership_deadline() != 0 {
+            panic!("active transfer")
+        }
+
+        let deadline = self.0.ledger().timestamp() + self.get_transfer_delay();
+        self.0
+            .storage()
+            .instance()
+            .set(&DataKey::TransferOwnershipDeadline, &deadline);
+        self.set_future_admin(new_admin);
+        Events::new(&self.0).commit_transfer_ownership(new_admin.clone(), deadline);
+    }
+
+    fn apply_transfer(&self) -> Address {
+        let deadline = self.get_transfer_ownership_deadline();
+        if self.0.ledger().timestamp() < deadline {
+            panic!("insufficient time")
+        }
+        if deadline == 0 {
+            panic!("no active transfer")
+        }
+
+        self.0
+            .storage()
+            .instance()
+            .set(&DataKey::TransferOwnershipDeadline, &0_u64);
+        let future_admin = self.get_future_admin().expect("Try get future admin");
+        self.set_admin(&future_admin);
+        Events::new(&self.0).apply_transfer_ownership(future_admin.clone());
+        future_admin
+    }
+
+    fn revert_transfer(&self) {
+        if self.get_transfer_ownership_deadline() == 0 {
+            panic!("no active transfer")
+        }
+        bump_instance(&self.0);
+        self.0
+            .storage()
+            .instance()
+            .set(&DataKey::TransferOwnershipDeadline, &0_u64);
+        Events::new(&self.0).revert_transfer_ownership();
+    }
+}
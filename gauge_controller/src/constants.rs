@@ -0,0 +1,4 @@
+// user voting power is split across gauges in basis points
+pub(crate) const MAX_VOTE_WEIGHT: u32 = 10_000;
+// maximum amount of active gauges, limited by distribution budget
+pub(crate) const MAX_GAUGES: u32 = 20;
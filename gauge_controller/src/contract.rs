@@ -0,0 +1,216 @@
+use crate::constants::{MAX_GAUGES, MAX_VOTE_WEIGHT};
+use crate::events::{Events, GaugeControllerEvents};
+use crate::interface::GaugeControllerInterface;
+use crate::router::{PoolState, RouterClient};
+use crate::storage::{
+    get_active_gauges, get_emission, get_epoch_duration, get_gauge, get_gauge_weight,
+    get_gauges_count, get_router, get_user_votes, get_voting_escrow, has_router, is_distributed,
+    set_active_gauges, set_distributed, set_emission, set_epoch_duration, set_gauge,
+    set_gauge_weight, set_gauges_count, set_router, set_user_votes, set_voting_escrow, Gauge,
+};
+use access_control::access::{AccessControl, AccessControlTrait};
+use access_control::interface::{RolesInterface, TransferableContract};
+use access_control::role::Role;
+use rewards::VotingEscrowClient;
+use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, Vec};
+
+#[contract]
+pub struct GaugeController;
+
+#[contractimpl]
+impl GaugeControllerInterface for GaugeController {
+    fn initialize(
+        e: Env,
+        admin: Address,
+        router: Address,
+        voting_escrow: Address,
+        epoch_duration: u64,
+        emission: u128,
+    ) {
+        if has_router(&e) {
+            panic!("already initialized")
+        }
+        if epoch_duration == 0 {
+            panic!("epoch duration should be positive")
+        }
+
+        let access_control = AccessControl::new(&e);
+        access_control.set_admin(&admin);
+        set_router(&e, &router);
+        set_voting_escrow(&e, &voting_escrow);
+        set_epoch_duration(&e, &epoch_duration);
+        set_emission(&e, &emission);
+    }
+
+    fn add_gauge(e: Env, admin: Address, tokens: Vec<Address>, pool_index: BytesN<32>) -> u32 {
+        let access_control = AccessControl::new(&e);
+        access_control.require_role(Role::RewardsAdmin, &admin);
+
+        let mut active_gauges = get_active_gauges(&e);
+        if active_gauges.len() >= MAX_GAUGES {
+            panic!("too many gauges")
+        }
+        let gauge = Gauge { tokens, pool_index };
+        for id in active_gauges.iter() {
+            if get_gauge(&e, id).unwrap() == gauge {
+                panic!("gauge already exists")
+            }
+        }
+
+        let id = get_gauges_count(&e);
+        set_gauge(&e, id, &gauge);
+        set_gauges_count(&e, &(id + 1));
+        active_gauges.push_back(id);
+        set_active_gauges(&e, &active_gauges);
+
+        Events::new(&e).add_gauge(id, gauge.tokens, gauge.pool_index);
+        id
+    }
+
+    fn remove_gauge(e: Env, admin: Address, id: u32) {
+        let access_control = AccessControl::new(&e);
+        access_control.require_role(Role::RewardsAdmin, &admin);
+
+        let mut active_gauges = get_active_gauges(&e);
+        let index = active_gauges
+            .first_index_of(id)
+            .expect("gauge is not active");
+        active_gauges.remove(index);
+        set_active_gauges(&e, &active_gauges);
+
+        Events::new(&e).remove_gauge(id);
+    }
+
+    fn set_emission(e: Env, admin: Address, emission: u128) {
+        let access_control = AccessControl::new(&e);
+        access_control.require_role(Role::RewardsAdmin, &admin);
+
+        set_emission(&e, &emission);
+        Events::new(&e).set_emission(emission);
+    }
+
+    fn vote(e: Env, user: Address, votes: Vec<(u32, u32)>) {
+        user.require_auth();
+
+        let epoch = Self::get_epoch(e.clone());
+        let active_gauges = get_active_gauges(&e);
+        let mut total_weight = 0;
+        for (id, weight) in votes.iter() {
+            if !active_gauges.contains(id) {
+                panic!("gauge is not active")
+            }
+            total_weight += weight;
+        }
+        if total_weight > MAX_VOTE_WEIGHT {
+            panic!("total vote weight exceeds 100%")
+        }
+
+        // revoke previous votes of the epoch
+        for (id, power) in get_user_votes(&e, epoch, &user).iter() {
+            set_gauge_weight(&e, epoch, id, get_gauge_weight(&e, epoch, id) - power);
+        }
+
+        let voting_power = VotingEscrowClient::new(&e, &get_voting_escrow(&e)).balance(&user);
+        let mut applied_votes = Vec::new(&e);
+        for (id, weight) in votes.iter() {
+            let power = voting_power * weight as u128 / MAX_VOTE_WEIGHT as u128;
+            set_gauge_weight(&e, epoch, id, get_gauge_weight(&e, epoch, id) + power);
+            applied_votes.push_back((id, power));
+        }
+        set_user_votes(&e, epoch, &user, &applied_votes);
+
+        Events::new(&e).vote(user, epoch, applied_votes);
+    }
+
+    fn distribute(e: Env, epoch: u64) {
+        let current_epoch = Self::get_epoch(e.clone());
+        if epoch >= current_epoch {
+            panic!("epoch voting is not finished")
+        }
+        // rewards of older epochs would overwrite configuration of the one being distributed
+        if epoch + 1 != current_epoch {
+            panic!("epoch distribution period is over")
+        }
+        if is_distributed(&e, epoch) {
+            panic!("epoch already distributed")
+        }
+        set_distributed(&e, epoch);
+
+        // gauges of deprecated or removed pools don't receive emission
+        let router = RouterClient::new(&e, &get_router(&e));
+        let mut gauges = Vec::new(&e);
+        let mut total_weight = 0;
+        for id in get_active_gauges(&e).iter() {
+            let weight = get_gauge_weight(&e, epoch, id);
+            if weight == 0 {
+                continue;
+            }
+            let gauge = get_gauge(&e, id).unwrap();
+            match router.try_get_pool_state(&gauge.tokens, &gauge.pool_index) {
+                Ok(Ok(PoolState::Active)) => {
+                    gauges.push_back((id, gauge, weight));
+                    total_weight += weight;
+                }
+                _ => Events::new(&e).skip_gauge(epoch, id),
+            }
+        }
+        if total_weight == 0 {
+            return;
+        }
+
+        // rewards are distributed during the epoch following the voting one
+        let epoch_duration = get_epoch_duration(&e);
+        let expired_at = (epoch + 2) * epoch_duration;
+        let emission = get_emission(&e);
+        for (id, gauge, weight) in gauges.iter() {
+            let tps = emission * weight / total_weight / epoch_duration as u128;
+            // failing gauge, e.g. pool without enough funds, shouldn't block the others
+            match router.try_set_rewards_config(
+                &e.current_contract_address(),
+                &gauge.tokens,
+                &gauge.pool_index,
+                &expired_at,
+                &tps,
+            ) {
+                Ok(_) => Events::new(&e).distribute(epoch, id, tps, expired_at),
+                Err(_) => Events::new(&e).distribute_failed(epoch, id, tps),
+            }
+        }
+    }
+
+    fn get_epoch(e: Env) -> u64 {
+        e.ledger().timestamp() / get_epoch_duration(&e)
+    }
+
+    fn get_epoch_duration(e: Env) -> u64 {
+        get_epoch_duration(&e)
+    }
+
+    fn get_emission(e: Env) -> u128 {
+        get_emission(&e)
+    }
+
+    fn get_gauge(e: Env, id: u32) -> Gauge {
+        get_gauge(&e, id).expect("gauge not found")
+    }
+
+    fn get_gauges(e: Env) -> Vec<u32> {
+        get_active_gauges(&e)
+    }
+
+    fn get_gauge_weight(e: Env, epoch: u64, id: u32) -> u128 {
+        get_gauge_weight(&e, epoch, id)
+    }
+
+    fn get_user_votes(e: Env, user: Address, epoch: u64) -> Vec<(u32, u128)> {
+        get_user_votes(&e, epoch, &user)
+    }
+
+    fn is_distributed(e: Env, epoch: u64) -> bool {
+        is_distributed(&e, epoch)
+    }
+}
+
+access_control::generate_transferable_contract!(GaugeController);
+
+access_control::generate_roles_interface!(GaugeController);
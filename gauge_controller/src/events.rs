@@ -0,0 +1,79 @@
+use soroban_sdk::{Address, BytesN, Env, Symbol, Vec};
+
+#[derive(Clone)]
+pub(crate) struct Events(Env);
+
+impl Events {
+    #[inline(always)]
+    pub(crate) fn env(&self) -> &Env {
+        &self.0
+    }
+
+    #[inline(always)]
+    pub(crate) fn new(env: &Env) -> Events {
+        Events(env.clone())
+    }
+}
+
+pub(crate) trait GaugeControllerEvents {
+    fn add_gauge(&self, id: u32, tokens: Vec<Address>, pool_index: BytesN<32>);
+
+    fn remove_gauge(&self, id: u32);
+
+    fn set_emission(&self, emission: u128);
+
+    fn vote(&self, user: Address, epoch: u64, votes: Vec<(u32, u128)>);
+
+    fn distribute(&self, epoch: u64, id: u32, tps: u128, expired_at: u64);
+
+    fn distribute_failed(&self, epoch: u64, id: u32, tps: u128);
+
+    fn skip_gauge(&self, epoch: u64, id: u32);
+}
+
+impl GaugeControllerEvents for Events {
+    fn add_gauge(&self, id: u32, tokens: Vec<Address>, pool_index: BytesN<32>) {
+        self.env().events().publish(
+            (Symbol::new(self.env(), "add_gauge"), tokens),
+            (id, pool_index),
+        );
+    }
+
+    fn remove_gauge(&self, id: u32) {
+        self.env()
+            .events()
+            .publish((Symbol::new(self.env(), "remove_gauge"),), (id,));
+    }
+
+    fn set_emission(&self, emission: u128) {
+        self.env()
+            .events()
+            .publish((Symbol::new(self.env(), "set_emission"),), (emission,));
+    }
+
+    fn vote(&self, user: Address, epoch: u64, votes: Vec<(u32, u128)>) {
+        self.env()
+            .events()
+            .publish((Symbol::new(self.env(), "vote"), user), (epoch, votes));
+    }
+
+    fn distribute(&self, epoch: u64, id: u32, tps: u128, expired_at: u64) {
+        self.env().events().publish(
+            (Symbol::new(self.env(), "distribute"), epoch),
+            (id, tps, expired_at),
+        );
+    }
+
+    fn distribute_failed(&self, epoch: u64, id: u32, tps: u128) {
+        self.env().events().publish(
+            (Symbol::new(self.env(), "distribute_failed"), epoch),
+            (id, tps),
+        );
+    }
+
+    fn skip_gauge(&self, epoch: u64, id: u32) {
+        self.env()
+            .events()
+            .publish((Symbol::new(self.env(), "skip_gauge"), epoch), (id,));
+    }
+}
@@ -0,0 +1,68 @@
+use crate::storage::Gauge;
+use soroban_sdk::{Address, BytesN, Env, Vec};
+
+pub trait GaugeControllerInterface {
+    // Initialize controller. Will panic if called twice
+    // router: liquidity pool router, controller should have rewards admin role there
+    // voting_escrow: contract providing users voting power
+    // epoch_duration: length of voting epoch in seconds
+    // emission: amount of reward tokens distributed across all gauges every epoch
+    fn initialize(
+        e: Env,
+        admin: Address,
+        router: Address,
+        voting_escrow: Address,
+        epoch_duration: u64,
+        emission: u128,
+    );
+
+    // Register pool as gauge users are able to vote for. Only rewards admin
+    // Returns gauge id
+    fn add_gauge(e: Env, admin: Address, tokens: Vec<Address>, pool_index: BytesN<32>) -> u32;
+
+    // Exclude gauge from voting and future distributions. Only rewards admin
+    fn remove_gauge(e: Env, admin: Address, id: u32);
+
+    // Set amount of reward tokens distributed every epoch. Only rewards admin
+    fn set_emission(e: Env, admin: Address, emission: u128);
+
+    // Allocate user voting power across gauges for current epoch.
+    // votes: list of (gauge id, weight) pairs, weights are in basis points and should not exceed 10000 in total
+    // Voting power is taken at the moment of voting. Voting again during the same epoch
+    // replaces previous votes and refreshes voting power
+    fn vote(e: Env, user: Address, votes: Vec<(u32, u32)>);
+
+    // Configure rewards of voted pools according to votes of finished epoch.
+    // Every gauge receives share of emission proportional to its votes,
+    // distributed evenly during the next epoch. Callable by anyone once per epoch,
+    // only during the epoch following the voting one. Epochs not distributed in time are skipped.
+    // Gauges of deprecated or removed pools are skipped and their share goes to the others.
+    // Gauge failing to configure rewards, e.g. pool not funded for emission, doesn't block
+    // the others and is reported with distribute_failed event.
+    // Controller should have rewards admin role in the router
+    fn distribute(e: Env, epoch: u64);
+
+    // Get current epoch number
+    fn get_epoch(e: Env) -> u64;
+
+    // Get epoch length in seconds
+    fn get_epoch_duration(e: Env) -> u64;
+
+    // Get amount of reward tokens distributed every epoch
+    fn get_emission(e: Env) -> u128;
+
+    // Get gauge pool
+    fn get_gauge(e: Env, id: u32) -> Gauge;
+
+    // Get ids of gauges available for voting
+    fn get_gauges(e: Env) -> Vec<u32>;
+
+    // Get votes given to the gauge during epoch
+    fn get_gauge_weight(e: Env, epoch: u64, id: u32) -> u128;
+
+    // Get user votes of epoch: list of (gauge id, voting power) pairs
+    fn get_user_votes(e: Env, user: Address, epoch: u64) -> Vec<(u32, u128)>;
+
+    // Check if epoch votes have been distributed
+    fn is_distributed(e: Env, epoch: u64) -> bool;
+}
@@ -0,0 +1,11 @@
+#![no_std]
+
+mod constants;
+mod contract;
+mod events;
+mod interface;
+mod router;
+mod storage;
+mod test;
+
+pub use crate::contract::{GaugeController, GaugeControllerClient};
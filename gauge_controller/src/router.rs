@@ -0,0 +1,27 @@
+use soroban_sdk::{contractclient, contracttype, Address, BytesN, Env, Vec};
+
+// Mirror of router pool state
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum PoolState {
+    Active = 0,
+    Deprecated = 1,
+    Removed = 2,
+}
+
+// Part of liquidity pool router interface used to configure pools rewards
+#[allow(dead_code)]
+#[contractclient(name = "RouterClient")]
+pub trait RouterInterface {
+    fn set_rewards_config(
+        e: Env,
+        admin: Address,
+        tokens: Vec<Address>,
+        pool_index: BytesN<32>,
+        expired_at: u64,
+        tps: u128,
+    );
+
+    fn get_pool_state(e: Env, tokens: Vec<Address>, pool_index: BytesN<32>) -> PoolState;
+}
@@ -0,0 +1,111 @@
+use paste::paste;
+use soroban_sdk::{contracttype, Address, BytesN, Env, Vec};
+use utils::bump::{bump_instance, bump_persistent};
+use utils::{
+    generate_instance_storage_getter, generate_instance_storage_getter_and_setter,
+    generate_instance_storage_getter_and_setter_with_default,
+    generate_instance_storage_getter_with_default, generate_instance_storage_setter,
+};
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Gauge {
+    pub tokens: Vec<Address>,
+    pub pool_index: BytesN<32>,
+}
+
+#[derive(Clone)]
+#[contracttype]
+enum DataKey {
+    Router,
+    VotingEscrow,
+    EpochDuration,
+    Emission,
+    GaugesCount,
+    ActiveGauges,
+    Gauge(u32),
+    GaugeWeight(u64, u32),
+    UserVotes(u64, Address),
+    Distributed(u64),
+}
+
+generate_instance_storage_getter_and_setter!(router, DataKey::Router, Address);
+generate_instance_storage_getter_and_setter!(voting_escrow, DataKey::VotingEscrow, Address);
+generate_instance_storage_getter_and_setter!(epoch_duration, DataKey::EpochDuration, u64);
+generate_instance_storage_getter_and_setter!(emission, DataKey::Emission, u128);
+generate_instance_storage_getter_and_setter_with_default!(
+    gauges_count,
+    DataKey::GaugesCount,
+    u32,
+    0
+);
+
+pub fn has_router(e: &Env) -> bool {
+    e.storage().instance().has(&DataKey::Router)
+}
+
+pub fn get_active_gauges(e: &Env) -> Vec<u32> {
+    bump_instance(e);
+    e.storage()
+        .instance()
+        .get(&DataKey::ActiveGauges)
+        .unwrap_or(Vec::new(e))
+}
+
+pub fn set_active_gauges(e: &Env, gauges: &Vec<u32>) {
+    bump_instance(e);
+    e.storage().instance().set(&DataKey::ActiveGauges, gauges)
+}
+
+pub fn get_gauge(e: &Env, id: u32) -> Option<Gauge> {
+    let key = DataKey::Gauge(id);
+    let value = e.storage().persistent().get(&key);
+    if value.is_some() {
+        bump_persistent(e, &key);
+    }
+    value
+}
+
+pub fn set_gauge(e: &Env, id: u32, gauge: &Gauge) {
+    let key = DataKey::Gauge(id);
+    e.storage().persistent().set(&key, gauge);
+    bump_persistent(e, &key);
+}
+
+// total votes given to the gauge during epoch
+pub fn get_gauge_weight(e: &Env, epoch: u64, id: u32) -> u128 {
+    e.storage()
+        .persistent()
+        .get(&DataKey::GaugeWeight(epoch, id))
+        .unwrap_or(0)
+}
+
+pub fn set_gauge_weight(e: &Env, epoch: u64, id: u32, value: u128) {
+    let key = DataKey::GaugeWeight(epoch, id);
+    e.storage().persistent().set(&key, &value);
+    bump_persistent(e, &key);
+}
+
+// votes applied by user during epoch: (gauge id, voting power)
+pub fn get_user_votes(e: &Env, epoch: u64, user: &Address) -> Vec<(u32, u128)> {
+    e.storage()
+        .persistent()
+        .get(&DataKey::UserVotes(epoch, user.clone()))
+        .unwrap_or(Vec::new(e))
+}
+
+pub fn set_user_votes(e: &Env, epoch: u64, user: &Address, votes: &Vec<(u32, u128)>) {
+    let key = DataKey::UserVotes(epoch, user.clone());
+    e.storage().persistent().set(&key, votes);
+    bump_persistent(e, &key);
+}
+
+pub fn is_distributed(e: &Env, epoch: u64) -> bool {
+    e.storage().persistent().has(&DataKey::Distributed(epoch))
+}
+
+pub fn set_distributed(e: &Env, epoch: u64) {
+    let key = DataKey::Distributed(epoch);
+    e.storage().persistent().set(&key, &true);
+    bump_persistent(e, &key);
+}
@@ -0,0 +1,369 @@
+#![cfg(test)]
+extern crate std;
+
+use crate::GaugeControllerClient;
+use soroban_sdk::testutils::{Address as _, Events, Ledger, LedgerInfo};
+use soroban_sdk::token::StellarAssetClient;
+use soroban_sdk::{
+    contract, contractimpl, symbol_short, vec, Address, BytesN, Env, IntoVal, Symbol, Vec,
+};
+
+const WEEK: u64 = 7 * 86400;
+
+mod router {
+    soroban_sdk::contractimport!(
+        file =
+            "../target/wasm32-unknown-unknown/release/soroban_liquidity_pool_router_contract.wasm"
+    );
+}
+
+mod token {
+    soroban_sdk::contractimport!(
+        file = "../target/wasm32-unknown-unknown/release/soroban_token_contract.wasm"
+    );
+}
+
+mod pool {
+    soroban_sdk::contractimport!(
+        file = "../target/wasm32-unknown-unknown/release/soroban_liquidity_pool_contract.wasm"
+    );
+}
+
+mod pool_plane {
+    soroban_sdk::contractimport!(
+        file =
+            "../target/wasm32-unknown-unknown/release/soroban_liquidity_pool_plane_contract.wasm"
+    );
+}
+
+// Vote-escrow mock with directly settable user locks
+#[contract]
+pub struct MockVotingEscrow;
+
+#[contractimpl]
+impl MockVotingEscrow {
+    pub fn set_balance(e: Env, user: Address, amount: u128) {
+        let total = Self::total_supply(e.clone()) - Self::balance(e.clone(), user.clone());
+        e.storage().persistent().set(&user, &amount);
+        e.storage()
+            .instance()
+            .set(&symbol_short!("total"), &(total + amount));
+    }
+
+    pub fn balance(e: Env, user: Address) -> u128 {
+        e.storage().persistent().get(&user).unwrap_or(0)
+    }
+
+    pub fn total_supply(e: Env) -> u128 {
+        e.storage()
+            .instance()
+            .get(&symbol_short!("total"))
+            .unwrap_or(0)
+    }
+}
+
+fn jump(e: &Env, time: u64) {
+    e.ledger().set(LedgerInfo {
+        timestamp: e.ledger().timestamp().saturating_add(time),
+        protocol_version: 20,
+        sequence_number: e.ledger().sequence(),
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: 999999,
+        min_persistent_entry_ttl: 999999,
+        max_entry_ttl: u32::MAX,
+    });
+}
+
+fn create_pool_tokens(e: &Env) -> Vec<Address> {
+    let admin = Address::generate(e);
+    let mut token1 = e.register_stellar_asset_contract(admin.clone());
+    let mut token2 = e.register_stellar_asset_contract(admin);
+    if token2 < token1 {
+        std::mem::swap(&mut token1, &mut token2);
+    }
+    Vec::from_array(e, [token1, token2])
+}
+
+struct Setup<'a> {
+    env: Env,
+    admin: Address,
+    users: [Address; 2],
+    voting_escrow: MockVotingEscrowClient<'a>,
+    router: router::Client<'a>,
+    gauge_controller: GaugeControllerClient<'a>,
+    pools: [(Vec<Address>, BytesN<32>); 2],
+}
+
+impl Default for Setup<'_> {
+    fn default() -> Self {
+        let e = Env::default();
+        e.mock_all_auths();
+        e.budget().reset_unlimited();
+        jump(&e, 1_700_000_000);
+
+        let admin = Address::generate(&e);
+        let users = [Address::generate(&e), Address::generate(&e)];
+
+        let router = router::Client::new(&e, &e.register_contract_wasm(None, router::WASM));
+        router.init_admin(&admin);
+        router.set_pool_hash(&e.deployer().upload_contract_wasm(pool::WASM));
+        router.set_token_hash(&e.deployer().upload_contract_wasm(token::WASM));
+        let reward_token = e.register_stellar_asset_contract(admin.clone());
+        router.set_reward_token(&reward_token);
+        router.set_pools_plane(&admin, &e.register_contract_wasm(None, pool_plane::WASM));
+
+        let tokens_a = create_pool_tokens(&e);
+        let tokens_b = create_pool_tokens(&e);
+        let (index_a, pool_a) = router.init_standard_pool(&admin, &tokens_a, &30);
+        let (index_b, pool_b) = router.init_standard_pool(&admin, &tokens_b, &30);
+        // pools should hold emission they're going to distribute
+        for pool in [pool_a, pool_b] {
+            StellarAssetClient::new(&e, &reward_token).mint(&pool, &(WEEK as i128 * 1000));
+        }
+
+        let voting_escrow =
+            MockVotingEscrowClient::new(&e, &e.register_contract(None, MockVotingEscrow {}));
+        let gauge_controller =
+            GaugeControllerClient::new(&e, &e.register_contract(None, crate::GaugeController {}));
+        // 100 tokens per second for whole epoch
+        gauge_controller.initialize(
+            &admin,
+            &router.address,
+            &voting_escrow.address,
+            &WEEK,
+            &(WEEK as u128 * 100),
+        );
+        router.grant_role(
+            &admin,
+            &router::Role::RewardsAdmin,
+            &gauge_controller.address,
+        );
+        gauge_controller.add_gauge(&admin, &tokens_a, &index_a);
+        gauge_controller.add_gauge(&admin, &tokens_b, &index_b);
+
+        Setup {
+            env: e,
+            admin,
+            users,
+            voting_escrow,
+            router,
+            gauge_controller,
+            pools: [(tokens_a, index_a), (tokens_b, index_b)],
+        }
+    }
+}
+
+fn get_pool_tps(setup: &Setup, pool: usize) -> (u128, u64) {
+    let (tokens, pool_index) = &setup.pools[pool];
+    let info = setup
+        .router
+        .get_rewards_info(&setup.users[0], tokens, pool_index);
+    (info.tps, info.expired_at)
+}
+
+#[test]
+fn test_vote_distribute() {
+    let setup = Setup::default();
+    let e = &setup.env;
+    let gauge_controller = &setup.gauge_controller;
+    let [user1, user2] = &setup.users;
+    assert_eq!(gauge_controller.get_gauges(), Vec::from_array(e, [0, 1]));
+
+    setup.voting_escrow.set_balance(user1, &300);
+    setup.voting_escrow.set_balance(user2, &100);
+    let epoch = gauge_controller.get_epoch();
+    assert_eq!(epoch, 1_700_000_000 / WEEK);
+
+    gauge_controller.vote(user1, &Vec::from_array(e, [(0, 10_000)]));
+    gauge_controller.vote(user2, &Vec::from_array(e, [(0, 5_000), (1, 5_000)]));
+    assert_eq!(
+        vec![e, e.events().all().last().unwrap()],
+        vec![
+            e,
+            (
+                gauge_controller.address.clone(),
+                (Symbol::new(e, "vote"), user2.clone()).into_val(e),
+                (epoch, Vec::from_array(e, [(0_u32, 50_u128), (1, 50)])).into_val(e),
+            )
+        ]
+    );
+    assert_eq!(gauge_controller.get_gauge_weight(&epoch, &0), 350);
+    assert_eq!(gauge_controller.get_gauge_weight(&epoch, &1), 50);
+    assert_eq!(
+        gauge_controller.get_user_votes(user1, &epoch),
+        Vec::from_array(e, [(0, 300)])
+    );
+
+    // voting is still in progress
+    assert!(gauge_controller.try_distribute(&epoch).is_err());
+
+    jump(e, WEEK);
+    gauge_controller.distribute(&epoch);
+    assert!(gauge_controller.is_distributed(&epoch));
+
+    let expired_at = (epoch + 2) * WEEK;
+    assert_eq!(get_pool_tps(&setup, 0), (100 * 350 / 400, expired_at));
+    assert_eq!(get_pool_tps(&setup, 1), (100 * 50 / 400, expired_at));
+
+    // epoch is distributed only once
+    assert!(gauge_controller.try_distribute(&epoch).is_err());
+}
+
+#[test]
+fn test_distribute_late() {
+    let setup = Setup::default();
+    let e = &setup.env;
+    let gauge_controller = &setup.gauge_controller;
+    let user1 = &setup.users[0];
+    setup.voting_escrow.set_balance(user1, &300);
+    let epoch = gauge_controller.get_epoch();
+
+    gauge_controller.vote(user1, &Vec::from_array(e, [(0, 10_000)]));
+    jump(e, WEEK);
+    gauge_controller.vote(user1, &Vec::from_array(e, [(1, 10_000)]));
+    jump(e, WEEK);
+
+    // missed epoch can't override rewards of the following one
+    assert!(gauge_controller.try_distribute(&epoch).is_err());
+    gauge_controller.distribute(&(epoch + 1));
+    assert!(!gauge_controller.is_distributed(&epoch));
+    assert_eq!(get_pool_tps(&setup, 0).0, 0);
+    assert_eq!(get_pool_tps(&setup, 1).0, 100);
+}
+
+#[test]
+fn test_revote() {
+    let setup = Setup::default();
+    let e = &setup.env;
+    let gauge_controller = &setup.gauge_controller;
+    let user1 = &setup.users[0];
+    setup.voting_escrow.set_balance(user1, &300);
+    let epoch = gauge_controller.get_epoch();
+
+    gauge_controller.vote(user1, &Vec::from_array(e, [(0, 10_000)]));
+    gauge_controller.vote(user1, &Vec::from_array(e, [(1, 5_000)]));
+    assert_eq!(gauge_controller.get_gauge_weight(&epoch, &0), 0);
+    assert_eq!(gauge_controller.get_gauge_weight(&epoch, &1), 150);
+
+    // votes are not carried over to the next epoch
+    jump(e, WEEK);
+    assert_eq!(gauge_controller.get_gauge_weight(&(epoch + 1), &1), 0);
+    gauge_controller.distribute(&epoch);
+    assert_eq!(get_pool_tps(&setup, 0).0, 0);
+    assert_eq!(get_pool_tps(&setup, 1).0, 100);
+}
+
+#[test]
+fn test_remove_gauge() {
+    let setup = Setup::default();
+    let e = &setup.env;
+    let gauge_controller = &setup.gauge_controller;
+    let user1 = &setup.users[0];
+    setup.voting_escrow.set_balance(user1, &300);
+    let epoch = gauge_controller.get_epoch();
+
+    gauge_controller.vote(user1, &Vec::from_array(e, [(0, 5_000), (1, 5_000)]));
+    gauge_controller.remove_gauge(&setup.admin, &1);
+    assert_eq!(gauge_controller.get_gauges(), Vec::from_array(e, [0]));
+    assert!(gauge_controller
+        .try_vote(user1, &Vec::from_array(e, [(1, 5_000)]))
+        .is_err());
+
+    // removed gauge share is redistributed across active ones
+    jump(e, WEEK);
+    gauge_controller.distribute(&epoch);
+    assert_eq!(get_pool_tps(&setup, 0).0, 100);
+    assert_eq!(get_pool_tps(&setup, 1).0, 0);
+}
+
+#[test]
+fn test_distribute_deprecated_pool() {
+    let setup = Setup::default();
+    let e = &setup.env;
+    let gauge_controller = &setup.gauge_controller;
+    let user1 = &setup.users[0];
+    setup.voting_escrow.set_balance(user1, &300);
+    let epoch = gauge_controller.get_epoch();
+
+    gauge_controller.vote(user1, &Vec::from_array(e, [(0, 5_000), (1, 5_000)]));
+    let (tokens, pool_index) = &setup.pools[1];
+    setup.router.set_pool_state(
+        &setup.admin,
+        tokens,
+        pool_index,
+        &router::PoolState::Deprecated,
+    );
+
+    // deprecated pool share is redistributed across the others
+    jump(e, WEEK);
+    gauge_controller.distribute(&epoch);
+    assert!(gauge_controller.is_distributed(&epoch));
+    assert_eq!(get_pool_tps(&setup, 0).0, 100);
+    assert_eq!(get_pool_tps(&setup, 1).0, 0);
+}
+
+#[test]
+fn test_distribute_unfunded_pool() {
+    let setup = Setup::default();
+    let e = &setup.env;
+    let gauge_controller = &setup.gauge_controller;
+    let user1 = &setup.users[0];
+    setup.voting_escrow.set_balance(user1, &300);
+    let epoch = gauge_controller.get_epoch();
+
+    // pool without reward tokens for emission
+    let tokens = create_pool_tokens(e);
+    let (pool_index, _) = setup.router.init_standard_pool(&setup.admin, &tokens, &30);
+    let id = gauge_controller.add_gauge(&setup.admin, &tokens, &pool_index);
+    gauge_controller.vote(user1, &Vec::from_array(e, [(0, 5_000), (id, 5_000)]));
+
+    jump(e, WEEK);
+    gauge_controller.distribute(&epoch);
+    assert!(gauge_controller.is_distributed(&epoch));
+    assert_eq!(
+        vec![e, e.events().all().last().unwrap()],
+        vec![
+            e,
+            (
+                gauge_controller.address.clone(),
+                (Symbol::new(e, "distribute_failed"), epoch).into_val(e),
+                (id, 50_u128).into_val(e),
+            )
+        ]
+    );
+    assert_eq!(get_pool_tps(&setup, 0).0, 50);
+    let info = setup.router.get_rewards_info(user1, &tokens, &pool_index);
+    assert_eq!(info.tps, 0);
+}
+
+#[test]
+#[should_panic(expected = "total vote weight exceeds 100%")]
+fn test_vote_weight_overflow() {
+    let setup = Setup::default();
+    let e = &setup.env;
+    setup.gauge_controller.vote(
+        &setup.users[0],
+        &Vec::from_array(e, [(0, 6_000), (1, 5_000)]),
+    );
+}
+
+#[test]
+#[should_panic(expected = "gauge already exists")]
+fn test_add_gauge_twice() {
+    let setup = Setup::default();
+    let (tokens, pool_index) = &setup.pools[0];
+    setup
+        .gauge_controller
+        .add_gauge(&setup.admin, tokens, pool_index);
+}
+
+#[test]
+fn test_add_gauge_not_admin() {
+    let setup = Setup::default();
+    let (tokens, pool_index) = &setup.pools[0];
+    assert!(setup
+        .gauge_controller
+        .try_add_gauge(&setup.users[0], tokens, pool_index)
+        .is_err());
+}
@@ -5,15 +5,23 @@ use crate::pool;
 use crate::pool_interface::{
     LiquidityPoolCrunch, LiquidityPoolTrait, RewardsTrait, UpgradeableContractTrait,
 };
-use crate::rewards::get_rewards_manager;
+use crate::rewards::{
+    claim_reward_to, get_reserved_balance, get_rewards_manager, get_stream_rewards,
+};
 use crate::storage::{
     get_fee_fraction, get_plane, get_reserve_a, get_reserve_b, get_token_a, get_token_b, has_plane,
     put_fee_fraction, put_reserve_a, put_reserve_b, put_token_a, put_token_b, set_plane,
 };
 use crate::token::{create_contract, get_balance_a, get_balance_b, transfer_a, transfer_b};
 use access_control::access::{AccessControl, AccessControlTrait};
+use access_control::interface::{RolesInterface, TransferableContract};
+use access_control::role::Role;
 use num_integer::Roots;
-use rewards::storage::{PoolRewardConfig, RewardsStorageTrait};
+use rewards::math::saturating_mul_div;
+use rewards::storage::{
+    Incentive, RewardsFunding, RewardsInfo, RewardsStorageTrait, ScheduledRewardConfig,
+    VolumeEpoch, VolumeMiningConfig,
+};
 use soroban_sdk::token::TokenClient as SorobanTokenClient;
 use soroban_sdk::{
     contract, contracterror, contractimpl, contractmeta, panic_with_error, symbol_short, Address,
@@ -50,6 +58,7 @@ impl LiquidityPoolCrunch for LiquidityPool {
         lp_token_wasm_hash: BytesN<32>,
         tokens: Vec<Address>,
         fee_fraction: u32,
+        reward_precision: u128,
         reward_token: Address,
         reward_storage: Address,
         plane: Address,
@@ -58,7 +67,7 @@ impl LiquidityPoolCrunch for LiquidityPool {
         // https://github.com/stellar/rs-soroban-env/issues/827
         Self::set_pools_plane(e.clone(), plane);
         Self::initialize(e.clone(), admin, lp_token_wasm_hash, tokens, fee_fraction);
-        Self::initialize_rewards_config(e.clone(), reward_token, reward_storage);
+        Self::initialize_rewards_config(e.clone(), reward_token, reward_storage, reward_precision);
     }
 }
 
@@ -95,6 +104,8 @@ impl LiquidityPoolTrait for LiquidityPool {
             &"Pool Share Token".into_val(&e),
             &"POOL".into_val(&e),
         );
+        // checkpoint rewards on share transfers
+        LPTokenClient::new(&e, &share_contract).set_rewards_checkpoint(&true);
 
         // 0.01% = 1; 1% = 100; 0.3% = 30
         if fee_fraction > 9999 {
@@ -134,15 +145,11 @@ impl LiquidityPoolTrait for LiquidityPool {
 
         let (reserve_a, reserve_b) = (get_reserve_a(&e), get_reserve_b(&e));
 
-        // Before actual changes were made to the pool, update total rewards data and refresh/initialize user reward
+        // Before actual changes were made to the pool, update total rewards data and refresh/initialize user reward of every stream
         let rewards = get_rewards_manager(&e);
         let total_shares = get_total_shares(&e);
         let user_shares = get_user_balance_shares(&e, &user);
-        let pool_data = rewards.manager().update_rewards_data(total_shares);
-        rewards
-            .manager()
-            .update_user_reward(&pool_data, &user, user_shares);
-        rewards.storage().bump_user_reward_data(&user);
+        rewards.checkpoint_user(&user, total_shares, user_shares);
 
         let desired_a = desired_amounts.get(0).unwrap();
         let desired_b = desired_amounts.get(1).unwrap();
@@ -189,7 +196,12 @@ impl LiquidityPoolTrait for LiquidityPool {
         };
 
         let shares_to_mint = new_total_shares - total_shares;
-        mint_shares(&e, user, shares_to_mint as i128);
+        mint_shares(&e, user.clone(), shares_to_mint as i128);
+        rewards.update_working_balance(
+            &user,
+            get_total_shares(&e),
+            get_user_balance_shares(&e, &user),
+        );
         put_reserve_a(&e, balance_a);
         put_reserve_b(&e, balance_b);
 
@@ -277,14 +289,23 @@ impl LiquidityPoolTrait for LiquidityPool {
         }
 
         if out_idx == 0 {
-            transfer_a(&e, user, out_a);
+            transfer_a(&e, user.clone(), out_a);
         } else {
-            transfer_b(&e, user, out_b);
+            transfer_b(&e, user.clone(), out_b);
         }
 
         put_reserve_a(&e, balance_a - out_a);
         put_reserve_b(&e, balance_b - out_b);
 
+        // volume mining points are the swap fee valued in the first token
+        let fee = saturating_mul_div(&e, in_amount, fee_fraction as u128, FEE_MULTIPLIER);
+        let points = match in_idx {
+            0 => fee,
+            _ => saturating_mul_div(&e, fee, reserve_a, reserve_b),
+        };
+        let rewards = get_rewards_manager(&e);
+        rewards.add_volume_points(&user, points, get_reserved_balance(&e, &rewards));
+
         // update plane data for every pool update
         update_plane(&e);
 
@@ -323,15 +344,11 @@ impl LiquidityPoolTrait for LiquidityPool {
     fn withdraw(e: Env, user: Address, share_amount: u128, min_amounts: Vec<u128>) -> Vec<u128> {
         user.require_auth();
 
-        // Before actual changes were made to the pool, update total rewards data and refresh user reward
+        // Before actual changes were made to the pool, update total rewards data and refresh user reward of every stream
         let rewards = get_rewards_manager(&e);
         let total_shares = get_total_shares(&e);
         let user_shares = get_user_balance_shares(&e, &user);
-        let pool_data = rewards.manager().update_rewards_data(total_shares);
-        rewards
-            .manager()
-            .update_user_reward(&pool_data, &user, user_shares);
-        rewards.storage().bump_user_reward_data(&user);
+        rewards.checkpoint_user(&user, total_shares, user_shares);
 
         // First transfer the pool shares that need to be redeemed
         let share_token_client = SorobanTokenClient::new(&e, &get_token_share(&e));
@@ -358,6 +375,11 @@ impl LiquidityPoolTrait for LiquidityPool {
         }
 
         burn_shares(&e, balance_shares as i128);
+        rewards.update_working_balance(
+            &user,
+            get_total_shares(&e),
+            get_user_balance_shares(&e, &user),
+        );
         transfer_a(&e, user.clone(), out_a);
         transfer_b(&e, user, out_b);
         put_reserve_a(&e, balance_a - out_a);
@@ -388,6 +410,11 @@ impl LiquidityPoolTrait for LiquidityPool {
     }
 }
 
+access_control::generate_transferable_contract!(LiquidityPool);
+
+access_control::generate_roles_interface!(LiquidityPool);
+
+#[contractimpl]
 impl UpgradeableContractTrait for LiquidityPool {
     fn version() -> u32 {
         100
@@ -402,17 +429,16 @@ impl UpgradeableContractTrait for LiquidityPool {
 
 #[contractimpl]
 impl RewardsTrait for LiquidityPool {
-    fn initialize_rewards_config(e: Env, reward_token: Address, reward_storage: Address) {
+    fn initialize_rewards_config(
+        e: Env,
+        reward_token: Address,
+        reward_storage: Address,
+        reward_precision: u128,
+    ) {
         // admin.require_auth();
         // check_admin(&e, &admin);
 
-        let rewards = get_rewards_manager(&e);
-        if rewards.storage().has_reward_token() {
-            panic!("rewards config already initialized")
-        }
-
-        rewards.storage().put_reward_token(reward_token);
-        rewards.storage().put_reward_storage(reward_storage);
+        get_rewards_manager(&e).initialize_config(reward_token, reward_storage, reward_precision);
     }
 
     fn set_rewards_config(
@@ -421,60 +447,347 @@ impl RewardsTrait for LiquidityPool {
         expired_at: u64, // timestamp
         tps: u128,       // value with 7 decimal places. example: 600_0000000
     ) {
+        Self::set_stream_rewards_config(e, admin, 0, expired_at, tps);
+    }
+
+    fn get_rewards_info(e: Env, user: Address) -> RewardsInfo {
+        get_rewards_manager(&e).get_rewards_info(
+            &user,
+            get_total_shares(&e),
+            get_user_balance_shares(&e, &user),
+        )
+    }
+
+    fn get_user_reward(e: Env, user: Address) -> u128 {
+        get_rewards_manager(&e).get_user_reward(
+            &user,
+            get_total_shares(&e),
+            get_user_balance_shares(&e, &user),
+        )
+    }
+
+    fn claim(e: Env, user: Address) -> u128 {
+        claim_reward_to(&e, &user, &user)
+    }
+
+    fn claim_to(e: Env, user: Address, recipient: Address) -> u128 {
+        user.require_auth();
+        claim_reward_to(&e, &user, &recipient)
+    }
+
+    fn claim_as_operator(e: Env, operator: Address, user: Address, recipient: Address) -> u128 {
+        // pool admin (router) checks operator approval on its own side
+        if AccessControl::new(&e).get_admin() == Some(operator.clone()) {
+            operator.require_auth();
+        } else {
+            get_rewards_manager(&e).require_claim_allowed(&operator, &user);
+        }
+        claim_reward_to(&e, &user, &recipient)
+    }
+
+    fn set_claim_operator(e: Env, user: Address, operator: Address, approved: bool) {
+        user.require_auth();
+        get_rewards_manager(&e).set_claim_operator(&user, &operator, approved);
+    }
+
+    fn is_claim_operator(e: Env, user: Address, operator: Address) -> bool {
+        get_rewards_manager(&e).is_claim_operator(&user, &operator)
+    }
+
+    fn get_reward_token(e: Env) -> Address {
+        let rewards = get_rewards_manager(&e);
+        rewards.storage().get_reward_token()
+    }
+
+    fn get_reward_storage(e: Env) -> Address {
+        let rewards = get_rewards_manager(&e);
+        rewards.storage().get_reward_storage()
+    }
+
+    fn collect_reward(e: Env, user: Address) -> u128 {
+        let rewards = get_rewards_manager(&e);
+        let reward_storage = rewards.storage().get_reward_storage();
+        reward_storage.require_auth();
+        // reward storage should be the router trusted by the pool, not an arbitrary account
+        AccessControl::new(&e).check_role(Role::RewardsAdmin, &reward_storage);
+
+        rewards.take_reward(
+            &user,
+            get_total_shares(&e),
+            get_user_balance_shares(&e, &user),
+        )
+    }
+
+    fn add_reward_stream(
+        e: Env,
+        admin: Address,
+        reward_token: Address,
+        reward_storage: Address,
+    ) -> u32 {
         admin.require_auth();
-        AccessControl::new(&e).check_admin(&admin);
+        AccessControl::new(&e).check_role(Role::RewardsAdmin, &admin);
 
         let rewards = get_rewards_manager(&e);
-        let total_shares = get_total_shares(&e);
-        rewards.manager().update_rewards_data(total_shares);
+        bump_instance(&e);
+        rewards.add_stream(reward_token, reward_storage)
+    }
+
+    fn set_stream_rewards_config(e: Env, admin: Address, stream: u32, expired_at: u64, tps: u128) {
+        admin.require_auth();
+        AccessControl::new(&e).check_role(Role::RewardsAdmin, &admin);
 
-        let config = PoolRewardConfig { tps, expired_at };
+        let rewards = get_stream_rewards(&e, stream);
         bump_instance(&e);
-        rewards.storage().set_pool_reward_config(&config);
+        rewards.set_config(
+            expired_at,
+            tps,
+            get_total_shares(&e),
+            get_reserved_balance(&e, &rewards),
+        );
     }
 
-    fn get_rewards_info(e: Env, user: Address) -> Map<Symbol, i128> {
+    fn get_all_rewards_info(e: Env, user: Address) -> Vec<(Address, RewardsInfo)> {
+        get_rewards_manager(&e).get_all_rewards_info(
+            &user,
+            get_total_shares(&e),
+            get_user_balance_shares(&e, &user),
+        )
+    }
+
+    fn get_all_user_rewards(e: Env, user: Address) -> Vec<u128> {
+        get_rewards_manager(&e).get_all_user_rewards(
+            &user,
+            get_total_shares(&e),
+            get_user_balance_shares(&e, &user),
+        )
+    }
+
+    fn claim_all_rewards(e: Env, user: Address) -> Vec<u128> {
+        get_rewards_manager(&e).claim_all(
+            &user,
+            get_total_shares(&e),
+            get_user_balance_shares(&e, &user),
+        )
+    }
+
+    fn set_voting_escrow(e: Env, admin: Address, voting_escrow: Address) {
+        admin.require_auth();
+        AccessControl::new(&e).check_role(Role::RewardsAdmin, &admin);
+
         let rewards = get_rewards_manager(&e);
-        let config = rewards.storage().get_pool_reward_config();
-        let total_shares = get_total_shares(&e);
-        let user_shares = get_user_balance_shares(&e, &user);
-        let pool_data = rewards.manager().update_rewards_data(total_shares);
-        let user_data = rewards
-            .manager()
-            .update_user_reward(&pool_data, &user, user_shares);
-        let mut result = Map::new(&e);
-        result.set(symbol_short!("tps"), config.tps as i128);
-        result.set(symbol_short!("exp_at"), config.expired_at as i128);
-        result.set(symbol_short!("acc"), pool_data.accumulated as i128);
-        result.set(symbol_short!("last_time"), pool_data.last_time as i128);
-        result.set(
-            symbol_short!("pool_acc"),
-            user_data.pool_accumulated as i128,
+        bump_instance(&e);
+        rewards.set_voting_escrow(voting_escrow, get_total_shares(&e));
+    }
+
+    fn get_voting_escrow(e: Env) -> Option<Address> {
+        get_rewards_manager(&e).get_voting_escrow()
+    }
+
+    fn set_reward_precision(e: Env, admin: Address, precision: u128) {
+        admin.require_auth();
+        AccessControl::new(&e).check_role(Role::RewardsAdmin, &admin);
+
+        let rewards = get_rewards_manager(&e);
+        bump_instance(&e);
+        rewards.set_precision(precision, get_total_shares(&e));
+    }
+
+    fn get_reward_precision(e: Env) -> u128 {
+        get_rewards_manager(&e).get_precision()
+    }
+
+    fn kick(e: Env, user: Address) {
+        get_rewards_manager(&e).kick(
+            &user,
+            get_total_shares(&e),
+            get_user_balance_shares(&e, &user),
         );
-        result.set(symbol_short!("block"), pool_data.block as i128);
-        result.set(symbol_short!("usr_block"), user_data.last_block as i128);
-        result.set(symbol_short!("to_claim"), user_data.to_claim as i128);
-        result
     }
 
-    fn get_user_reward(e: Env, user: Address) -> u128 {
+    fn get_working_balance(e: Env, user: Address) -> u128 {
+        get_rewards_manager(&e).get_working_balance(
+            &user,
+            get_total_shares(&e),
+            get_user_balance_shares(&e, &user),
+        )
+    }
+
+    fn get_working_supply(e: Env) -> u128 {
+        get_rewards_manager(&e).working_supply(get_total_shares(&e))
+    }
+
+    fn enable_rewards_checkpoint(e: Env, admin: Address) {
+        admin.require_auth();
+        AccessControl::new(&e).check_role(Role::RewardsAdmin, &admin);
+
+        bump_instance(&e);
+        LPTokenClient::new(&e, &get_token_share(&e)).set_rewards_checkpoint(&true);
+    }
+
+    fn checkpoint_reward(e: Env, user: Address, shares_before: u128, shares_after: u128) {
+        get_token_share(&e).require_auth();
+
         let rewards = get_rewards_manager(&e);
         let total_shares = get_total_shares(&e);
-        let user_shares = get_user_balance_shares(&e, &user);
-        rewards
-            .manager()
-            .get_amount_to_claim(&user, total_shares, user_shares)
+        rewards.checkpoint_user(&user, total_shares, shares_before);
+        rewards.update_working_balance(&user, total_shares, shares_after);
     }
 
-    fn claim(e: Env, user: Address) -> u128 {
+    fn schedule_rewards_config(
+        e: Env,
+        admin: Address,
+        stream: u32,
+        start_at: u64,
+        expired_at: u64,
+        tps: u128,
+    ) {
+        admin.require_auth();
+        AccessControl::new(&e).check_role(Role::RewardsAdmin, &admin);
+
+        let rewards = get_stream_rewards(&e, stream);
+        bump_instance(&e);
+        rewards.schedule_config(
+            start_at,
+            expired_at,
+            tps,
+            get_reserved_balance(&e, &rewards),
+        );
+    }
+
+    fn cancel_scheduled_rewards_config(e: Env, admin: Address, stream: u32, start_at: u64) {
+        admin.require_auth();
+        AccessControl::new(&e).check_role(Role::RewardsAdmin, &admin);
+
+        let rewards = get_stream_rewards(&e, stream);
+        bump_instance(&e);
+        rewards.manager().cancel_scheduled_rewards_config(start_at);
+    }
+
+    fn stop_rewards(e: Env, admin: Address, stream: u32) {
+        admin.require_auth();
+        AccessControl::new(&e).check_role(Role::RewardsAdmin, &admin);
+
+        let rewards = get_stream_rewards(&e, stream);
+        bump_instance(&e);
+        rewards.stop(get_total_shares(&e));
+    }
+
+    fn get_rewards_funding(e: Env, stream: u32) -> RewardsFunding {
+        let rewards = get_stream_rewards(&e, stream);
+        rewards.get_funding(get_reserved_balance(&e, &rewards))
+    }
+
+    fn get_rewards_tps_per_share(e: Env, stream: u32) -> u128 {
+        get_stream_rewards(&e, stream).get_tps_per_share(get_total_shares(&e))
+    }
+
+    fn get_remaining_emission(e: Env, stream: u32) -> u128 {
+        get_stream_rewards(&e, stream).get_remaining_emission(get_total_shares(&e))
+    }
+
+    fn deposit_incentive(
+        e: Env,
+        depositor: Address,
+        token: Address,
+        amount: u128,
+        duration: u64,
+    ) -> u32 {
+        depositor.require_auth();
+        if token == get_token_a(&e) || token == get_token_b(&e) {
+            panic!("pool tokens can't be used as incentive")
+        }
+
+        SorobanTokenClient::new(&e, &token).transfer(
+            &depositor,
+            &e.current_contract_address(),
+            &(amount as i128),
+        );
         let rewards = get_rewards_manager(&e);
-        let total_shares = get_total_shares(&e);
-        let user_shares = get_user_balance_shares(&e, &user);
-        let reward = rewards
-            .manager()
-            .claim_reward(&user, total_shares, user_shares);
-        rewards.storage().bump_user_reward_data(&user);
-        reward
+        bump_instance(&e);
+        rewards.add_incentive(&depositor, token, amount, duration, get_total_shares(&e))
+    }
+
+    fn set_incentive_token(e: Env, admin: Address, token: Address, min_amount: Option<u128>) {
+        admin.require_auth();
+        AccessControl::new(&e).check_role(Role::RewardsAdmin, &admin);
+
+        bump_instance(&e);
+        get_rewards_manager(&e).set_incentive_token(&token, min_amount);
+    }
+
+    fn get_incentive_min_amount(e: Env, token: Address) -> Option<u128> {
+        get_rewards_manager(&e)
+            .storage()
+            .get_incentive_min_amount(&token)
+    }
+
+    fn settle_incentive(e: Env, stream: u32) -> u128 {
+        let (_, leftover) = get_stream_rewards(&e, stream).settle_incentive(get_total_shares(&e));
+        leftover
+    }
+
+    fn get_incentive(e: Env, stream: u32) -> Option<Incentive> {
+        get_stream_rewards(&e, stream).storage().get_incentive()
+    }
+
+    fn reclaim_unallocated(e: Env, admin: Address, stream: u32, to: Address) -> u128 {
+        admin.require_auth();
+        AccessControl::new(&e).check_role(Role::RewardsAdmin, &admin);
+
+        let rewards = get_stream_rewards(&e, stream);
+        rewards.reclaim_unallocated(
+            &to,
+            get_total_shares(&e),
+            get_reserved_balance(&e, &rewards),
+        )
+    }
+
+    fn get_scheduled_rewards_configs(e: Env, stream: u32) -> Vec<ScheduledRewardConfig> {
+        get_stream_rewards(&e, stream).get_scheduled_configs(get_total_shares(&e))
+    }
+
+    fn set_volume_mining_config(e: Env, admin: Address, epoch_length: u64, budget: u128) {
+        admin.require_auth();
+        AccessControl::new(&e).check_role(Role::RewardsAdmin, &admin);
+
+        let rewards = get_rewards_manager(&e);
+        bump_instance(&e);
+        rewards.set_volume_mining_config(epoch_length, budget, get_reserved_balance(&e, &rewards));
+    }
+
+    fn get_volume_mining_config(e: Env) -> Option<VolumeMiningConfig> {
+        get_rewards_manager(&e).volume_mining().get_config()
+    }
+
+    fn get_volume_epochs_count(e: Env) -> u32 {
+        get_rewards_manager(&e).volume_mining().epochs_count()
+    }
+
+    fn get_volume_epoch(e: Env, epoch: u32) -> Option<VolumeEpoch> {
+        get_rewards_manager(&e).volume_mining().get_epoch(epoch)
+    }
+
+    fn get_user_volume_points(e: Env, user: Address, epoch: u32) -> u128 {
+        get_rewards_manager(&e)
+            .volume_mining()
+            .get_user_points(&user, epoch)
+    }
+
+    fn get_user_volume_reward(e: Env, user: Address, epoch: u32) -> u128 {
+        get_rewards_manager(&e)
+            .volume_mining()
+            .get_user_reward(&user, epoch)
+    }
+
+    fn claim_volume_reward(e: Env, user: Address, epoch: u32) -> u128 {
+        user.require_auth();
+        bump_instance(&e);
+        get_rewards_manager(&e).volume_mining().claim(&user, epoch)
+    }
+
+    fn sweep_volume_epoch(e: Env, epoch: u32) -> u128 {
+        bump_instance(&e);
+        get_rewards_manager(&e).volume_mining().sweep(epoch)
     }
 }
 
@@ -1,3 +1,6 @@
+use rewards::storage::{
+    Incentive, RewardsFunding, RewardsInfo, ScheduledRewardConfig, VolumeEpoch, VolumeMiningConfig,
+};
 use soroban_sdk::{Address, BytesN, Env, Map, Symbol, Val, Vec};
 
 pub trait LiquidityPoolCrunch {
@@ -8,6 +11,7 @@ pub trait LiquidityPoolCrunch {
         lp_token_wasm_hash: BytesN<32>,
         tokens: Vec<Address>,
         fee_fraction: u32,
+        reward_precision: u128,
         reward_token: Address,
         reward_storage: Address,
         plane: Address,
@@ -84,7 +88,13 @@ pub trait RewardsTrait {
 
     // Initialize rewards settings: token address and storage address
     // from which transfer will be made on claim
-    fn initialize_rewards_config(e: Env, reward_token: Address, reward_storage: Address);
+    // reward_precision: reward per share precision, shared by all reward streams
+    fn initialize_rewards_config(
+        e: Env,
+        reward_token: Address,
+        reward_storage: Address,
+        reward_precision: u128,
+    );
 
     // Configure rewards for pool. Every second tps of coins
     // being distributed across all liquidity providers
@@ -92,8 +102,8 @@ pub trait RewardsTrait {
     fn set_rewards_config(e: Env, admin: Address, expired_at: u64, tps: u128);
 
     // Get rewards status for the pool,
-    // including amount available for the user
-    fn get_rewards_info(e: Env, user: Address) -> Map<Symbol, i128>;
+    // including amount available for the user. Nothing is written
+    fn get_rewards_info(e: Env, user: Address) -> RewardsInfo;
 
     // Get amount of reward tokens available for the user to claim.
     fn get_user_reward(e: Env, user: Address) -> u128;
@@ -101,4 +111,175 @@ pub trait RewardsTrait {
     // Claim reward as a user.
     // returns amount of tokens rewarded to the user
     fn claim(e: Env, user: Address) -> u128;
+
+    // Claim reward as a user, transferring it to recipient.
+    // returns amount of tokens rewarded
+    fn claim_to(e: Env, user: Address, recipient: Address) -> u128;
+
+    // Claim user reward by operator approved by the user, transferring it to recipient.
+    // Pool admin is trusted as operator, as router checks its own approvals.
+    // returns amount of tokens rewarded
+    fn claim_as_operator(e: Env, operator: Address, user: Address, recipient: Address) -> u128;
+
+    // Allow or disallow operator to claim rewards on behalf of the user
+    fn set_claim_operator(e: Env, user: Address, operator: Address, approved: bool);
+
+    // Check if operator is allowed to claim rewards on behalf of the user
+    fn is_claim_operator(e: Env, user: Address, operator: Address) -> bool;
+
+    // Get reward token address
+    fn get_reward_token(e: Env) -> Address;
+
+    // Get address from which rewards are being transferred on claim
+    fn get_reward_storage(e: Env) -> Address;
+
+    // Mark user reward as claimed without transferring it. Returns reward amount.
+    // Can be called only by reward storage, which is responsible for paying reward out
+    // and should hold rewards admin role in the pool (router).
+    // Allows to aggregate rewards from multiple pools into single transfer
+    fn collect_reward(e: Env, user: Address) -> u128;
+
+    // Add reward stream distributing another token alongside the primary one.
+    // reward_storage: address from which stream rewards are transferred on claim
+    // Returns stream number
+    fn add_reward_stream(
+        e: Env,
+        admin: Address,
+        reward_token: Address,
+        reward_storage: Address,
+    ) -> u32;
+
+    // Configure rewards of specific stream. Stream 0 is the primary one
+    // Streams with unsettled incentive can't be reconfigured, scheduled or stopped
+    fn set_stream_rewards_config(e: Env, admin: Address, stream: u32, expired_at: u64, tps: u128);
+
+    // Get reward token and rewards status of every stream,
+    // including amount available for the user
+    fn get_all_rewards_info(e: Env, user: Address) -> Vec<(Address, RewardsInfo)>;
+
+    // Get amounts of reward tokens available for the user to claim for every stream
+    fn get_all_user_rewards(e: Env, user: Address) -> Vec<u128>;
+
+    // Claim rewards of every stream. Reward of additional stream which token fails
+    // to transfer is kept for the user.
+    // returns amounts of tokens rewarded to the user for every stream
+    fn claim_all_rewards(e: Env, user: Address) -> Vec<u128>;
+
+    // Enable rewards boost using vote-escrow contract.
+    // User working balance becomes min(shares, 40% of shares + 60% of total shares * user lock / total lock)
+    fn set_voting_escrow(e: Env, admin: Address, voting_escrow: Address);
+
+    // Get vote-escrow contract address if boost is enabled
+    fn get_voting_escrow(e: Env) -> Option<Address>;
+
+    // Increase reward per share precision. New precision should be a multiple of current one
+    fn set_reward_precision(e: Env, admin: Address, precision: u128);
+
+    // Get reward per share precision
+    fn get_reward_precision(e: Env) -> u128;
+
+    // Recalculate user working balance according to his current lock. Callable by anyone
+    fn kick(e: Env, user: Address);
+
+    // Get amount of shares user is rewarded for
+    fn get_working_balance(e: Env, user: Address) -> u128;
+
+    // Get sum of working balances of all users
+    fn get_working_supply(e: Env) -> u128;
+
+    // Make share token of pool created before transfers checkpointing was introduced
+    // notify the pool about balance changes. Share token code should support checkpoints
+    fn enable_rewards_checkpoint(e: Env, admin: Address);
+
+    // Checkpoint user rewards before his shares balance changes.
+    // Called by pool share token on every transfer
+    fn checkpoint_reward(e: Env, user: Address, shares_before: u128, shares_after: u128);
+
+    // Queue rewards config of the stream to be applied automatically at start_at timestamp.
+    // Periods should not overlap and be scheduled in chronological order
+    fn schedule_rewards_config(
+        e: Env,
+        admin: Address,
+        stream: u32,
+        start_at: u64,
+        expired_at: u64,
+        tps: u128,
+    );
+
+    // Remove not started rewards config from the stream queue
+    fn cancel_scheduled_rewards_config(e: Env, admin: Address, stream: u32, start_at: u64);
+
+    // End reward period of the stream now. Scheduled configs are kept
+    fn stop_rewards(e: Env, admin: Address, stream: u32);
+
+    // Get committed, paid and available amounts of the stream reward token
+    fn get_rewards_funding(e: Env, stream: u32) -> RewardsFunding;
+
+    // Get reward per second for single working share of the stream, multiplied by reward precision.
+    // Zero if stream rewards are not being distributed at the moment
+    fn get_rewards_tps_per_share(e: Env, stream: u32) -> u128;
+
+    // Get amount of rewards to be generated by active and scheduled configs of the stream
+    fn get_remaining_emission(e: Env, stream: u32) -> u128;
+
+    // Stream amount of token to liquidity providers during duration seconds. Callable by anyone.
+    // Token should be allowed for incentives and amount shouldn't be less than its minimum.
+    // Undistributed leftover is returned to depositor once incentive is finished.
+    // Free stream of the token is reused, otherwise a new one is added. Returns stream number.
+    // Like any added stream, a new one is earned by providers from their next position update
+    fn deposit_incentive(
+        e: Env,
+        depositor: Address,
+        token: Address,
+        amount: u128,
+        duration: u64,
+    ) -> u32;
+
+    // Allow token for incentives with minimum amount, or disallow it if min_amount is none.
+    // Pool tokens can't be used anyway
+    fn set_incentive_token(e: Env, admin: Address, token: Address, min_amount: Option<u128>);
+
+    // Get minimum incentive amount of the token. None if token is not allowed
+    fn get_incentive_min_amount(e: Env, token: Address) -> Option<u128>;
+
+    // Return undistributed leftover of finished incentive to its depositor. Callable by anyone.
+    // Returns leftover amount
+    fn settle_incentive(e: Env, stream: u32) -> u128;
+
+    // Get incentive of the stream which is not settled yet
+    fn get_incentive(e: Env, stream: u32) -> Option<Incentive>;
+
+    // Transfer reward tokens not committed to any stream to the given address.
+    // Available only if pool is the reward storage. Returns amount transferred
+    fn reclaim_unallocated(e: Env, admin: Address, stream: u32, to: Address) -> u128;
+
+    // Get rewards configs of the stream waiting for activation
+    fn get_scheduled_rewards_configs(e: Env, stream: u32) -> Vec<ScheduledRewardConfig>;
+    // Configure trading volume mining. Every epoch of epoch_length seconds budget of reward
+    // token is split between traders proportionally to the swap fees they paid.
+    // Zero budget disables it, epoch in progress is finished anyway
+    fn set_volume_mining_config(e: Env, admin: Address, epoch_length: u64, budget: u128);
+
+    // Get volume mining config. None if volume mining is disabled
+    fn get_volume_mining_config(e: Env) -> Option<VolumeMiningConfig>;
+
+    // Get amount of volume mining epochs started. The last one may be still in progress
+    fn get_volume_epochs_count(e: Env) -> u32;
+
+    // Get volume mining epoch state
+    fn get_volume_epoch(e: Env, epoch: u32) -> Option<VolumeEpoch>;
+
+    // Get points collected by the user in the epoch
+    fn get_user_volume_points(e: Env, user: Address, epoch: u32) -> u128;
+
+    // Get user reward for the finished epoch
+    fn get_user_volume_reward(e: Env, user: Address, epoch: u32) -> u128;
+
+    // Claim user reward for the finished epoch. Returns amount transferred.
+    // Reward can be claimed during 30 days after epoch end
+    fn claim_volume_reward(e: Env, user: Address, epoch: u32) -> u128;
+
+    // Release budget left unclaimed once epoch claim period is over, so it can be reclaimed
+    // as unallocated. Callable by anyone. Returns amount released
+    fn sweep_volume_epoch(e: Env, epoch: u32) -> u128;
 }
@@ -2,10 +2,15 @@
 extern crate std;
 
 use crate::testutils::{
-    create_liqpool_contract, create_token_contract, install_token_wasm, jump, Setup, TestConfig,
+    create_broken_token_contract, create_liqpool_contract, create_token_contract,
+    create_voting_escrow_contract, install_token_wasm, jump, Setup, TestConfig,
+};
+use rewards::storage::{
+    LegacyPoolRewardData, LegacyUserRewardData, RewardsFunding, RewardsInfo, RewardsStorageTrait,
+    ScheduledRewardConfig, VolumeEpoch,
 };
 use soroban_sdk::testutils::{AuthorizedFunction, AuthorizedInvocation};
-use soroban_sdk::{testutils::Address as _, Address, IntoVal, Symbol, Vec};
+use soroban_sdk::{testutils::Address as _, Address, IntoVal, Map, Symbol, Vec};
 use utils::test_utils::assert_approx_eq_abs;
 
 #[test]
@@ -209,6 +214,7 @@ fn initialize_already_initialized_plane() {
         &install_token_wasm(&setup.env),
         &Vec::from_array(&setup.env, [token1.address.clone(), token2.address.clone()]),
         &10_u32,
+        &1_000_000_000_000,
         &token1.address,
         &setup.liq_pool.address,
         &setup.plane.address,
@@ -474,11 +480,13 @@ fn test_rewards_many_users(iterations_to_simulate: u32) {
     );
     jump(&env, 10);
 
-    // we have this because of last jump(100)
-    let mut expected_reward = 100 * reward_1_tps / iterations_to_simulate as u128;
+    // we have this because of last jump(100). sum is kept scaled to avoid rounding
+    let scale = 1_000_000_000;
+    let mut expected_reward = 100 * reward_1_tps * scale / iterations_to_simulate as u128;
     for i in 0..iterations_to_simulate as u128 {
-        expected_reward += reward_1_tps / (i + 1);
+        expected_reward += reward_1_tps * scale / (i + 1);
     }
+    expected_reward /= scale;
 
     liq_pool.deposit(&first_user, &Vec::from_array(&env, [1000, 1000]));
     jump(&env, 1);
@@ -507,3 +515,1285 @@ fn test_rewards_1k() {
 fn test_rewards_50k() {
     test_rewards_many_users(50_000);
 }
+
+// cost of idle user claim after given amount of pool rewards updates
+fn idle_user_claim_cost(updates: u32) -> u64 {
+    let Setup {
+        env,
+        users,
+        token1: _token1,
+        token2: _token2,
+        token_reward: _token_reward,
+        token_share: _token_share,
+        liq_pool,
+        plane: _plane,
+    } = Setup::default();
+
+    liq_pool.deposit(&users[1], &Vec::from_array(&env, [100, 100]));
+    liq_pool.deposit(&users[0], &Vec::from_array(&env, [100, 100]));
+    for _ in 0..updates {
+        jump(&env, 1);
+        liq_pool.claim(&users[0]);
+    }
+    jump(&env, 1);
+
+    env.budget().reset_default();
+    liq_pool.claim(&users[1]);
+    let cost = env.budget().cpu_instruction_cost();
+    env.budget().reset_unlimited();
+    cost
+}
+
+#[test]
+fn test_claim_cost_independent_of_history() {
+    assert_eq!(idle_user_claim_cost(5), idle_user_claim_cost(50));
+}
+
+#[test]
+fn test_legacy_rewards_migration() {
+    let Setup {
+        env,
+        users,
+        token1: _token1,
+        token2: _token2,
+        token_reward,
+        token_share: _token_share,
+        liq_pool,
+        plane: _plane,
+    } = Setup::default();
+    let reward_tps = TestConfig::default().reward_tps;
+    liq_pool.deposit(&users[0], &Vec::from_array(&env, [100, 100]));
+
+    // rewards history written in block based format: two blocks generating 300 and 500 tokens
+    let now = env.ledger().timestamp();
+    env.as_contract(&liq_pool.address, || {
+        let mut storage = rewards::Storage::new(&env);
+        storage.remove_pool_reward_data();
+        storage.remove_user_reward_data(&users[0]);
+        storage.set_legacy_pool_reward_data(&LegacyPoolRewardData {
+            block: 2,
+            accumulated: 800,
+            last_time: now,
+        });
+        storage.set_reward_inv_data(
+            0,
+            0,
+            Map::from_array(&env, [(0, 0), (1, 300 * 1000 / 100), (2, 500 * 1000 / 100)]),
+        );
+        storage.set_legacy_user_reward_data(
+            &users[0],
+            &LegacyUserRewardData {
+                pool_accumulated: 0,
+                to_claim: 7,
+                last_block: 0,
+            },
+        );
+    });
+
+    // legacy reward is kept, new rewards are accumulated on top of it
+    jump(&env, 10);
+    assert_eq!(
+        liq_pool.get_user_reward(&users[0]),
+        7 + 300 + 500 + reward_tps * 10
+    );
+    assert_eq!(
+        liq_pool.get_rewards_info(&users[0]).accumulated,
+        800 + reward_tps * 10
+    );
+    assert_eq!(liq_pool.claim(&users[0]), 7 + 300 + 500 + reward_tps * 10);
+    assert_eq!(
+        token_reward.balance(&users[0]) as u128,
+        7 + 300 + 500 + reward_tps * 10
+    );
+    env.as_contract(&liq_pool.address, || {
+        let storage = rewards::Storage::new(&env);
+        assert!(storage.get_legacy_pool_reward_data().is_none());
+        assert!(storage.get_legacy_user_reward_data(&users[0]).is_none());
+    });
+
+    jump(&env, 10);
+    assert_eq!(liq_pool.claim(&users[0]), reward_tps * 10);
+}
+
+#[test]
+fn test_reward_dust_bounded() {
+    let Setup {
+        env,
+        users,
+        token1: _token1,
+        token2: _token2,
+        token_reward,
+        token_share,
+        liq_pool,
+        plane: _plane,
+    } = Setup::new_with_config(&TestConfig {
+        users_count: 3,
+        ..TestConfig::default()
+    });
+    assert_eq!(liq_pool.get_reward_precision(), 1_000_000_000_000);
+
+    // small emission split across odd amounts of shares
+    liq_pool.set_rewards_config(&users[0], &env.ledger().timestamp().saturating_add(100), &1);
+    for (user, amount) in users.iter().zip([333, 100, 7]) {
+        liq_pool.deposit(user, &Vec::from_array(&env, [amount, amount]));
+    }
+    let total_shares: u128 = users
+        .iter()
+        .map(|user| token_share.balance(user) as u128)
+        .sum();
+
+    // frequent updates should not accumulate rounding error
+    for i in 0..100 {
+        jump(&env, 1);
+        liq_pool.claim(&users[i % 3]);
+    }
+    for user in users.iter() {
+        liq_pool.claim(user);
+        let user_shares = token_share.balance(user) as u128;
+        let expected_reward = 100 * user_shares / total_shares;
+        let reward = token_reward.balance(user) as u128;
+        assert!(reward <= expected_reward && expected_reward - reward <= 1);
+    }
+}
+
+#[test]
+fn test_reward_precision_migration() {
+    let Setup {
+        env,
+        users,
+        token1: _token1,
+        token2: _token2,
+        token_reward,
+        token_share,
+        liq_pool,
+        plane: _plane,
+    } = Setup::default();
+
+    // pool created before precision became configurable
+    env.as_contract(&liq_pool.address, || {
+        rewards::Storage::new(&env).put_reward_precision(1_000);
+    });
+    liq_pool.set_rewards_config(&users[0], &env.ledger().timestamp().saturating_add(100), &1);
+    liq_pool.deposit(&users[0], &Vec::from_array(&env, [333, 333]));
+    liq_pool.deposit(&users[1], &Vec::from_array(&env, [107, 107]));
+    let user_shares = token_share.balance(&users[0]) as u128;
+    let total_shares = user_shares + token_share.balance(&users[1]) as u128;
+
+    // reward per share is truncated with legacy precision
+    jump(&env, 50);
+    let legacy_reward = 1_000 * 50 / total_shares * user_shares / 1_000;
+    assert_eq!(liq_pool.get_user_reward(&users[0]), legacy_reward);
+
+    assert!(liq_pool
+        .try_set_reward_precision(&users[0], &1_500)
+        .is_err());
+    assert!(liq_pool
+        .try_set_reward_precision(&users[0], &1_000_000_000_000_000_000_000)
+        .is_err());
+    liq_pool.set_reward_precision(&users[0], &1_000_000_000_000);
+    assert_eq!(liq_pool.get_reward_precision(), 1_000_000_000_000);
+
+    // reward accumulated before migration is kept, new one is precise
+    jump(&env, 50);
+    assert_approx_eq_abs(
+        liq_pool.claim(&users[0]),
+        legacy_reward + 50 * user_shares / total_shares,
+        2,
+    );
+    assert_approx_eq_abs(
+        token_reward.balance(&users[0]) as u128,
+        legacy_reward + 50 * user_shares / total_shares,
+        2,
+    );
+}
+
+#[test]
+fn test_reward_precision_max() {
+    let Setup {
+        env,
+        users,
+        token1: _token1,
+        token2: _token2,
+        token_reward,
+        token_share,
+        liq_pool,
+        plane: _plane,
+    } = Setup::default();
+    liq_pool.set_reward_precision(&users[0], &1_000_000_000_000_000_000);
+    assert_eq!(liq_pool.get_reward_precision(), 1_000_000_000_000_000_000);
+
+    // pool keeps working at maximum precision
+    liq_pool.deposit(&users[0], &Vec::from_array(&env, [1000, 1000]));
+    jump(&env, 10);
+    liq_pool.deposit(&users[1], &Vec::from_array(&env, [1000, 1000]));
+    jump(&env, 10);
+    token_share.approve(&users[1], &liq_pool.address, &500, &99999);
+    liq_pool.withdraw(&users[1], &500, &Vec::from_array(&env, [0, 0]));
+    let reward_tps = TestConfig::default().reward_tps;
+    assert_eq!(liq_pool.claim(&users[0]), reward_tps * 15);
+    assert_eq!(token_reward.balance(&users[0]) as u128, reward_tps * 15);
+    assert!(liq_pool
+        .try_set_reward_precision(&users[0], &1_000_000_000_000_000_000_000)
+        .is_err());
+}
+
+#[test]
+fn test_reward_precision_headroom() {
+    let Setup {
+        env,
+        users,
+        token1: _token1,
+        token2: _token2,
+        token_reward: _token_reward,
+        token_share: _token_share,
+        liq_pool,
+        plane: _plane,
+    } = Setup::new_with_config(&TestConfig {
+        rewards_count: 1_000_000_000_000_000_000_000_000,
+        ..TestConfig::default()
+    });
+
+    // huge emission per single share
+    liq_pool.set_rewards_config(
+        &users[0],
+        &env.ledger().timestamp().saturating_add(30),
+        &10_000_000_000_000_000_000_000,
+    );
+    liq_pool.deposit(&users[0], &Vec::from_array(&env, [1000, 1000]));
+    jump(&env, 30);
+
+    // rescaled reward per share wouldn't be able to grow further
+    assert!(liq_pool
+        .try_set_reward_precision(&users[0], &1_000_000_000_000_000_000)
+        .is_err());
+    liq_pool.set_reward_precision(&users[0], &1_000_000_000_000_000);
+    assert_eq!(
+        liq_pool.get_user_reward(&users[0]),
+        30 * 10_000_000_000_000_000_000_000
+    );
+}
+
+#[test]
+#[should_panic(expected = "reward precision is out of allowed range")]
+fn test_reward_precision_out_of_range() {
+    let setup = Setup::default();
+    let liq_pool = crate::LiquidityPoolClient::new(
+        &setup.env,
+        &setup.env.register_contract(None, crate::LiquidityPool {}),
+    );
+    liq_pool.initialize_all(
+        &setup.users[0],
+        &install_token_wasm(&setup.env),
+        &Vec::from_array(
+            &setup.env,
+            [setup.token1.address.clone(), setup.token2.address.clone()],
+        ),
+        &30,
+        &0,
+        &setup.token_reward.address,
+        &liq_pool.address,
+        &setup.plane.address,
+    );
+}
+
+#[test]
+fn test_rewards_funding_check() {
+    let setup = Setup::default();
+    let rewards_count = TestConfig::default().rewards_count as u128;
+    let expired_at = setup.env.ledger().timestamp().saturating_add(100);
+
+    // pool holds less than tps * duration
+    assert!(setup
+        .liq_pool
+        .try_set_rewards_config(&setup.users[0], &expired_at, &(rewards_count / 100 + 1))
+        .is_err());
+    assert!(setup
+        .liq_pool
+        .try_schedule_rewards_config(
+            &setup.users[0],
+            &0,
+            &expired_at,
+            &(expired_at + 100),
+            &(rewards_count / 100 + 1),
+        )
+        .is_err());
+    setup
+        .liq_pool
+        .set_rewards_config(&setup.users[0], &expired_at, &(rewards_count / 100));
+    // disabling rewards is always allowed
+    setup
+        .liq_pool
+        .set_rewards_config(&setup.users[0], &expired_at, &0);
+}
+
+#[test]
+fn test_stop_rewards_and_reclaim() {
+    let Setup {
+        env,
+        users,
+        token1: _token1,
+        token2: _token2,
+        token_reward,
+        token_share: _token_share,
+        liq_pool,
+        plane: _plane,
+    } = Setup::default();
+    let reward_tps = TestConfig::default().reward_tps;
+    let rewards_count = TestConfig::default().rewards_count as u128;
+    let treasury = Address::generate(&env);
+
+    liq_pool.deposit(&users[1], &Vec::from_array(&env, [100, 100]));
+    assert_eq!(
+        liq_pool.get_rewards_funding(&0),
+        RewardsFunding {
+            committed: reward_tps * 60,
+            paid: 0,
+            available: rewards_count,
+        }
+    );
+
+    jump(&env, 30);
+    liq_pool.stop_rewards(&users[0], &0);
+    assert_eq!(
+        liq_pool.get_rewards_info(&users[1]).expired_at,
+        env.ledger().timestamp()
+    );
+    jump(&env, 30);
+    assert_eq!(liq_pool.get_user_reward(&users[1]), reward_tps * 30);
+
+    // only reward not committed to users can be reclaimed
+    assert!(liq_pool
+        .try_reclaim_unallocated(&users[1], &0, &treasury)
+        .is_err());
+    assert_eq!(
+        liq_pool.reclaim_unallocated(&users[0], &0, &treasury),
+        rewards_count - reward_tps * 30
+    );
+    assert_eq!(
+        token_reward.balance(&treasury) as u128,
+        rewards_count - reward_tps * 30
+    );
+    assert_eq!(liq_pool.reclaim_unallocated(&users[0], &0, &treasury), 0);
+
+    assert_eq!(liq_pool.claim(&users[1]), reward_tps * 30);
+    assert_eq!(
+        liq_pool.get_rewards_funding(&0),
+        RewardsFunding {
+            committed: reward_tps * 30,
+            paid: reward_tps * 30,
+            available: 0,
+        }
+    );
+}
+
+#[test]
+fn test_rewards_info_read_only() {
+    let Setup {
+        env,
+        users,
+        token1: _token1,
+        token2: _token2,
+        token_reward: _token_reward,
+        token_share: _token_share,
+        liq_pool,
+        plane: _plane,
+    } = Setup::default();
+    let reward_tps = TestConfig::default().reward_tps;
+    let precision = liq_pool.get_reward_precision();
+
+    liq_pool.deposit(&users[1], &Vec::from_array(&env, [100, 100]));
+    let deposited_at = env.ledger().timestamp();
+    assert_eq!(
+        liq_pool.get_rewards_tps_per_share(&0),
+        reward_tps * precision / 100
+    );
+    assert_eq!(liq_pool.get_remaining_emission(&0), reward_tps * 60);
+
+    jump(&env, 20);
+    assert_eq!(
+        liq_pool.get_rewards_info(&users[1]),
+        RewardsInfo {
+            tps: reward_tps,
+            expired_at: deposited_at + 60,
+            accumulated: reward_tps * 20,
+            last_time: deposited_at + 20,
+            reward_per_share: reward_tps * 20 * precision / 100,
+            precision,
+            user_reward_per_share: reward_tps * 20 * precision / 100,
+            to_claim: reward_tps * 20,
+        }
+    );
+    assert_eq!(liq_pool.get_remaining_emission(&0), reward_tps * 40);
+    assert_eq!(liq_pool.get_user_reward(&users[1]), reward_tps * 20);
+    assert_eq!(
+        liq_pool.get_all_user_rewards(&users[1]),
+        Vec::from_array(&env, [reward_tps * 20])
+    );
+    env.as_contract(&liq_pool.address, || {
+        let storage = rewards::Storage::new(&env);
+        assert_eq!(storage.get_pool_reward_data().last_time, deposited_at);
+        assert_eq!(
+            storage
+                .get_user_reward_data(&users[1])
+                .unwrap()
+                .reward_per_share,
+            0
+        );
+    });
+
+    // rewards are not distributed anymore
+    jump(&env, 60);
+    assert_eq!(liq_pool.get_rewards_tps_per_share(&0), 0);
+    assert_eq!(liq_pool.get_remaining_emission(&0), 0);
+    assert_eq!(
+        liq_pool.get_rewards_info(&users[1]).to_claim,
+        reward_tps * 60
+    );
+    assert_eq!(liq_pool.claim(&users[1]), reward_tps * 60);
+}
+
+#[test]
+fn test_transfer_ownership() {
+    let Setup {
+        env: e,
+        users,
+        token1: _token1,
+        token2: _token2,
+        token_reward: _token_reward,
+        token_share: _token_share,
+        liq_pool,
+        plane: _plane,
+    } = Setup::default();
+    let admin_original = users[0].clone();
+    let admin_new = Address::generate(&e);
+
+    liq_pool.set_transfer_delay(&admin_original, &(4 * 86400));
+    assert_eq!(liq_pool.get_transfer_delay(), 4 * 86400);
+    assert_eq!(liq_pool.get_future_admin(), None);
+
+    liq_pool.commit_transfer_ownership(&admin_original, &admin_new);
+    assert_eq!(liq_pool.get_future_admin(), Some(admin_new.clone()));
+    assert_eq!(
+        liq_pool.get_transfer_ownership_deadline(),
+        e.ledger().timestamp() + 4 * 86400
+    );
+    jump(&e, 4 * 86400 + 1);
+    liq_pool.apply_transfer_ownership(&admin_original);
+    assert_eq!(liq_pool.get_future_admin(), None);
+
+    // new admin is able to call protected methods
+    liq_pool.set_rewards_config(&admin_new, &e.ledger().timestamp().saturating_add(60), &1);
+}
+
+#[test]
+#[should_panic(expected = "insufficient time")]
+fn test_transfer_ownership_too_early() {
+    let Setup {
+        env: e,
+        users,
+        token1: _token1,
+        token2: _token2,
+        token_reward: _token_reward,
+        token_share: _token_share,
+        liq_pool,
+        plane: _plane,
+    } = Setup::default();
+    let admin_original = users[0].clone();
+    let admin_new = Address::generate(&e);
+
+    liq_pool.commit_transfer_ownership(&admin_original, &admin_new);
+    jump(&e, 3 * 86400 - 1);
+    liq_pool.apply_transfer_ownership(&admin_original);
+}
+
+#[test]
+fn test_multiple_reward_streams() {
+    let Setup {
+        env,
+        users,
+        token1: _token1,
+        token2: _token2,
+        token_reward,
+        token_share: _token_share,
+        liq_pool,
+        plane: _plane,
+    } = Setup::default();
+    let total_reward_1 = TestConfig::default().reward_tps * 60;
+
+    // partner token distributed alongside primary reward, pool is used as its storage
+    let partner_tps = 2_0000000_u128;
+    let total_partner_reward = partner_tps * 60;
+    let token_partner = create_token_contract(&env, &users[1]);
+    token_partner.mint(&liq_pool.address, &(total_partner_reward as i128));
+    assert_eq!(
+        liq_pool.add_reward_stream(&users[0], &token_partner.address, &liq_pool.address),
+        1
+    );
+    liq_pool.set_stream_rewards_config(
+        &users[0],
+        &1,
+        &env.ledger().timestamp().saturating_add(60),
+        &partner_tps,
+    );
+
+    // second user joins after 30 seconds, so it gets only 1/4 of every stream reward
+    liq_pool.deposit(&users[0], &Vec::from_array(&env, [100, 100]));
+    jump(&env, 30);
+    liq_pool.deposit(&users[1], &Vec::from_array(&env, [100, 100]));
+    jump(&env, 100);
+
+    let rewards_info = liq_pool.get_all_rewards_info(&users[0]);
+    assert_eq!(rewards_info.len(), 2);
+    let (reward_token, info) = rewards_info.get(1).unwrap();
+    assert_eq!(reward_token, token_partner.address);
+    assert_eq!(info.tps, partner_tps);
+
+    assert_eq!(
+        liq_pool.get_all_user_rewards(&users[0]),
+        Vec::from_array(&env, [total_reward_1 / 4 * 3, total_partner_reward / 4 * 3])
+    );
+    assert_eq!(
+        liq_pool.claim_all_rewards(&users[0]),
+        Vec::from_array(&env, [total_reward_1 / 4 * 3, total_partner_reward / 4 * 3])
+    );
+    // primary stream is claimable separately
+    assert_eq!(liq_pool.claim(&users[1]), total_reward_1 / 4);
+    assert_eq!(
+        liq_pool.claim_all_rewards(&users[1]),
+        Vec::from_array(&env, [0, total_partner_reward / 4])
+    );
+
+    assert_eq!(
+        token_reward.balance(&users[0]) as u128,
+        total_reward_1 / 4 * 3
+    );
+    assert_eq!(
+        token_partner.balance(&users[0]) as u128,
+        total_partner_reward / 4 * 3
+    );
+    assert_eq!(
+        token_partner.balance(&users[1]) as u128,
+        total_partner_reward / 4
+    );
+}
+
+#[test]
+fn test_incentive_deposit_and_settle() {
+    let Setup {
+        env,
+        users,
+        token1,
+        token2: _token2,
+        token_reward: _token_reward,
+        token_share: _token_share,
+        liq_pool,
+        plane: _plane,
+    } = Setup::default();
+    let depositor = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let token_incentive = create_token_contract(&env, &depositor);
+    token_incentive.mint(&depositor, &1000_0000000);
+
+    // pool tokens can't be used since they are counted as liquidity
+    token1.mint(&depositor, &100_0000000);
+    assert!(liq_pool
+        .try_deposit_incentive(&depositor, &token1.address, &100_0000000, &100)
+        .is_err());
+
+    // only tokens allowed by rewards admin can be used, dust amounts are rejected
+    assert!(liq_pool
+        .try_deposit_incentive(&depositor, &token_incentive.address, &100_0000000, &100)
+        .is_err());
+    assert!(liq_pool
+        .try_set_incentive_token(&depositor, &token_incentive.address, &Some(10_0000000))
+        .is_err());
+    liq_pool.set_incentive_token(&users[0], &token_incentive.address, &Some(10_0000000));
+    assert_eq!(
+        liq_pool.get_incentive_min_amount(&token_incentive.address),
+        Some(10_0000000)
+    );
+    assert!(liq_pool
+        .try_deposit_incentive(&depositor, &token_incentive.address, &9_9999999, &1)
+        .is_err());
+
+    // 1 token per second, the rest is rounding leftover
+    assert_eq!(
+        liq_pool.deposit_incentive(&depositor, &token_incentive.address, &100_0000003, &100),
+        1
+    );
+    assert_eq!(token_incentive.balance(&liq_pool.address), 100_0000003);
+    let incentive = liq_pool.get_incentive(&1).unwrap();
+    assert_eq!(incentive.depositor, depositor);
+    assert_eq!(incentive.expired_at, env.ledger().timestamp() + 100);
+    // stream is busy until incentive is finished, so another one is added
+    assert_eq!(
+        liq_pool.deposit_incentive(&depositor, &token_incentive.address, &50_0000000, &100),
+        2
+    );
+
+    // incentive stream config can't be changed by rewards admin
+    let now = env.ledger().timestamp();
+    assert!(liq_pool
+        .try_set_stream_rewards_config(&users[0], &1, &(now + 10), &1)
+        .is_err());
+    assert!(liq_pool
+        .try_schedule_rewards_config(&users[0], &1, &(now + 200), &(now + 300), &1)
+        .is_err());
+    assert!(liq_pool.try_stop_rewards(&users[0], &1).is_err());
+
+    // nobody provides liquidity during first 20 seconds
+    jump(&env, 20);
+    liq_pool.deposit(&users[1], &Vec::from_array(&env, [100, 100]));
+    jump(&env, 50);
+    assert!(liq_pool.try_settle_incentive(&1).is_err());
+    jump(&env, 30);
+
+    // undistributed part is reserved for depositor
+    assert_eq!(liq_pool.reclaim_unallocated(&users[0], &1, &treasury), 0);
+    assert_eq!(liq_pool.settle_incentive(&1), 20_0000003);
+    assert_eq!(liq_pool.settle_incentive(&2), 10_0000000);
+    assert_eq!(
+        token_incentive.balance(&depositor),
+        1000_0000000 - 150_0000003 + 30_0000003
+    );
+    assert_eq!(liq_pool.get_incentive(&1), None);
+    assert!(liq_pool.try_settle_incentive(&1).is_err());
+
+    let user_rewards = liq_pool.get_all_user_rewards(&users[1]);
+    assert_eq!(user_rewards.get(1), Some(80_0000000));
+    assert_eq!(user_rewards.get(2), Some(40_0000000));
+    liq_pool.claim_all_rewards(&users[1]);
+    assert_eq!(token_incentive.balance(&users[1]), 120_0000000);
+
+    // finished stream is reused by next incentive in the same token
+    assert_eq!(
+        liq_pool.deposit_incentive(&depositor, &token_incentive.address, &10_0000000, &10),
+        1
+    );
+    jump(&env, 10);
+    assert_eq!(
+        liq_pool.get_all_user_rewards(&users[1]).get(1),
+        Some(10_0000000)
+    );
+
+    // disallowed token can't be used anymore
+    liq_pool.set_incentive_token(&users[0], &token_incentive.address, &None);
+    assert_eq!(
+        liq_pool.get_incentive_min_amount(&token_incentive.address),
+        None
+    );
+    assert!(liq_pool
+        .try_deposit_incentive(&depositor, &token_incentive.address, &10_0000000, &10)
+        .is_err());
+}
+
+#[test]
+fn test_incentive_failing_transfer_doesnt_block_claim() {
+    let Setup {
+        env,
+        users,
+        token1: _token1,
+        token2: _token2,
+        token_reward: _token_reward,
+        token_share: _token_share,
+        liq_pool,
+        plane: _plane,
+    } = Setup::default();
+    let depositor = Address::generate(&env);
+    let token_broken = create_broken_token_contract(&env);
+    let token_incentive = create_token_contract(&env, &depositor);
+    token_incentive.mint(&depositor, &100_0000000);
+    for token in [&token_broken.address, &token_incentive.address] {
+        liq_pool.set_incentive_token(&users[0], token, &Some(0));
+        liq_pool.deposit_incentive(&depositor, token, &100_0000000, &100);
+    }
+    liq_pool.deposit(&users[1], &Vec::from_array(&env, [100, 100]));
+    jump(&env, 100);
+
+    token_broken.set_broken(&true);
+    let claimed = liq_pool.claim_all_rewards(&users[1]);
+    assert_eq!(claimed.get(1), Some(0));
+    assert_eq!(claimed.get(2), Some(100_0000000));
+    assert_eq!(token_incentive.balance(&users[1]), 100_0000000);
+    let user_rewards = liq_pool.get_all_user_rewards(&users[1]);
+    assert_eq!(user_rewards.get(1), Some(100_0000000));
+    assert_eq!(user_rewards.get(2), Some(0));
+
+    // reward is kept for the user until token works again
+    token_broken.set_broken(&false);
+    assert_eq!(
+        liq_pool.claim_all_rewards(&users[1]).get(1),
+        Some(100_0000000)
+    );
+    assert_eq!(liq_pool.get_all_user_rewards(&users[1]).get(1), Some(0));
+}
+
+#[test]
+fn test_volume_mining() {
+    let Setup {
+        env,
+        users,
+        token1: _token1,
+        token2: _token2,
+        token_reward,
+        token_share: _token_share,
+        liq_pool,
+        plane: _plane,
+    } = Setup::new_with_config(&TestConfig {
+        users_count: 3,
+        mint_to_user: 1000_0000000,
+        ..TestConfig::default()
+    });
+    let treasury = Address::generate(&env);
+    liq_pool.deposit(
+        &users[0],
+        &Vec::from_array(&env, [500_0000000, 500_0000000]),
+    );
+
+    assert!(liq_pool
+        .try_set_volume_mining_config(&users[1], &100, &90_0000000)
+        .is_err());
+    // budget should be covered by reward token balance on top of lp rewards
+    assert!(liq_pool
+        .try_set_volume_mining_config(&users[0], &100, &1_000_000_0000000)
+        .is_err());
+    liq_pool.set_volume_mining_config(&users[0], &100, &90_0000000);
+
+    // 0.3% fee is collected as points
+    liq_pool.swap(&users[1], &0, &1, &100_0000000, &0);
+    liq_pool.swap(&users[2], &0, &1, &200_0000000, &0);
+    assert_eq!(liq_pool.get_volume_epochs_count(), 1);
+    assert_eq!(
+        liq_pool.get_volume_epoch(&0),
+        Some(VolumeEpoch {
+            budget: 90_0000000,
+            total_points: 9000000,
+            end_at: env.ledger().timestamp() + 100,
+            claimed: 0,
+            swept: false,
+        })
+    );
+    assert_eq!(liq_pool.get_user_volume_points(&users[1], &0), 3000000);
+    assert_eq!(liq_pool.get_user_volume_reward(&users[1], &0), 0);
+    assert!(liq_pool.try_claim_volume_reward(&users[1], &0).is_err());
+
+    jump(&env, 100);
+    assert_eq!(liq_pool.get_user_volume_reward(&users[2], &0), 60_0000000);
+    assert_eq!(liq_pool.claim_volume_reward(&users[1], &0), 30_0000000);
+    assert_eq!(token_reward.balance(&users[1]), 30_0000000);
+    assert_eq!(liq_pool.claim_volume_reward(&users[1], &0), 0);
+
+    // next epoch is started by the next swap. fee in second token is valued in the first one
+    let reserves = liq_pool.get_reserves();
+    liq_pool.swap(&users[1], &1, &0, &100_0000000, &0);
+    assert_eq!(liq_pool.get_volume_epochs_count(), 2);
+    assert_eq!(
+        liq_pool.get_user_volume_points(&users[1], &1),
+        3000000 * reserves.get(0).unwrap() / reserves.get(1).unwrap()
+    );
+
+    liq_pool.set_volume_mining_config(&users[0], &100, &0);
+    assert_eq!(liq_pool.get_volume_mining_config(), None);
+    jump(&env, 100);
+    liq_pool.swap(&users[2], &0, &1, &100_0000000, &0);
+    assert_eq!(liq_pool.get_volume_epochs_count(), 2);
+
+    // unclaimed budget can't be reclaimed
+    liq_pool.reclaim_unallocated(&users[0], &0, &treasury);
+    assert_eq!(liq_pool.claim_volume_reward(&users[2], &0), 60_0000000);
+    assert_eq!(liq_pool.claim_volume_reward(&users[1], &1), 90_0000000);
+    assert_eq!(liq_pool.get_volume_epoch(&0).unwrap().claimed, 90_0000000);
+
+    // budget left unclaimed is released once claim period is over
+    token_reward.mint(&liq_pool.address, &10_0000000);
+    liq_pool.set_volume_mining_config(&users[0], &100, &10_0000000);
+    liq_pool.swap(&users[2], &0, &1, &100_0000000, &0);
+    jump(&env, 100);
+    assert!(liq_pool.try_sweep_volume_epoch(&2).is_err());
+    jump(&env, 30 * 24 * 60 * 60);
+    assert_eq!(liq_pool.get_user_volume_reward(&users[2], &2), 0);
+    assert!(liq_pool.try_claim_volume_reward(&users[2], &2).is_err());
+    assert_eq!(liq_pool.sweep_volume_epoch(&0), 0);
+    assert_eq!(liq_pool.sweep_volume_epoch(&2), 10_0000000);
+    assert!(liq_pool.get_volume_epoch(&2).unwrap().swept);
+    assert!(liq_pool.try_sweep_volume_epoch(&2).is_err());
+    liq_pool.set_volume_mining_config(&users[0], &100, &0);
+    assert_eq!(
+        liq_pool.reclaim_unallocated(&users[0], &0, &treasury),
+        10_0000000
+    );
+}
+
+#[test]
+fn test_volume_mining_unfunded_epoch() {
+    let Setup {
+        env,
+        users,
+        token1: _token1,
+        token2: _token2,
+        token_reward,
+        token_share: _token_share,
+        liq_pool,
+        plane: _plane,
+    } = Setup::new_with_config(&TestConfig {
+        users_count: 2,
+        mint_to_user: 1000_0000000,
+        ..TestConfig::default()
+    });
+    liq_pool.deposit(
+        &users[0],
+        &Vec::from_array(&env, [500_0000000, 500_0000000]),
+    );
+
+    // budget covers single epoch only
+    let funding = liq_pool.get_rewards_funding(&0);
+    let budget = funding.available - (funding.committed - funding.paid);
+    liq_pool.set_volume_mining_config(&users[0], &100, &budget);
+    liq_pool.swap(&users[1], &0, &1, &100_0000000, &0);
+    assert_eq!(liq_pool.get_volume_epochs_count(), 1);
+
+    // next epoch isn't started while not funded, swap still goes through
+    jump(&env, 100);
+    liq_pool.swap(&users[1], &0, &1, &100_0000000, &0);
+    assert_eq!(liq_pool.get_volume_epochs_count(), 1);
+
+    assert_eq!(liq_pool.claim_volume_reward(&users[1], &0), budget);
+    token_reward.mint(&liq_pool.address, &(budget as i128 + 100));
+    liq_pool.swap(&users[1], &0, &1, &100_0000000, &0);
+    assert_eq!(liq_pool.get_volume_epochs_count(), 2);
+}
+
+#[test]
+#[should_panic(expected = "reward stream doesn't exist")]
+fn test_reward_stream_not_exists() {
+    let Setup {
+        env,
+        users,
+        token1: _token1,
+        token2: _token2,
+        token_reward: _token_reward,
+        token_share: _token_share,
+        liq_pool,
+        plane: _plane,
+    } = Setup::default();
+
+    liq_pool.set_stream_rewards_config(
+        &users[0],
+        &1,
+        &env.ledger().timestamp().saturating_add(60),
+        &1,
+    );
+}
+
+#[test]
+fn test_boosted_rewards() {
+    let Setup {
+        env,
+        users,
+        token1: _token1,
+        token2: _token2,
+        token_reward,
+        token_share: _token_share,
+        liq_pool,
+        plane: _plane,
+    } = Setup::default();
+    let total_reward_1 = TestConfig::default().reward_tps * 60;
+    let voting_escrow = create_voting_escrow_contract(&env);
+
+    liq_pool.deposit(&users[0], &Vec::from_array(&env, [100, 100]));
+    liq_pool.deposit(&users[1], &Vec::from_array(&env, [100, 100]));
+
+    // boost disabled: working balances are equal to shares
+    assert_eq!(liq_pool.get_voting_escrow(), None);
+    assert_eq!(liq_pool.get_working_balance(&users[1]), 100);
+    assert_eq!(liq_pool.get_working_supply(), 200);
+
+    liq_pool.set_voting_escrow(&users[0], &voting_escrow.address);
+    assert_eq!(
+        liq_pool.get_voting_escrow(),
+        Some(voting_escrow.address.clone())
+    );
+    assert_eq!(liq_pool.get_working_supply(), 200);
+
+    // only first user has lock. second one is limited to 40% of his shares
+    voting_escrow.set_balance(&users[0], &100);
+    liq_pool.kick(&users[0]);
+    liq_pool.kick(&users[1]);
+    assert_eq!(liq_pool.get_working_balance(&users[0]), 100);
+    assert_eq!(liq_pool.get_working_balance(&users[1]), 40);
+    assert_eq!(liq_pool.get_working_supply(), 140);
+
+    jump(&env, 60);
+    assert_approx_eq_abs(liq_pool.claim(&users[0]), total_reward_1 * 100 / 140, 1000);
+    assert_approx_eq_abs(liq_pool.claim(&users[1]), total_reward_1 * 40 / 140, 1000);
+    assert_approx_eq_abs(
+        token_reward.balance(&users[1]) as u128,
+        total_reward_1 * 40 / 140,
+        1000,
+    );
+}
+
+#[test]
+fn test_boost_kick() {
+    let Setup {
+        env,
+        users,
+        token1: _token1,
+        token2: _token2,
+        token_reward: _token_reward,
+        token_share: _token_share,
+        liq_pool,
+        plane: _plane,
+    } = Setup::default();
+    let voting_escrow = create_voting_escrow_contract(&env);
+    liq_pool.set_voting_escrow(&users[0], &voting_escrow.address);
+    voting_escrow.set_balance(&users[0], &100);
+
+    // working balance is calculated on deposit
+    liq_pool.deposit(&users[0], &Vec::from_array(&env, [100, 100]));
+    liq_pool.deposit(&users[1], &Vec::from_array(&env, [100, 100]));
+    assert_eq!(liq_pool.get_working_balance(&users[0]), 100);
+    assert_eq!(liq_pool.get_working_balance(&users[1]), 40);
+    assert_eq!(liq_pool.get_working_supply(), 140);
+
+    // boost is stale until user is kicked
+    voting_escrow.set_balance(&users[1], &100);
+    assert_eq!(liq_pool.get_working_balance(&users[1]), 40);
+    liq_pool.kick(&users[1]);
+    assert_eq!(liq_pool.get_working_balance(&users[1]), 100);
+    assert_eq!(liq_pool.get_working_supply(), 200);
+
+    // lock expired: only tokenless part is left after next deposit
+    voting_escrow.set_balance(&users[0], &0);
+    liq_pool.deposit(&users[0], &Vec::from_array(&env, [100, 100]));
+    assert_eq!(liq_pool.get_working_balance(&users[0]), 80);
+    assert_eq!(liq_pool.get_working_supply(), 180);
+}
+
+#[test]
+fn test_boost_huge_lock() {
+    let Setup {
+        env,
+        users,
+        token1: _token1,
+        token2: _token2,
+        token_reward: _token_reward,
+        token_share: _token_share,
+        liq_pool,
+        plane: _plane,
+    } = Setup::default();
+    let voting_escrow = create_voting_escrow_contract(&env);
+    liq_pool.set_voting_escrow(&users[0], &voting_escrow.address);
+
+    // shares multiplied by lock don't fit into u128
+    voting_escrow.set_balance(&users[0], &(u128::MAX / 10));
+    voting_escrow.set_balance(&users[1], &(u128::MAX / 10));
+    liq_pool.deposit(&users[0], &Vec::from_array(&env, [100, 100]));
+    liq_pool.deposit(&users[1], &Vec::from_array(&env, [100, 100]));
+    liq_pool.kick(&users[0]);
+    assert_eq!(liq_pool.get_working_balance(&users[0]), 100);
+    assert_eq!(liq_pool.get_working_balance(&users[1]), 100);
+    assert_eq!(liq_pool.get_working_supply(), 200);
+}
+
+#[test]
+#[should_panic(expected = "boost is not enabled")]
+fn test_kick_boost_disabled() {
+    let setup = Setup::default();
+    setup.liq_pool.kick(&setup.users[0]);
+}
+
+#[test]
+fn test_scheduled_rewards() {
+    let Setup {
+        env,
+        users,
+        token1: _token1,
+        token2: _token2,
+        token_reward,
+        token_share: _token_share,
+        liq_pool,
+        plane: _plane,
+    } = Setup::default();
+    let reward_1_tps = TestConfig::default().reward_tps;
+    let reward_2_tps = 20_0000000_u128;
+    let reward_3_tps = 6_0000000_u128;
+    let start = env.ledger().timestamp();
+
+    // gap between current config expiration and first scheduled period is not rewarded
+    liq_pool.schedule_rewards_config(&users[0], &0, &(start + 100), &(start + 200), &reward_2_tps);
+    liq_pool.schedule_rewards_config(&users[0], &0, &(start + 200), &(start + 300), &reward_3_tps);
+    assert_eq!(
+        liq_pool.get_scheduled_rewards_configs(&0),
+        Vec::from_array(
+            &env,
+            [
+                ScheduledRewardConfig {
+                    start_at: start + 100,
+                    expired_at: start + 200,
+                    tps: reward_2_tps,
+                },
+                ScheduledRewardConfig {
+                    start_at: start + 200,
+                    expired_at: start + 300,
+                    tps: reward_3_tps,
+                },
+            ]
+        )
+    );
+
+    liq_pool.deposit(&users[0], &Vec::from_array(&env, [100, 100]));
+    jump(&env, 150);
+    assert_eq!(
+        liq_pool.get_user_reward(&users[0]),
+        reward_1_tps * 60 + reward_2_tps * 50
+    );
+    // started config is active now
+    assert_eq!(liq_pool.get_scheduled_rewards_configs(&0).len(), 1);
+    let info = liq_pool.get_rewards_info(&users[0]);
+    assert_eq!(info.tps, reward_2_tps);
+
+    jump(&env, 250);
+    let total_reward = reward_1_tps * 60 + reward_2_tps * 100 + reward_3_tps * 100;
+    assert_eq!(liq_pool.claim(&users[0]), total_reward);
+    assert_eq!(token_reward.balance(&users[0]) as u128, total_reward);
+    assert_eq!(liq_pool.get_scheduled_rewards_configs(&0).len(), 0);
+}
+
+#[test]
+fn test_cancel_scheduled_rewards() {
+    let setup = Setup::default();
+    let env = setup.env;
+    let liq_pool = setup.liq_pool;
+    let admin = &setup.users[0];
+    let start = env.ledger().timestamp();
+
+    liq_pool.schedule_rewards_config(admin, &0, &(start + 100), &(start + 200), &1);
+    liq_pool.schedule_rewards_config(admin, &0, &(start + 200), &(start + 300), &2);
+    liq_pool.cancel_scheduled_rewards_config(admin, &0, &(start + 100));
+    assert_eq!(
+        liq_pool.get_scheduled_rewards_configs(&0),
+        Vec::from_array(
+            &env,
+            [ScheduledRewardConfig {
+                start_at: start + 200,
+                expired_at: start + 300,
+                tps: 2,
+            }]
+        )
+    );
+    assert!(liq_pool
+        .try_cancel_scheduled_rewards_config(admin, &0, &(start + 100))
+        .is_err());
+    assert!(liq_pool
+        .try_cancel_scheduled_rewards_config(&setup.users[1], &0, &(start + 200))
+        .is_err());
+}
+
+#[test]
+#[should_panic(expected = "scheduled rewards overlap")]
+fn test_scheduled_rewards_overlap() {
+    let setup = Setup::default();
+    let start = setup.env.ledger().timestamp();
+    let admin = &setup.users[0];
+
+    setup
+        .liq_pool
+        .schedule_rewards_config(admin, &0, &(start + 100), &(start + 200), &1);
+    setup
+        .liq_pool
+        .schedule_rewards_config(admin, &0, &(start + 150), &(start + 300), &1);
+}
+
+#[test]
+#[should_panic(expected = "cannot schedule rewards in the past")]
+fn test_scheduled_rewards_in_past() {
+    let setup = Setup::default();
+    jump(&setup.env, 100);
+    let now = setup.env.ledger().timestamp();
+
+    setup
+        .liq_pool
+        .schedule_rewards_config(&setup.users[0], &0, &(now - 10), &(now + 100), &1);
+}
+
+#[test]
+fn test_share_transfer_rewards_checkpoint() {
+    let Setup {
+        env,
+        users,
+        token1: _token1,
+        token2: _token2,
+        token_reward: _token_reward,
+        token_share,
+        liq_pool,
+        plane: _plane,
+    } = Setup::default();
+    let reward_tps = TestConfig::default().reward_tps;
+    assert!(token_share.get_rewards_checkpoint());
+
+    liq_pool.deposit(&users[0], &Vec::from_array(&env, [100, 100]));
+    jump(&env, 30);
+    // receiver is not rewarded for the time before transfer,
+    // sender keeps reward accumulated while holding shares
+    token_share.transfer(&users[0], &users[1], &50);
+    jump(&env, 30);
+
+    assert_eq!(
+        liq_pool.get_user_reward(&users[0]),
+        reward_tps * 30 + reward_tps * 30 / 2
+    );
+    assert_eq!(liq_pool.get_user_reward(&users[1]), reward_tps * 30 / 2);
+}
+
+#[test]
+fn test_share_transfer_from_rewards_checkpoint() {
+    let Setup {
+        env,
+        users,
+        token1: _token1,
+        token2: _token2,
+        token_reward: _token_reward,
+        token_share,
+        liq_pool,
+        plane: _plane,
+    } = Setup::default();
+    let reward_tps = TestConfig::default().reward_tps;
+
+    liq_pool.deposit(&users[0], &Vec::from_array(&env, [100, 100]));
+    token_share.approve(&users[0], &users[1], &100, &99999);
+    jump(&env, 30);
+    token_share.transfer_from(&users[1], &users[0], &users[1], &100);
+    jump(&env, 30);
+
+    assert_eq!(liq_pool.get_user_reward(&users[0]), reward_tps * 30);
+    assert_eq!(liq_pool.get_user_reward(&users[1]), reward_tps * 30);
+
+    // receiver is able to withdraw transferred shares with rewards settled
+    token_share.approve(&users[1], &liq_pool.address, &100, &99999);
+    liq_pool.withdraw(&users[1], &100, &Vec::from_array(&env, [100, 100]));
+    assert_eq!(liq_pool.claim(&users[1]), reward_tps * 30);
+}
+
+#[test]
+fn test_enable_rewards_checkpoint_for_existing_pool() {
+    let Setup {
+        env,
+        users,
+        token1: _token1,
+        token2: _token2,
+        token_reward: _token_reward,
+        token_share,
+        liq_pool,
+        plane: _plane,
+    } = Setup::default();
+    let reward_tps = TestConfig::default().reward_tps;
+
+    // share token of pool created before transfers were checkpointed
+    token_share.set_rewards_checkpoint(&false);
+    liq_pool.deposit(&users[0], &Vec::from_array(&env, [100, 100]));
+    jump(&env, 30);
+
+    assert!(liq_pool
+        .try_enable_rewards_checkpoint(&Address::generate(&env))
+        .is_err());
+    liq_pool.enable_rewards_checkpoint(&users[0]);
+    assert!(token_share.get_rewards_checkpoint());
+
+    token_share.transfer(&users[0], &users[1], &50);
+    jump(&env, 30);
+    assert_eq!(
+        liq_pool.get_user_reward(&users[0]),
+        reward_tps * 30 + reward_tps * 30 / 2
+    );
+    assert_eq!(liq_pool.get_user_reward(&users[1]), reward_tps * 30 / 2);
+}
+
+#[test]
+fn test_share_transfer_checkpoint_only_from_token() {
+    let setup = Setup::default();
+    setup.env.set_auths(&[]);
+    assert!(setup
+        .liq_pool
+        .try_checkpoint_reward(&setup.users[0], &0, &100)
+        .is_err());
+}
+
+#[test]
+fn test_claim_to() {
+    let Setup {
+        env,
+        users,
+        token1: _token1,
+        token2: _token2,
+        token_reward,
+        token_share: _token_share,
+        liq_pool,
+        plane: _plane,
+    } = Setup::default();
+    let reward_tps = TestConfig::default().reward_tps;
+    let treasury = Address::generate(&env);
+
+    liq_pool.deposit(&users[0], &Vec::from_array(&env, [100, 100]));
+    jump(&env, 30);
+    assert_eq!(liq_pool.claim_to(&users[0], &treasury), reward_tps * 30);
+    assert_eq!(token_reward.balance(&treasury) as u128, reward_tps * 30);
+    assert_eq!(token_reward.balance(&users[0]), 0);
+    assert_eq!(liq_pool.get_user_reward(&users[0]), 0);
+}
+
+#[test]
+fn test_claim_as_operator() {
+    let Setup {
+        env,
+        users,
+        token1: _token1,
+        token2: _token2,
+        token_reward,
+        token_share: _token_share,
+        liq_pool,
+        plane: _plane,
+    } = Setup::default();
+    let reward_tps = TestConfig::default().reward_tps;
+    let operator = Address::generate(&env);
+    let treasury = Address::generate(&env);
+
+    liq_pool.deposit(&users[0], &Vec::from_array(&env, [100, 100]));
+    jump(&env, 30);
+    assert!(!liq_pool.is_claim_operator(&users[0], &operator));
+    assert!(liq_pool
+        .try_claim_as_operator(&operator, &users[0], &treasury)
+        .is_err());
+
+    liq_pool.set_claim_operator(&users[0], &operator, &true);
+    assert!(liq_pool.is_claim_operator(&users[0], &operator));
+    assert_eq!(
+        liq_pool.claim_as_operator(&operator, &users[0], &treasury),
+        reward_tps * 30
+    );
+    assert_eq!(token_reward.balance(&treasury) as u128, reward_tps * 30);
+
+    // approval is revoked
+    liq_pool.set_claim_operator(&users[0], &operator, &false);
+    assert!(!liq_pool.is_claim_operator(&users[0], &operator));
+    jump(&env, 10);
+    assert!(liq_pool
+        .try_claim_as_operator(&operator, &users[0], &treasury)
+        .is_err());
+
+    // pool admin (router) checks approvals itself
+    liq_pool.deposit(&users[1], &Vec::from_array(&env, [100, 100]));
+    jump(&env, 10);
+    assert!(!liq_pool.is_claim_operator(&users[1], &users[0]));
+    assert!(liq_pool.claim_as_operator(&users[0], &users[1], &treasury) > 0);
+}
+
+#[test]
+fn test_claim_to_requires_user_auth() {
+    let setup = Setup::default();
+    setup.env.set_auths(&[]);
+    assert!(setup
+        .liq_pool
+        .try_claim_to(&setup.users[0], &setup.users[1])
+        .is_err());
+}
@@ -3,6 +3,7 @@ extern crate std;
 use crate::plane::{pool_plane, PoolPlaneClient};
 use crate::LiquidityPoolClient;
 use soroban_sdk::{
+    contract, contractimpl, symbol_short,
     testutils::{Address as _, Ledger, LedgerInfo},
     Address, BytesN, Env, Vec,
 };
@@ -168,6 +169,7 @@ pub fn create_liqpool_contract<'a>(
         token_wasm_hash,
         tokens,
         &fee_fraction,
+        &1_000_000_000_000,
         token_reward,
         &liqpool.address,
         plane,
@@ -175,6 +177,63 @@ pub fn create_liqpool_contract<'a>(
     liqpool
 }
 
+// Vote-escrow mock with directly settable user locks
+#[contract]
+pub struct MockVotingEscrow;
+
+#[contractimpl]
+impl MockVotingEscrow {
+    pub fn set_balance(e: Env, user: Address, amount: u128) {
+        let total = Self::total_supply(e.clone()) - Self::balance(e.clone(), user.clone());
+        e.storage().persistent().set(&user, &amount);
+        e.storage()
+            .instance()
+            .set(&symbol_short!("total"), &(total + amount));
+    }
+
+    pub fn balance(e: Env, user: Address) -> u128 {
+        e.storage().persistent().get(&user).unwrap_or(0)
+    }
+
+    pub fn total_supply(e: Env) -> u128 {
+        e.storage()
+            .instance()
+            .get(&symbol_short!("total"))
+            .unwrap_or(0)
+    }
+}
+
+pub fn create_voting_escrow_contract<'a>(e: &Env) -> MockVotingEscrowClient<'a> {
+    MockVotingEscrowClient::new(e, &e.register_contract(None, MockVotingEscrow {}))
+}
+
+// token which transfers nothing and fails every transfer while broken
+#[contract]
+pub struct MockBrokenToken;
+
+#[contractimpl]
+impl MockBrokenToken {
+    pub fn set_broken(e: Env, broken: bool) {
+        e.storage()
+            .instance()
+            .set(&symbol_short!("broken"), &broken);
+    }
+
+    pub fn transfer(e: Env, _from: Address, _to: Address, _amount: i128) {
+        if e.storage()
+            .instance()
+            .get(&symbol_short!("broken"))
+            .unwrap_or(false)
+        {
+            panic!("token is broken")
+        }
+    }
+}
+
+pub fn create_broken_token_contract<'a>(e: &Env) -> MockBrokenTokenClient<'a> {
+    MockBrokenTokenClient::new(e, &e.register_contract(None, MockBrokenToken {}))
+}
+
 pub fn install_token_wasm(e: &Env) -> BytesN<32> {
     e.deployer().upload_contract_wasm(WASM)
 }
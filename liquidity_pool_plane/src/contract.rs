@@ -1,5 +1,7 @@
-use crate::interface::PlaneInterface;
+use crate::interface::{AdminInterface, PlaneInterface};
 use crate::storage::{get, update, PoolPlane};
+use access_control::access::{AccessControl, AccessControlTrait};
+use access_control::interface::TransferableContract;
 use soroban_sdk::{contract, contractimpl, Address, Env, Symbol, Vec};
 
 #[contract]
@@ -31,3 +33,15 @@ impl PlaneInterface for LiquidityPoolPlane {
         result
     }
 }
+
+#[contractimpl]
+impl AdminInterface for LiquidityPoolPlane {
+    fn init_admin(e: Env, account: Address) {
+        let access_control = AccessControl::new(&e);
+        if !access_control.has_admin() {
+            access_control.set_admin(&account)
+        }
+    }
+}
+
+access_control::generate_transferable_contract!(LiquidityPoolPlane);
@@ -1,5 +1,10 @@
 use soroban_sdk::{Address, Env, Symbol, Vec};
 
+pub trait AdminInterface {
+    // Initialize admin user. Will panic if called twice
+    fn init_admin(e: Env, account: Address);
+}
+
 pub trait PlaneInterface {
     // update pool stored data. any pool can use it to store it's information
     fn update(e: Env, pool: Address, pool_type: Symbol, init_args: Vec<u128>, reserves: Vec<u128>);
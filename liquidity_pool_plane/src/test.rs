@@ -2,7 +2,7 @@
 extern crate std;
 
 use crate::{contract::LiquidityPoolPlane, LiquidityPoolPlaneClient};
-use soroban_sdk::testutils::Address as _;
+use soroban_sdk::testutils::{Address as _, Ledger};
 use soroban_sdk::{symbol_short, Address, Env, Vec};
 
 fn create_plane_contract<'a>(e: &Env) -> LiquidityPoolPlaneClient<'a> {
@@ -48,3 +48,23 @@ fn test() {
     );
     assert_eq!(data2.2, Vec::from_array(&e, [800_u128, 900_u128]));
 }
+
+#[test]
+#[should_panic(expected = "Error(Contract, #102)")]
+fn test_transfer_ownership() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().with_mut(|li| li.timestamp = 1_700_000_000);
+
+    let admin_original = Address::generate(&e);
+    let admin_new = Address::generate(&e);
+    let plane = create_plane_contract(&e);
+    plane.init_admin(&admin_original);
+
+    plane.commit_transfer_ownership(&admin_original, &admin_new);
+    e.ledger().with_mut(|li| li.timestamp += 3 * 86400);
+    plane.apply_transfer_ownership(&admin_original);
+
+    // previous admin has no access anymore
+    plane.set_transfer_delay(&admin_original, &86400);
+}
@@ -1,3 +1,13 @@
 pub(crate) const MAX_POOLS_FOR_PAIR: u32 = 10;
-pub(crate) const CONSTANT_PRODUCT_FEE_AVAILABLE: [u32; 3] = [10, 30, 100];
 pub(crate) const STABLESWAP_MAX_POOLS: u32 = 3;
+pub(crate) const MAX_POOLS_ADMIN_BATCH: u32 = 20;
+pub(crate) const MAX_CLAIM_POOLS: u32 = 10;
+pub(crate) const TOKEN_TOKENS_SETS_PAGE_SIZE: u128 = 50;
+pub(crate) const MAX_TOKEN_POOLS_PAGE: u128 = 20;
+
+// default pool creation policy values, used until admin configures policy for pool type
+pub(crate) const CONSTANT_PRODUCT_DEFAULT_FEE_FRACTIONS: [u32; 3] = [10, 30, 100];
+pub(crate) const CONSTANT_PRODUCT_MAX_FEE_FRACTION: u32 = 9999;
+pub(crate) const STABLESWAP_MAX_FEE_FRACTION: u32 = 5000;
+pub(crate) const STABLESWAP_MAX_A: u128 = 1_000_000;
+pub(crate) const STABLESWAP_MAX_ADMIN_FEE: u32 = 10000;
@@ -1,28 +1,42 @@
-use crate::constants::CONSTANT_PRODUCT_FEE_AVAILABLE;
-use crate::events::{Events, LiquidityPoolRouterEvents};
+use crate::constants::{MAX_POOLS_ADMIN_BATCH, MAX_TOKEN_POOLS_PAGE};
+use crate::events::{Events, IncentiveDeposit, LiquidityPoolRouterEvents};
+use crate::merkle_distributor::{MerkleClaim, MerkleDistributorClient};
 use crate::pool_interface::{
     LiquidityPoolInterfaceTrait, PoolPlaneInterface, PoolsManagementTrait, RewardsInterfaceTrait,
     SwapRouterInterface,
 };
 use crate::pool_utils::{
-    deploy_stableswap_pool, deploy_standard_pool, get_custom_salt, get_stableswap_pool_salt,
-    get_standard_pool_salt, pool_salt,
+    apply_pool_creation_policy, check_fee_fraction, check_stableswap_params, claim_pools_rewards,
+    deploy_stableswap_pool, deploy_standard_pool, get_custom_salt, get_pool_admin_account,
+    get_stableswap_pool_salt, get_standard_pool_salt, invoke_pool_admin_method, pool_salt,
+    require_active_pool,
 };
 use crate::rewards::get_rewards_manager;
-use crate::router_interface::{AdminInterface, UpgradeableContract};
+use crate::router_interface::{AdminInterface, PoolsAdminInterface, UpgradeableContract};
+use crate::staking_gauge::StakingGaugeClient;
 use crate::storage::{
-    add_pool, get_init_pool_payment_address, get_init_pool_payment_amount,
-    get_init_pool_payment_token, get_pool, get_pool_plane, get_pools_plain, get_swap_router,
-    has_pool, remove_pool, set_constant_product_pool_hash, set_init_pool_payment_address,
-    set_init_pool_payment_amount, set_init_pool_payment_token, set_pool_plane,
-    set_stableswap_pool_hash, set_swap_router, set_token_hash, LiquidityPoolType,
+    add_pool, get_merkle_distributor, get_pool, get_pool_creation_policy, get_pool_gauge,
+    get_pool_plane, get_pool_state, get_pool_version, get_pools, get_pools_count, get_pools_info,
+    get_pools_plain, get_swap_router, get_token_tokens_sets, get_token_tokens_sets_count,
+    get_tokens_set, get_tokens_sets_count, has_pool, index_legacy_pools, is_pool_managed,
+    remove_pool, remove_pool_gauge, set_constant_product_pool_hash, set_merkle_distributor,
+    set_pool_creation_policy, set_pool_gauge, set_pool_managed, set_pool_plane, set_pool_state,
+    set_pool_version, set_stableswap_pool_hash, set_swap_router, set_token_hash, LiquidityPoolInfo,
+    LiquidityPoolType, PoolCreationPayment, PoolCreationPolicy, PoolError, PoolState,
 };
 use crate::swap_router::SwapRouterClient;
 use access_control::access::{AccessControl, AccessControlTrait};
-use rewards::storage::RewardsStorageTrait;
+use access_control::interface::{RolesInterface, TransferableContract};
+use access_control::role::Role;
+use rewards::storage::{
+    Incentive, RewardsFunding, RewardsInfo, RewardsStorageTrait, ScheduledRewardConfig,
+    VolumeEpoch, VolumeMiningConfig,
+};
+use rewards::MAX_REWARD_PRECISION;
 use soroban_sdk::token::Client as SorobanTokenClient;
 use soroban_sdk::{
-    contract, contractimpl, symbol_short, Address, BytesN, Env, IntoVal, Map, Symbol, Val, Vec,
+    contract, contractimpl, panic_with_error, symbol_short, Address, BytesN, Env, Error, IntoVal,
+    Map, Symbol, Val, Vec,
 };
 use utils::utils::check_vec_ordered;
 
@@ -70,6 +84,7 @@ impl LiquidityPoolInterfaceTrait for LiquidityPoolRouter {
         user.require_auth();
 
         let pool_id = get_pool(&e, tokens.clone(), pool_index).expect("unable to find pool");
+        require_active_pool(&e, &pool_id);
 
         let (amounts, share_amount): (Vec<u128>, u128) = e.invoke_contract(
             &pool_id,
@@ -98,6 +113,7 @@ impl LiquidityPoolInterfaceTrait for LiquidityPoolRouter {
             panic!("tokens are not sorted")
         }
         let pool_id = get_pool(&e, tokens.clone(), pool_index.clone()).expect("Pool doesn't exist");
+        require_active_pool(&e, &pool_id);
         let tokens: Vec<Address> = Self::get_tokens(e.clone(), tokens.clone(), pool_index.clone());
 
         let out_amt = e.invoke_contract(
@@ -196,10 +212,224 @@ impl UpgradeableContract for LiquidityPoolRouter {
     fn upgrade(e: Env, new_wasm_hash: BytesN<32>) {
         let access_control = AccessControl::new(&e);
         access_control.require_admin();
+        Events::new(&e).upgrade(new_wasm_hash.clone());
         e.deployer().update_current_contract_wasm(new_wasm_hash);
     }
+
+    fn upgrade_pools(
+        e: Env,
+        pool_type: LiquidityPoolType,
+        tokens_count: u32,
+        offset: u128,
+        limit: u128,
+        new_wasm_hash: BytesN<32>,
+    ) -> Vec<(Address, u32)> {
+        let access_control = AccessControl::new(&e);
+        access_control.require_admin();
+
+        let mut result = Vec::new(&e);
+        let end = get_tokens_sets_count(&e).min(offset.saturating_add(limit));
+        for index in offset..end {
+            let tokens = get_tokens_set(&e, index);
+            if tokens.len() != tokens_count {
+                continue;
+            }
+            let salt = pool_salt(&e, tokens.clone());
+            for (_pool_index, data) in get_pools(&e, &salt) {
+                if data.pool_type != pool_type
+                    || get_pool_state(&e, &data.address) == PoolState::Removed
+                {
+                    continue;
+                }
+                let pool_id = data.address;
+                // pools deployed before upgrades were introduced don't export `upgrade`
+                // and pools with external admin can't be upgraded by the router. both are reported
+                let upgraded = e.try_invoke_contract::<(), Error>(
+                    &pool_id,
+                    &symbol_short!("upgrade"),
+                    Vec::from_array(&e, [new_wasm_hash.into_val(&e)]),
+                );
+                if upgraded.is_err() {
+                    Events::new(&e).upgrade_pool_failed(
+                        tokens.clone(),
+                        pool_id,
+                        new_wasm_hash.clone(),
+                    );
+                    continue;
+                }
+                let version: u32 =
+                    e.invoke_contract(&pool_id, &symbol_short!("version"), Vec::new(&e));
+                set_pool_version(&e, &pool_id, version);
+                Events::new(&e).upgrade_pool(
+                    tokens.clone(),
+                    pool_id.clone(),
+                    new_wasm_hash.clone(),
+                    version,
+                );
+                result.push_back((pool_id, version));
+            }
+        }
+        result
+    }
+
+    fn get_pool_version(e: Env, tokens: Vec<Address>, pool_index: BytesN<32>) -> u32 {
+        let pool_id = get_pool(&e, tokens, pool_index).expect("Pool doesn't exist");
+        match get_pool_version(&e, &pool_id) {
+            Some(version) => version,
+            None => e.invoke_contract(&pool_id, &symbol_short!("version"), Vec::new(&e)),
+        }
+    }
+}
+
+access_control::generate_transferable_contract!(LiquidityPoolRouter);
+
+#[contractimpl]
+impl PoolsAdminInterface for LiquidityPoolRouter {
+    fn commit_pools_admin(
+        e: Env,
+        admin: Address,
+        pools: Vec<(Vec<Address>, BytesN<32>)>,
+        new_admin: Address,
+    ) {
+        admin.require_auth();
+        let access_control = AccessControl::new(&e);
+        access_control.check_admin(&admin);
+        if pools.len() > MAX_POOLS_ADMIN_BATCH {
+            panic!("too many pools at once")
+        }
+
+        for (tokens, pool_index) in pools {
+            let pool_id = get_pool(&e, tokens.clone(), pool_index).expect("Pool doesn't exist");
+            if get_pool_admin_account(&e, &pool_id, &admin) == new_admin {
+                continue;
+            }
+
+            // pool keeps its own transfer delay
+            invoke_pool_admin_method(
+                &e,
+                &pool_id,
+                &admin,
+                "commit_transfer_ownership",
+                Vec::from_array(&e, [new_admin.into_val(&e)]),
+            );
+            Events::new(&e).commit_pool_admin(tokens, pool_id, new_admin.clone());
+        }
+    }
+
+    fn apply_pools_admin(e: Env, admin: Address, pools: Vec<(Vec<Address>, BytesN<32>)>) {
+        admin.require_auth();
+        let access_control = AccessControl::new(&e);
+        access_control.check_admin(&admin);
+        if pools.len() > MAX_POOLS_ADMIN_BATCH {
+            panic!("too many pools at once")
+        }
+
+        let router = e.current_contract_address();
+        for (tokens, pool_index) in pools {
+            let pool_id = get_pool(&e, tokens.clone(), pool_index).expect("Pool doesn't exist");
+            let new_admin: Option<Address> =
+                e.invoke_contract(&pool_id, &Symbol::new(&e, "get_future_admin"), Vec::new(&e));
+            let new_admin = new_admin.expect("no active transfer");
+            invoke_pool_admin_method(
+                &e,
+                &pool_id,
+                &admin,
+                "apply_transfer_ownership",
+                Vec::new(&e),
+            );
+            set_pool_managed(&e, &pool_id, new_admin == router);
+            Events::new(&e).set_pool_admin(tokens, pool_id, new_admin);
+        }
+    }
+
+    fn is_pool_managed(e: Env, tokens: Vec<Address>, pool_index: BytesN<32>) -> bool {
+        let pool_id = get_pool(&e, tokens, pool_index).expect("Pool doesn't exist");
+        is_pool_managed(&e, &pool_id)
+    }
+
+    fn kill_pool(e: Env, user: Address, tokens: Vec<Address>, pool_index: BytesN<32>) {
+        let access_control = AccessControl::new(&e);
+        access_control.require_role(Role::EmergencyPauseAdmin, &user);
+        let pool_id = get_pool(&e, tokens, pool_index).expect("Pool doesn't exist");
+        invoke_pool_admin_method(&e, &pool_id, &user, "kill_me", Vec::new(&e));
+    }
+
+    fn unkill_pool(e: Env, user: Address, tokens: Vec<Address>, pool_index: BytesN<32>) {
+        let access_control = AccessControl::new(&e);
+        access_control.require_role(Role::EmergencyPauseAdmin, &user);
+        let pool_id = get_pool(&e, tokens, pool_index).expect("Pool doesn't exist");
+        invoke_pool_admin_method(&e, &pool_id, &user, "unkill_me", Vec::new(&e));
+    }
+
+    fn commit_pool_fee(
+        e: Env,
+        user: Address,
+        tokens: Vec<Address>,
+        pool_index: BytesN<32>,
+        new_fee: u32,
+        new_admin_fee: u32,
+    ) {
+        let access_control = AccessControl::new(&e);
+        access_control.require_role(Role::OperationsAdmin, &user);
+        let pool_id = get_pool(&e, tokens, pool_index).expect("Pool doesn't exist");
+        invoke_pool_admin_method(
+            &e,
+            &pool_id,
+            &user,
+            "commit_new_fee",
+            Vec::from_array(&e, [new_fee.into_val(&e), new_admin_fee.into_val(&e)]),
+        );
+    }
+
+    fn apply_pool_fee(e: Env, user: Address, tokens: Vec<Address>, pool_index: BytesN<32>) {
+        let access_control = AccessControl::new(&e);
+        access_control.require_role(Role::OperationsAdmin, &user);
+        let pool_id = get_pool(&e, tokens, pool_index).expect("Pool doesn't exist");
+        invoke_pool_admin_method(&e, &pool_id, &user, "apply_new_fee", Vec::new(&e));
+    }
+
+    fn revert_pool_fee(e: Env, user: Address, tokens: Vec<Address>, pool_index: BytesN<32>) {
+        let access_control = AccessControl::new(&e);
+        access_control.require_role(Role::OperationsAdmin, &user);
+        let pool_id = get_pool(&e, tokens, pool_index).expect("Pool doesn't exist");
+        invoke_pool_admin_method(&e, &pool_id, &user, "revert_new_parameters", Vec::new(&e));
+    }
+
+    fn withdraw_pool_admin_fees(
+        e: Env,
+        admin: Address,
+        tokens: Vec<Address>,
+        pool_index: BytesN<32>,
+        to: Address,
+    ) -> Vec<u128> {
+        admin.require_auth();
+        let access_control = AccessControl::new(&e);
+        access_control.check_admin(&admin);
+        let pool_id = get_pool(&e, tokens.clone(), pool_index).expect("Pool doesn't exist");
+
+        // fees are sent to pool admin, forward them to destination
+        let account = get_pool_admin_account(&e, &pool_id, &admin);
+        let mut balances_before = Vec::new(&e);
+        for token in tokens.iter() {
+            balances_before.push_back(SorobanTokenClient::new(&e, &token).balance(&account));
+        }
+        invoke_pool_admin_method(&e, &pool_id, &admin, "withdraw_admin_fees", Vec::new(&e));
+
+        let mut result = Vec::new(&e);
+        for (i, token) in tokens.iter().enumerate() {
+            let token_client = SorobanTokenClient::new(&e, &token);
+            let amount = token_client.balance(&account) - balances_before.get_unchecked(i as u32);
+            if amount > 0 && account != to {
+                token_client.transfer(&account, &to, &amount);
+            }
+            result.push_back(amount as u128);
+        }
+        result
+    }
 }
 
+access_control::generate_roles_interface!(LiquidityPoolRouter);
+
 #[contractimpl]
 impl AdminInterface for LiquidityPoolRouter {
     fn init_admin(e: Env, account: Address) {
@@ -213,33 +443,67 @@ impl AdminInterface for LiquidityPoolRouter {
         let access_control = AccessControl::new(&e);
         access_control.require_admin();
         set_token_hash(&e, &new_hash);
+        Events::new(&e).set_token_hash(new_hash);
     }
 
     fn set_pool_hash(e: Env, new_hash: BytesN<32>) {
         let access_control = AccessControl::new(&e);
         access_control.require_admin();
         set_constant_product_pool_hash(&e, &new_hash);
+        Events::new(&e).set_pool_hash(new_hash);
     }
 
     fn set_stableswap_pool_hash(e: Env, num_tokens: u32, new_hash: BytesN<32>) {
         let access_control = AccessControl::new(&e);
         access_control.require_admin();
         set_stableswap_pool_hash(&e, num_tokens, &new_hash);
+        Events::new(&e).set_stableswap_pool_hash(num_tokens, new_hash);
     }
 
     fn configure_init_pool_payment(e: Env, token: Address, amount: u128, to: Address) {
         let access_control = AccessControl::new(&e);
         access_control.require_admin();
-        set_init_pool_payment_token(&e, &token);
-        set_init_pool_payment_amount(&e, &amount);
-        set_init_pool_payment_address(&e, &to);
+        let mut policy = get_pool_creation_policy(&e, LiquidityPoolType::StableSwap);
+        policy.payments = Vec::from_array(
+            &e,
+            [PoolCreationPayment {
+                token: token.clone(),
+                amount,
+                to: to.clone(),
+            }],
+        );
+        set_pool_creation_policy(&e, LiquidityPoolType::StableSwap, &policy);
+        Events::new(&e).configure_init_pool_payment(token, amount, to);
+    }
+
+    fn set_pool_creation_policy(e: Env, pool_type: LiquidityPoolType, policy: PoolCreationPolicy) {
+        let access_control = AccessControl::new(&e);
+        access_control.require_admin();
+        if pool_type != LiquidityPoolType::ConstantProduct
+            && pool_type != LiquidityPoolType::StableSwap
+        {
+            panic!("pool type doesn't support creation policy")
+        }
+        if policy.min_fee_fraction > policy.max_fee_fraction || policy.min_a > policy.max_a {
+            panic!("invalid bounds")
+        }
+        if policy.reward_precision == 0 || policy.reward_precision > MAX_REWARD_PRECISION {
+            panic!("reward precision is out of allowed range")
+        }
+        set_pool_creation_policy(&e, pool_type, &policy);
+        Events::new(&e).set_pool_creation_policy(pool_type, policy);
+    }
+
+    fn get_pool_creation_policy(e: Env, pool_type: LiquidityPoolType) -> PoolCreationPolicy {
+        get_pool_creation_policy(&e, pool_type)
     }
 
     fn set_reward_token(e: Env, reward_token: Address) {
         let access_control = AccessControl::new(&e);
         access_control.require_admin();
         let rewards = get_rewards_manager(&e);
-        rewards.storage().put_reward_token(reward_token);
+        rewards.storage().put_reward_token(reward_token.clone());
+        Events::new(&e).set_reward_token(reward_token);
     }
 }
 
@@ -253,24 +517,24 @@ impl RewardsInterfaceTrait for LiquidityPoolRouter {
         expired_at: u64,
         tps: u128,
     ) {
-        admin.require_auth();
         let access_control = AccessControl::new(&e);
-        access_control.require_admin();
+        access_control.require_role(Role::RewardsAdmin, &admin);
 
         let pool_id = get_pool(&e, tokens.clone(), pool_index.clone()).expect("Pool doesn't exist");
+        // rewards can only be stopped for inactive pool
+        if tps > 0 {
+            require_active_pool(&e, &pool_id);
+        }
 
-        e.invoke_contract::<Val>(
+        invoke_pool_admin_method(
+            &e,
             &pool_id,
-            &Symbol::new(&e, "set_rewards_config"),
-            Vec::from_array(
-                &e,
-                [
-                    admin.into_val(&e),
-                    expired_at.into_val(&e),
-                    tps.into_val(&e),
-                ],
-            ),
+            &admin,
+            "set_rewards_config",
+            Vec::from_array(&e, [expired_at.into_val(&e), tps.into_val(&e)]),
         );
+
+        Events::new(&e).set_rewards_config(tokens, admin, pool_id, expired_at, tps);
     }
 
     fn get_rewards_info(
@@ -278,7 +542,7 @@ impl RewardsInterfaceTrait for LiquidityPoolRouter {
         user: Address,
         tokens: Vec<Address>,
         pool_index: BytesN<32>,
-    ) -> Map<Symbol, i128> {
+    ) -> RewardsInfo {
         let pool_id = get_pool(&e, tokens, pool_index.clone()).expect("Pool doesn't exist");
 
         e.invoke_contract(
@@ -305,168 +569,1080 @@ impl RewardsInterfaceTrait for LiquidityPoolRouter {
 
     fn claim(e: Env, user: Address, tokens: Vec<Address>, pool_index: BytesN<32>) -> u128 {
         user.require_auth();
-        let pool_id = get_pool(&e, tokens, pool_index.clone()).expect("Pool doesn't exist");
+        let pool_id = get_pool(&e, tokens.clone(), pool_index.clone()).expect("Pool doesn't exist");
 
-        e.invoke_contract(
+        let reward: u128 = e.invoke_contract(
             &pool_id,
             &symbol_short!("claim"),
             Vec::from_array(&e, [user.clone().into_val(&e)]),
-        )
-    }
-}
+        );
 
-#[contractimpl]
-impl PoolsManagementTrait for LiquidityPoolRouter {
-    fn init_pool(e: Env, tokens: Vec<Address>) -> (BytesN<32>, Address) {
-        let salt = pool_salt(&e, tokens.clone());
-        let pools = get_pools_plain(&e, &salt);
-        if pools.is_empty() {
-            deploy_standard_pool(&e, tokens, 30)
-        } else {
-            let pool_hash = pools.keys().first().unwrap();
-            (pool_hash.clone(), pools.get(pool_hash).unwrap())
-        }
+        Events::new(&e).claim(tokens, user, pool_id, reward);
+        reward
     }
 
-    fn init_standard_pool(
+    fn claim_to(
         e: Env,
         user: Address,
         tokens: Vec<Address>,
-        fee_fraction: u32,
-    ) -> (BytesN<32>, Address) {
+        pool_index: BytesN<32>,
+        recipient: Address,
+    ) -> u128 {
         user.require_auth();
-        if !CONSTANT_PRODUCT_FEE_AVAILABLE.contains(&fee_fraction) {
-            panic!("non-standard fee");
-        }
+        let pool_id = get_pool(&e, tokens.clone(), pool_index.clone()).expect("Pool doesn't exist");
 
-        let salt = pool_salt(&e, tokens.clone());
-        let pools = get_pools_plain(&e, &salt);
-        let pool_index = get_standard_pool_salt(&e, &fee_fraction);
+        let reward: u128 = e.invoke_contract(
+            &pool_id,
+            &Symbol::new(&e, "claim_to"),
+            Vec::from_array(&e, [user.clone().into_val(&e), recipient.into_val(&e)]),
+        );
 
-        match pools.get(pool_index.clone()) {
-            Some(pool_address) => (pool_index, pool_address),
-            None => deploy_standard_pool(&e, tokens, fee_fraction),
-        }
+        Events::new(&e).claim_to(tokens, user, pool_id, recipient, reward);
+        reward
     }
 
-    fn init_stableswap_pool(
+    fn claim_as_operator(
         e: Env,
+        operator: Address,
         user: Address,
         tokens: Vec<Address>,
-        a: u128,
-        fee_fraction: u32,
-        admin_fee: u32,
-    ) -> (BytesN<32>, Address) {
-        user.require_auth();
-
-        // pay for pool creation
-        let init_pool_token = get_init_pool_payment_token(&e);
-        let init_pool_amount = get_init_pool_payment_amount(&e);
-        let init_pool_address = get_init_pool_payment_address(&e);
-        SorobanTokenClient::new(&e, &init_pool_token).transfer_from(
-            &e.current_contract_address(),
-            &user,
-            &init_pool_address,
-            &(init_pool_amount as i128),
+        pool_index: BytesN<32>,
+        recipient: Address,
+    ) -> u128 {
+        get_rewards_manager(&e).require_claim_allowed(&operator, &user);
+        let pool_id = get_pool(&e, tokens.clone(), pool_index.clone()).expect("Pool doesn't exist");
+        let pool_reward_storage: Address = e.invoke_contract(
+            &pool_id,
+            &Symbol::new(&e, "get_reward_storage"),
+            Vec::new(&e),
         );
 
-        let salt = pool_salt(&e, tokens.clone());
-        let pools = get_pools_plain(&e, &salt);
-        let pool_index = get_stableswap_pool_salt(&e);
+        let reward: u128 = if pool_reward_storage == e.current_contract_address() {
+            // router is the reward storage - collect reward and pay it out directly
+            let pool_reward_token: Address =
+                e.invoke_contract(&pool_id, &Symbol::new(&e, "get_reward_token"), Vec::new(&e));
+            let reward: u128 = e.invoke_contract(
+                &pool_id,
+                &Symbol::new(&e, "collect_reward"),
+                Vec::from_array(&e, [user.clone().into_val(&e)]),
+            );
+            if reward > 0 {
+                SorobanTokenClient::new(&e, &pool_reward_token).transfer(
+                    &e.current_contract_address(),
+                    &recipient,
+                    &(reward as i128),
+                );
+            }
+            reward
+        } else {
+            e.invoke_contract(
+                &pool_id,
+                &Symbol::new(&e, "claim_as_operator"),
+                Vec::from_array(
+                    &e,
+                    [
+                        // router is the pool admin trusted to check operator approval
+                        e.current_contract_address().into_val(&e),
+                        user.clone().into_val(&e),
+                        recipient.clone().into_val(&e),
+                    ],
+                ),
+            )
+        };
+
+        Events::new(&e).claim_to(tokens, user, pool_id, recipient, reward);
+        reward
+    }
 
-        match pools.get(pool_index.clone()) {
-            Some(pool_address) => (pool_index, pool_address),
-            None => deploy_stableswap_pool(&e, tokens, a, fee_fraction, admin_fee),
-        }
+    fn set_claim_operator(e: Env, user: Address, operator: Address, approved: bool) {
+        user.require_auth();
+        get_rewards_manager(&e).set_claim_operator(&user, &operator, approved);
+        Events::new(&e).set_claim_operator(user, operator, approved);
     }
 
-    fn get_pools(e: Env, tokens: Vec<Address>) -> Map<BytesN<32>, Address> {
-        let salt = pool_salt(&e, tokens);
-        get_pools_plain(&e, &salt)
+    fn is_claim_operator(e: Env, user: Address, operator: Address) -> bool {
+        get_rewards_manager(&e).is_claim_operator(&user, &operator)
     }
 
-    fn add_custom_pool(
+    fn add_reward_stream(
         e: Env,
-        user: Address,
+        admin: Address,
         tokens: Vec<Address>,
-        pool_address: Address,
-        pool_type: Symbol,
-        init_args: Vec<Val>,
-    ) -> BytesN<32> {
+        pool_index: BytesN<32>,
+        reward_token: Address,
+        reward_storage: Address,
+    ) -> u32 {
         let access_control = AccessControl::new(&e);
-        user.require_auth();
-        access_control.check_admin(&user);
-        let salt = pool_salt(&e, tokens.clone());
-        let subpool_salt = get_custom_salt(&e, &pool_type, &init_args);
-
-        if has_pool(&e, &salt, subpool_salt.clone()) {
-            panic!("pool already exists")
-        }
+        access_control.require_role(Role::RewardsAdmin, &admin);
 
-        add_pool(
-            &e,
-            &salt,
-            subpool_salt.clone(),
-            LiquidityPoolType::Custom,
-            pool_address.clone(),
+        let pool_id = get_pool(&e, tokens.clone(), pool_index).expect("Pool doesn't exist");
+        let stream: u32 = e.invoke_contract(
+            &pool_id,
+            &Symbol::new(&e, "add_reward_stream"),
+            Vec::from_array(
+                &e,
+                [
+                    get_pool_admin_account(&e, &pool_id, &admin).into_val(&e),
+                    reward_token.into_val(&e),
+                    reward_storage.into_val(&e),
+                ],
+            ),
         );
 
-        Events::new(&e).add_pool(
+        Events::new(&e).add_reward_stream(
             tokens,
-            pool_address,
-            pool_type,
-            subpool_salt.clone(),
-            init_args,
+            admin,
+            pool_id,
+            stream,
+            reward_token,
+            reward_storage,
         );
-        subpool_salt
+        stream
     }
 
-    fn remove_pool(e: Env, user: Address, tokens: Vec<Address>, pool_hash: BytesN<32>) {
+    fn set_stream_rewards_config(
+        e: Env,
+        admin: Address,
+        tokens: Vec<Address>,
+        pool_index: BytesN<32>,
+        stream: u32,
+        expired_at: u64,
+        tps: u128,
+    ) {
         let access_control = AccessControl::new(&e);
-        user.require_auth();
-        access_control.check_admin(&user);
-        let salt = pool_salt(&e, tokens.clone());
-        if has_pool(&e, &salt, pool_hash.clone()) {
-            remove_pool(&e, &salt, pool_hash)
-        }
-    }
-}
+        access_control.require_role(Role::RewardsAdmin, &admin);
 
-#[contractimpl]
-impl PoolPlaneInterface for LiquidityPoolRouter {
-    fn set_pools_plane(e: Env, admin: Address, plane: Address) {
-        let access_control = AccessControl::new(&e);
-        admin.require_auth();
-        access_control.check_admin(&admin);
+        let pool_id = get_pool(&e, tokens.clone(), pool_index).expect("Pool doesn't exist");
+        // rewards can only be stopped for inactive pool
+        if tps > 0 {
+            require_active_pool(&e, &pool_id);
+        }
 
-        set_pool_plane(&e, &plane);
-    }
+        invoke_pool_admin_method(
+            &e,
+            &pool_id,
+            &admin,
+            "set_stream_rewards_config",
+            Vec::from_array(
+                &e,
+                [
+                    stream.into_val(&e),
+                    expired_at.into_val(&e),
+                    tps.into_val(&e),
+                ],
+            ),
+        );
 
-    fn get_plane(e: Env) -> Address {
-        get_pool_plane(&e)
+        Events::new(&e).set_stream_rewards_config(tokens, admin, pool_id, stream, expired_at, tps);
     }
-}
 
-#[contractimpl]
-impl SwapRouterInterface for LiquidityPoolRouter {
-    fn set_swap_router(e: Env, admin: Address, router: Address) {
-        let access_control = AccessControl::new(&e);
-        admin.require_auth();
-        access_control.check_admin(&admin);
-        set_swap_router(&e, &router);
-    }
+    fn get_all_rewards_info(
+        e: Env,
+        user: Address,
+        tokens: Vec<Address>,
+        pool_index: BytesN<32>,
+    ) -> Vec<(Address, RewardsInfo)> {
+        let pool_id = get_pool(&e, tokens, pool_index).expect("Pool doesn't exist");
 
-    fn get_swap_router(e: Env) -> Address {
-        get_swap_router(&e)
+        e.invoke_contract(
+            &pool_id,
+            &Symbol::new(&e, "get_all_rewards_info"),
+            Vec::from_array(&e, [user.into_val(&e)]),
+        )
     }
 
-    fn estimate_swap_routed(
+    fn get_all_user_rewards(
         e: Env,
+        user: Address,
         tokens: Vec<Address>,
-        token_in: Address,
-        token_out: Address,
-        in_amount: u128,
-    ) -> (BytesN<32>, Address, u128) {
+        pool_index: BytesN<32>,
+    ) -> Vec<u128> {
+        let pool_id = get_pool(&e, tokens, pool_index).expect("Pool doesn't exist");
+
+        e.invoke_contract(
+            &pool_id,
+            &Symbol::new(&e, "get_all_user_rewards"),
+            Vec::from_array(&e, [user.into_val(&e)]),
+        )
+    }
+
+    fn claim_all_rewards(
+        e: Env,
+        user: Address,
+        tokens: Vec<Address>,
+        pool_index: BytesN<32>,
+    ) -> Vec<u128> {
+        user.require_auth();
+        let pool_id = get_pool(&e, tokens.clone(), pool_index).expect("Pool doesn't exist");
+
+        let rewards: Vec<u128> = e.invoke_contract(
+            &pool_id,
+            &Symbol::new(&e, "claim_all_rewards"),
+            Vec::from_array(&e, [user.clone().into_val(&e)]),
+        );
+
+        Events::new(&e).claim_all_rewards(tokens, user, pool_id, rewards.clone());
+        rewards
+    }
+
+    fn set_pool_voting_escrow(
+        e: Env,
+        admin: Address,
+        tokens: Vec<Address>,
+        pool_index: BytesN<32>,
+        voting_escrow: Address,
+    ) {
+        let access_control = AccessControl::new(&e);
+        access_control.require_role(Role::RewardsAdmin, &admin);
+
+        let pool_id = get_pool(&e, tokens.clone(), pool_index).expect("Pool doesn't exist");
+        invoke_pool_admin_method(
+            &e,
+            &pool_id,
+            &admin,
+            "set_voting_escrow",
+            Vec::from_array(&e, [voting_escrow.clone().into_val(&e)]),
+        );
+
+        Events::new(&e).set_pool_voting_escrow(tokens, admin, pool_id, voting_escrow);
+    }
+
+    fn enable_pool_rewards_checkpoint(
+        e: Env,
+        admin: Address,
+        tokens: Vec<Address>,
+        pool_index: BytesN<32>,
+    ) {
+        let access_control = AccessControl::new(&e);
+        access_control.require_role(Role::RewardsAdmin, &admin);
+
+        let pool_id = get_pool(&e, tokens.clone(), pool_index).expect("Pool doesn't exist");
+        invoke_pool_admin_method(
+            &e,
+            &pool_id,
+            &admin,
+            "enable_rewards_checkpoint",
+            Vec::new(&e),
+        );
+
+        Events::new(&e).enable_pool_rewards_checkpoint(tokens, admin, pool_id);
+    }
+
+    fn set_pool_reward_precision(
+        e: Env,
+        admin: Address,
+        tokens: Vec<Address>,
+        pool_index: BytesN<32>,
+        precision: u128,
+    ) {
+        let access_control = AccessControl::new(&e);
+        access_control.require_role(Role::RewardsAdmin, &admin);
+
+        let pool_id = get_pool(&e, tokens.clone(), pool_index).expect("Pool doesn't exist");
+        invoke_pool_admin_method(
+            &e,
+            &pool_id,
+            &admin,
+            "set_reward_precision",
+            Vec::from_array(&e, [precision.into_val(&e)]),
+        );
+
+        Events::new(&e).set_pool_reward_precision(tokens, admin, pool_id, precision);
+    }
+
+    fn schedule_rewards_config(
+        e: Env,
+        admin: Address,
+        tokens: Vec<Address>,
+        pool_index: BytesN<32>,
+        stream: u32,
+        config: ScheduledRewardConfig,
+    ) {
+        let access_control = AccessControl::new(&e);
+        access_control.require_role(Role::RewardsAdmin, &admin);
+
+        let pool_id = get_pool(&e, tokens.clone(), pool_index).expect("Pool doesn't exist");
+        if config.tps > 0 {
+            require_active_pool(&e, &pool_id);
+        }
+
+        invoke_pool_admin_method(
+            &e,
+            &pool_id,
+            &admin,
+            "schedule_rewards_config",
+            Vec::from_array(
+                &e,
+                [
+                    stream.into_val(&e),
+                    config.start_at.into_val(&e),
+                    config.expired_at.into_val(&e),
+                    config.tps.into_val(&e),
+                ],
+            ),
+        );
+
+        Events::new(&e).schedule_rewards_config(tokens, admin, pool_id, stream, config);
+    }
+
+    fn cancel_scheduled_rewards_config(
+        e: Env,
+        admin: Address,
+        tokens: Vec<Address>,
+        pool_index: BytesN<32>,
+        stream: u32,
+        start_at: u64,
+    ) {
+        let access_control = AccessControl::new(&e);
+        access_control.require_role(Role::RewardsAdmin, &admin);
+
+        let pool_id = get_pool(&e, tokens.clone(), pool_index).expect("Pool doesn't exist");
+        invoke_pool_admin_method(
+            &e,
+            &pool_id,
+            &admin,
+            "cancel_scheduled_rewards_config",
+            Vec::from_array(&e, [stream.into_val(&e), start_at.into_val(&e)]),
+        );
+
+        Events::new(&e).cancel_scheduled_rewards_config(tokens, admin, pool_id, stream, start_at);
+    }
+
+    fn get_scheduled_rewards_configs(
+        e: Env,
+        tokens: Vec<Address>,
+        pool_index: BytesN<32>,
+        stream: u32,
+    ) -> Vec<ScheduledRewardConfig> {
+        let pool_id = get_pool(&e, tokens, pool_index).expect("Pool doesn't exist");
+        e.invoke_contract(
+            &pool_id,
+            &Symbol::new(&e, "get_scheduled_rewards_configs"),
+            Vec::from_array(&e, [stream.into_val(&e)]),
+        )
+    }
+
+    fn stop_rewards(
+        e: Env,
+        admin: Address,
+        tokens: Vec<Address>,
+        pool_index: BytesN<32>,
+        stream: u32,
+    ) {
+        let access_control = AccessControl::new(&e);
+        access_control.require_role(Role::RewardsAdmin, &admin);
+
+        let pool_id = get_pool(&e, tokens.clone(), pool_index).expect("Pool doesn't exist");
+        invoke_pool_admin_method(
+            &e,
+            &pool_id,
+            &admin,
+            "stop_rewards",
+            Vec::from_array(&e, [stream.into_val(&e)]),
+        );
+
+        Events::new(&e).stop_rewards(tokens, admin, pool_id, stream);
+    }
+
+    fn get_rewards_funding(
+        e: Env,
+        tokens: Vec<Address>,
+        pool_index: BytesN<32>,
+        stream: u32,
+    ) -> RewardsFunding {
+        let pool_id = get_pool(&e, tokens, pool_index).expect("Pool doesn't exist");
+        e.invoke_contract(
+            &pool_id,
+            &Symbol::new(&e, "get_rewards_funding"),
+            Vec::from_array(&e, [stream.into_val(&e)]),
+        )
+    }
+
+    fn get_rewards_tps_per_share(
+        e: Env,
+        tokens: Vec<Address>,
+        pool_index: BytesN<32>,
+        stream: u32,
+    ) -> u128 {
+        let pool_id = get_pool(&e, tokens, pool_index).expect("Pool doesn't exist");
+        e.invoke_contract(
+            &pool_id,
+            &Symbol::new(&e, "get_rewards_tps_per_share"),
+            Vec::from_array(&e, [stream.into_val(&e)]),
+        )
+    }
+
+    fn get_remaining_emission(
+        e: Env,
+        tokens: Vec<Address>,
+        pool_index: BytesN<32>,
+        stream: u32,
+    ) -> u128 {
+        let pool_id = get_pool(&e, tokens, pool_index).expect("Pool doesn't exist");
+        e.invoke_contract(
+            &pool_id,
+            &Symbol::new(&e, "get_remaining_emission"),
+            Vec::from_array(&e, [stream.into_val(&e)]),
+        )
+    }
+
+    fn reclaim_unallocated_rewards(
+        e: Env,
+        admin: Address,
+        tokens: Vec<Address>,
+        pool_index: BytesN<32>,
+        stream: u32,
+        to: Address,
+    ) -> u128 {
+        let access_control = AccessControl::new(&e);
+        access_control.require_role(Role::RewardsAdmin, &admin);
+
+        let pool_id = get_pool(&e, tokens.clone(), pool_index).expect("Pool doesn't exist");
+        let amount: u128 = e.invoke_contract(
+            &pool_id,
+            &Symbol::new(&e, "reclaim_unallocated"),
+            Vec::from_array(
+                &e,
+                [
+                    get_pool_admin_account(&e, &pool_id, &admin).into_val(&e),
+                    stream.into_val(&e),
+                    to.clone().into_val(&e),
+                ],
+            ),
+        );
+
+        Events::new(&e).reclaim_unallocated_rewards(tokens, admin, pool_id, stream, to, amount);
+        amount
+    }
+
+    fn deposit_incentive(
+        e: Env,
+        depositor: Address,
+        tokens: Vec<Address>,
+        pool_index: BytesN<32>,
+        token: Address,
+        amount: u128,
+        duration: u64,
+    ) -> u32 {
+        depositor.require_auth();
+        let pool_id = get_pool(&e, tokens.clone(), pool_index).expect("Pool doesn't exist");
+        require_active_pool(&e, &pool_id);
+
+        let stream: u32 = e.invoke_contract(
+            &pool_id,
+            &Symbol::new(&e, "deposit_incentive"),
+            Vec::from_array(
+                &e,
+                [
+                    depositor.clone().into_val(&e),
+                    token.clone().into_val(&e),
+                    amount.into_val(&e),
+                    duration.into_val(&e),
+                ],
+            ),
+        );
+
+        Events::new(&e).deposit_incentive(
+            tokens,
+            IncentiveDeposit {
+                depositor,
+                pool_id,
+                stream,
+                token,
+                amount,
+                duration,
+            },
+        );
+        stream
+    }
+
+    fn set_pool_incentive_token(
+        e: Env,
+        admin: Address,
+        tokens: Vec<Address>,
+        pool_index: BytesN<32>,
+        token: Address,
+        min_amount: Option<u128>,
+    ) {
+        let access_control = AccessControl::new(&e);
+        access_control.require_role(Role::RewardsAdmin, &admin);
+
+        let pool_id = get_pool(&e, tokens.clone(), pool_index).expect("Pool doesn't exist");
+        invoke_pool_admin_method(
+            &e,
+            &pool_id,
+            &admin,
+            "set_incentive_token",
+            Vec::from_array(&e, [token.clone().into_val(&e), min_amount.into_val(&e)]),
+        );
+
+        Events::new(&e).set_pool_incentive_token(tokens, admin, pool_id, token, min_amount);
+    }
+
+    fn get_pool_incentive_min_amount(
+        e: Env,
+        tokens: Vec<Address>,
+        pool_index: BytesN<32>,
+        token: Address,
+    ) -> Option<u128> {
+        let pool_id = get_pool(&e, tokens, pool_index).expect("Pool doesn't exist");
+        e.invoke_contract(
+            &pool_id,
+            &Symbol::new(&e, "get_incentive_min_amount"),
+            Vec::from_array(&e, [token.into_val(&e)]),
+        )
+    }
+
+    fn settle_incentive(e: Env, tokens: Vec<Address>, pool_index: BytesN<32>, stream: u32) -> u128 {
+        let pool_id = get_pool(&e, tokens.clone(), pool_index.clone()).expect("Pool doesn't exist");
+        let incentive = Self::get_incentive(e.clone(), tokens.clone(), pool_index, stream)
+            .expect("no incentive in the stream");
+        let leftover: u128 = e.invoke_contract(
+            &pool_id,
+            &Symbol::new(&e, "settle_incentive"),
+            Vec::from_array(&e, [stream.into_val(&e)]),
+        );
+
+        Events::new(&e).settle_incentive(tokens, incentive.depositor, pool_id, stream, leftover);
+        leftover
+    }
+
+    fn get_incentive(
+        e: Env,
+        tokens: Vec<Address>,
+        pool_index: BytesN<32>,
+        stream: u32,
+    ) -> Option<Incentive> {
+        let pool_id = get_pool(&e, tokens, pool_index).expect("Pool doesn't exist");
+        e.invoke_contract(
+            &pool_id,
+            &Symbol::new(&e, "get_incentive"),
+            Vec::from_array(&e, [stream.into_val(&e)]),
+        )
+    }
+
+    fn set_volume_mining_config(
+        e: Env,
+        admin: Address,
+        tokens: Vec<Address>,
+        pool_index: BytesN<32>,
+        epoch_length: u64,
+        budget: u128,
+    ) {
+        let access_control = AccessControl::new(&e);
+        access_control.require_role(Role::RewardsAdmin, &admin);
+
+        let pool_id = get_pool(&e, tokens.clone(), pool_index).expect("Pool doesn't exist");
+        invoke_pool_admin_method(
+            &e,
+            &pool_id,
+            &admin,
+            "set_volume_mining_config",
+            Vec::from_array(&e, [epoch_length.into_val(&e), budget.into_val(&e)]),
+        );
+
+        Events::new(&e).set_volume_mining_config(tokens, admin, pool_id, epoch_length, budget);
+    }
+
+    fn get_volume_mining_config(
+        e: Env,
+        tokens: Vec<Address>,
+        pool_index: BytesN<32>,
+    ) -> Option<VolumeMiningConfig> {
+        let pool_id = get_pool(&e, tokens, pool_index).expect("Pool doesn't exist");
+        e.invoke_contract(
+            &pool_id,
+            &Symbol::new(&e, "get_volume_mining_config"),
+            Vec::new(&e),
+        )
+    }
+
+    fn get_volume_epochs_count(e: Env, tokens: Vec<Address>, pool_index: BytesN<32>) -> u32 {
+        let pool_id = get_pool(&e, tokens, pool_index).expect("Pool doesn't exist");
+        e.invoke_contract(
+            &pool_id,
+            &Symbol::new(&e, "get_volume_epochs_count"),
+            Vec::new(&e),
+        )
+    }
+
+    fn get_volume_epoch(
+        e: Env,
+        tokens: Vec<Address>,
+        pool_index: BytesN<32>,
+        epoch: u32,
+    ) -> Option<VolumeEpoch> {
+        let pool_id = get_pool(&e, tokens, pool_index).expect("Pool doesn't exist");
+        e.invoke_contract(
+            &pool_id,
+            &Symbol::new(&e, "get_volume_epoch"),
+            Vec::from_array(&e, [epoch.into_val(&e)]),
+        )
+    }
+
+    fn get_user_volume_points(
+        e: Env,
+        user: Address,
+        tokens: Vec<Address>,
+        pool_index: BytesN<32>,
+        epoch: u32,
+    ) -> u128 {
+        let pool_id = get_pool(&e, tokens, pool_index).expect("Pool doesn't exist");
+        e.invoke_contract(
+            &pool_id,
+            &Symbol::new(&e, "get_user_volume_points"),
+            Vec::from_array(&e, [user.into_val(&e), epoch.into_val(&e)]),
+        )
+    }
+
+    fn get_user_volume_reward(
+        e: Env,
+        user: Address,
+        tokens: Vec<Address>,
+        pool_index: BytesN<32>,
+        epoch: u32,
+    ) -> u128 {
+        let pool_id = get_pool(&e, tokens, pool_index).expect("Pool doesn't exist");
+        e.invoke_contract(
+            &pool_id,
+            &Symbol::new(&e, "get_user_volume_reward"),
+            Vec::from_array(&e, [user.into_val(&e), epoch.into_val(&e)]),
+        )
+    }
+
+    fn claim_volume_reward(
+        e: Env,
+        user: Address,
+        tokens: Vec<Address>,
+        pool_index: BytesN<32>,
+        epoch: u32,
+    ) -> u128 {
+        user.require_auth();
+        let pool_id = get_pool(&e, tokens.clone(), pool_index).expect("Pool doesn't exist");
+        let amount: u128 = e.invoke_contract(
+            &pool_id,
+            &Symbol::new(&e, "claim_volume_reward"),
+            Vec::from_array(&e, [user.clone().into_val(&e), epoch.into_val(&e)]),
+        );
+
+        Events::new(&e).claim_volume_reward(tokens, user, pool_id, epoch, amount);
+        amount
+    }
+
+    fn sweep_pool_volume_epoch(
+        e: Env,
+        tokens: Vec<Address>,
+        pool_index: BytesN<32>,
+        epoch: u32,
+    ) -> u128 {
+        let pool_id = get_pool(&e, tokens.clone(), pool_index).expect("Pool doesn't exist");
+        let amount: u128 = e.invoke_contract(
+            &pool_id,
+            &Symbol::new(&e, "sweep_volume_epoch"),
+            Vec::from_array(&e, [epoch.into_val(&e)]),
+        );
+
+        Events::new(&e).sweep_volume_epoch(tokens, pool_id, epoch, amount);
+        amount
+    }
+
+    fn set_pool_gauge(
+        e: Env,
+        admin: Address,
+        tokens: Vec<Address>,
+        pool_index: BytesN<32>,
+        gauge: Address,
+    ) {
+        let access_control = AccessControl::new(&e);
+        access_control.require_role(Role::RewardsAdmin, &admin);
+
+        let pool_id = get_pool(&e, tokens.clone(), pool_index).expect("Pool doesn't exist");
+        let share_token: Address =
+            e.invoke_contract(&pool_id, &Symbol::new(&e, "share_id"), Vec::new(&e));
+        if StakingGaugeClient::new(&e, &gauge).get_lp_token() != share_token {
+            panic!("gauge should stake pool share token")
+        }
+        set_pool_gauge(&e, &pool_id, &gauge);
+
+        Events::new(&e).set_pool_gauge(tokens, admin, pool_id, gauge);
+    }
+
+    fn remove_pool_gauge(e: Env, admin: Address, tokens: Vec<Address>, pool_index: BytesN<32>) {
+        let access_control = AccessControl::new(&e);
+        access_control.require_role(Role::RewardsAdmin, &admin);
+
+        let pool_id = get_pool(&e, tokens.clone(), pool_index).expect("Pool doesn't exist");
+        if get_pool_gauge(&e, &pool_id).is_none() {
+            panic!("pool has no gauge")
+        }
+        remove_pool_gauge(&e, &pool_id);
+
+        Events::new(&e).remove_pool_gauge(tokens, admin, pool_id);
+    }
+
+    fn get_pool_gauge(e: Env, tokens: Vec<Address>, pool_index: BytesN<32>) -> Option<Address> {
+        let pool_id = get_pool(&e, tokens, pool_index).expect("Pool doesn't exist");
+        get_pool_gauge(&e, &pool_id)
+    }
+
+    fn get_total_user_reward(
+        e: Env,
+        user: Address,
+        pools: Vec<(Vec<Address>, BytesN<32>)>,
+    ) -> Vec<u128> {
+        let mut result = Vec::new(&e);
+        for (tokens, pool_index) in pools {
+            result.push_back(Self::get_user_reward(
+                e.clone(),
+                user.clone(),
+                tokens,
+                pool_index,
+            ));
+        }
+        result
+    }
+
+    fn claim_all(e: Env, user: Address, pools: Vec<(Vec<Address>, BytesN<32>)>) -> Vec<u128> {
+        user.require_auth();
+        claim_pools_rewards(&e, &user, pools)
+    }
+
+    fn set_merkle_distributor(e: Env, admin: Address, distributor: Address) {
+        let access_control = AccessControl::new(&e);
+        admin.require_auth();
+        access_control.check_admin(&admin);
+        set_merkle_distributor(&e, &distributor);
+        Events::new(&e).set_merkle_distributor(distributor);
+    }
+
+    fn get_merkle_distributor(e: Env) -> Option<Address> {
+        get_merkle_distributor(&e)
+    }
+
+    fn claim_all_with_distributions(
+        e: Env,
+        user: Address,
+        pools: Vec<(Vec<Address>, BytesN<32>)>,
+        claims: Vec<MerkleClaim>,
+    ) -> (Vec<u128>, Vec<u128>) {
+        user.require_auth();
+        // router reward token may be not configured if only distributions are claimed
+        let pools_rewards = match pools.is_empty() {
+            true => Vec::new(&e),
+            false => claim_pools_rewards(&e, &user, pools),
+        };
+
+        let mut distributed = Vec::new(&e);
+        if !claims.is_empty() {
+            let distributor = MerkleDistributorClient::new(
+                &e,
+                &get_merkle_distributor(&e).expect("merkle distributor not set"),
+            );
+            for claim in claims {
+                distributed.push_back(distributor.claim(
+                    &claim.epoch,
+                    &claim.index,
+                    &user,
+                    &claim.amount,
+                    &claim.proof,
+                ));
+            }
+        }
+        (pools_rewards, distributed)
+    }
+}
+
+#[contractimpl]
+impl PoolsManagementTrait for LiquidityPoolRouter {
+    fn init_pool(e: Env, tokens: Vec<Address>) -> (BytesN<32>, Address) {
+        let salt = pool_salt(&e, tokens.clone());
+        let pools = get_pools_plain(&e, &salt);
+        if pools.is_empty() {
+            let policy = get_pool_creation_policy(&e, LiquidityPoolType::ConstantProduct);
+            // caller is unknown, restricted creation goes through operations admin check
+            if !policy.permissionless {
+                panic!("pool creation is restricted, use init_standard_pool")
+            }
+            if policy.payments.iter().any(|payment| payment.amount > 0) {
+                panic!("pool creation payment required, use init_standard_pool")
+            }
+            check_fee_fraction(&policy, 30);
+            deploy_standard_pool(&e, tokens, 30, policy.reward_precision)
+        } else {
+            let pool_hash = pools.keys().first().unwrap();
+            (pool_hash.clone(), pools.get(pool_hash).unwrap())
+        }
+    }
+
+    fn init_standard_pool(
+        e: Env,
+        user: Address,
+        tokens: Vec<Address>,
+        fee_fraction: u32,
+    ) -> (BytesN<32>, Address) {
+        user.require_auth();
+        let policy = get_pool_creation_policy(&e, LiquidityPoolType::ConstantProduct);
+        check_fee_fraction(&policy, fee_fraction);
+
+        let salt = pool_salt(&e, tokens.clone());
+        let pools = get_pools_plain(&e, &salt);
+        let pool_index = get_standard_pool_salt(&e, &fee_fraction);
+
+        match pools.get(pool_index.clone()) {
+            Some(pool_address) => (pool_index, pool_address),
+            None => {
+                apply_pool_creation_policy(&e, &user, &policy);
+                deploy_standard_pool(&e, tokens, fee_fraction, policy.reward_precision)
+            }
+        }
+    }
+
+    fn init_stableswap_pool(
+        e: Env,
+        user: Address,
+        tokens: Vec<Address>,
+        a: u128,
+        fee_fraction: u32,
+        admin_fee: u32,
+    ) -> (BytesN<32>, Address) {
+        user.require_auth();
+        let policy = get_pool_creation_policy(&e, LiquidityPoolType::StableSwap);
+        check_fee_fraction(&policy, fee_fraction);
+        check_stableswap_params(&policy, a, admin_fee);
+
+        // pay for pool creation
+        apply_pool_creation_policy(&e, &user, &policy);
+
+        let salt = pool_salt(&e, tokens.clone());
+        let pools = get_pools_plain(&e, &salt);
+        let pool_index = get_stableswap_pool_salt(&e);
+
+        match pools.get(pool_index.clone()) {
+            Some(pool_address) => (pool_index, pool_address),
+            None => deploy_stableswap_pool(
+                &e,
+                tokens,
+                a,
+                fee_fraction,
+                admin_fee,
+                policy.reward_precision,
+            ),
+        }
+    }
+
+    fn get_pools(e: Env, tokens: Vec<Address>) -> Map<BytesN<32>, Address> {
+        let salt = pool_salt(&e, tokens);
+        get_pools_plain(&e, &salt)
+    }
+
+    fn get_pools_with_info(e: Env, tokens: Vec<Address>) -> Map<BytesN<32>, LiquidityPoolInfo> {
+        let salt = pool_salt(&e, tokens);
+        get_pools_info(&e, &salt)
+    }
+
+    fn find_pool(
+        e: Env,
+        tokens: Vec<Address>,
+        pool_type: Symbol,
+        params: Vec<Val>,
+    ) -> LiquidityPoolInfo {
+        let salt = pool_salt(&e, tokens);
+        for (_pool_index, info) in get_pools_info(&e, &salt) {
+            if info.pool_type_name == pool_type && info.init_args == params {
+                return info;
+            }
+        }
+        panic_with_error!(&e, PoolError::PoolNotFound)
+    }
+
+    fn add_custom_pool(
+        e: Env,
+        user: Address,
+        tokens: Vec<Address>,
+        pool_address: Address,
+        pool_type: Symbol,
+        init_args: Vec<Val>,
+    ) -> BytesN<32> {
+        let access_control = AccessControl::new(&e);
+        user.require_auth();
+        access_control.check_role(Role::OperationsAdmin, &user);
+        let salt = pool_salt(&e, tokens.clone());
+        let subpool_salt = get_custom_salt(&e, &pool_type, &init_args);
+
+        if has_pool(&e, &salt, subpool_salt.clone()) {
+            panic!("pool already exists")
+        }
+
+        add_pool(
+            &e,
+            &tokens,
+            &salt,
+            &LiquidityPoolInfo {
+                pool_index: subpool_salt.clone(),
+                pool_type: LiquidityPoolType::Custom,
+                pool_type_name: pool_type.clone(),
+                address: pool_address.clone(),
+                fee_fraction: 0,
+                a: 0,
+                admin_fee: 0,
+                init_args: init_args.clone(),
+                created_at: e.ledger().timestamp(),
+            },
+        );
+
+        Events::new(&e).add_pool(
+            tokens,
+            pool_address,
+            pool_type,
+            subpool_salt.clone(),
+            init_args,
+        );
+        subpool_salt
+    }
+
+    fn remove_pool(e: Env, user: Address, tokens: Vec<Address>, pool_hash: BytesN<32>) {
+        let access_control = AccessControl::new(&e);
+        user.require_auth();
+        access_control.check_role(Role::OperationsAdmin, &user);
+        let salt = pool_salt(&e, tokens.clone());
+        if let Some(pool_address) = get_pools_plain(&e, &salt).get(pool_hash.clone()) {
+            remove_pool(&e, &pool_address);
+            Events::new(&e).remove_pool(tokens, pool_address, pool_hash);
+        }
+    }
+
+    fn set_pool_state(
+        e: Env,
+        user: Address,
+        tokens: Vec<Address>,
+        pool_index: BytesN<32>,
+        state: PoolState,
+    ) {
+        let access_control = AccessControl::new(&e);
+        user.require_auth();
+        // emergency pause admin is only able to deprecate pool
+        if !(state == PoolState::Deprecated
+            && access_control.has_role(Role::EmergencyPauseAdmin, &user))
+        {
+            access_control.check_role(Role::OperationsAdmin, &user);
+        }
+
+        let pool_id = get_pool(&e, tokens.clone(), pool_index.clone()).expect("Pool doesn't exist");
+        let current_state = get_pool_state(&e, &pool_id);
+        if current_state == PoolState::Removed {
+            panic!("pool is removed")
+        }
+        if current_state == state {
+            return;
+        }
+
+        match state {
+            PoolState::Removed => remove_pool(&e, &pool_id),
+            _ => set_pool_state(&e, &pool_id, state),
+        }
+        Events::new(&e).set_pool_state(tokens, pool_id, pool_index, state);
+    }
+
+    fn get_pool_state(e: Env, tokens: Vec<Address>, pool_index: BytesN<32>) -> PoolState {
+        let pool_id = get_pool(&e, tokens, pool_index).expect("Pool doesn't exist");
+        get_pool_state(&e, &pool_id)
+    }
+
+    fn get_tokens_sets_count(e: Env) -> u128 {
+        get_tokens_sets_count(&e)
+    }
+
+    fn get_tokens_sets(e: Env, offset: u128, limit: u128) -> Vec<Vec<Address>> {
+        let count = get_tokens_sets_count(&e);
+        let mut result = Vec::new(&e);
+        let mut index = offset;
+        while index < count && index < offset.saturating_add(limit) {
+            result.push_back(get_tokens_set(&e, index));
+            index += 1;
+        }
+        result
+    }
+
+    fn get_pools_count(e: Env) -> u128 {
+        get_pools_count(&e)
+    }
+
+    fn index_tokens_sets(e: Env, admin: Address, tokens_sets: Vec<Vec<Address>>) -> u32 {
+        let access_control = AccessControl::new(&e);
+        admin.require_auth();
+        access_control.check_admin(&admin);
+        if tokens_sets.len() > MAX_POOLS_ADMIN_BATCH {
+            panic!("batch is too large")
+        }
+
+        let mut indexed = 0;
+        for tokens in tokens_sets {
+            let salt = pool_salt(&e, tokens.clone());
+            indexed += index_legacy_pools(&e, &tokens, &salt);
+        }
+        indexed
+    }
+
+    fn get_token_tokens_sets_count(e: Env, token: Address) -> u128 {
+        get_token_tokens_sets_count(&e, &token)
+    }
+
+    fn get_pools_for_token(
+        e: Env,
+        token: Address,
+        offset: u128,
+        limit: u128,
+    ) -> Vec<(Vec<Address>, Map<BytesN<32>, Address>)> {
+        if limit > MAX_TOKEN_POOLS_PAGE {
+            panic!("limit is over max")
+        }
+        let mut result = Vec::new(&e);
+        for index in get_token_tokens_sets(&e, &token, offset, limit) {
+            let tokens = get_tokens_set(&e, index);
+            let salt = pool_salt(&e, tokens.clone());
+            result.push_back((tokens, get_pools_plain(&e, &salt)));
+        }
+        result
+    }
+}
+
+#[contractimpl]
+impl PoolPlaneInterface for LiquidityPoolRouter {
+    fn set_pools_plane(e: Env, admin: Address, plane: Address) {
+        let access_control = AccessControl::new(&e);
+        admin.require_auth();
+        access_control.check_admin(&admin);
+
+        set_pool_plane(&e, &plane);
+        Events::new(&e).set_pools_plane(plane);
+    }
+
+    fn get_plane(e: Env) -> Address {
+        get_pool_plane(&e)
+    }
+}
+
+#[contractimpl]
+impl SwapRouterInterface for LiquidityPoolRouter {
+    fn set_swap_router(e: Env, admin: Address, router: Address) {
+        let access_control = AccessControl::new(&e);
+        admin.require_auth();
+        access_control.check_admin(&admin);
+        set_swap_router(&e, &router);
+        Events::new(&e).set_swap_router(router);
+    }
+
+    fn get_swap_router(e: Env) -> Address {
+        get_swap_router(&e)
+    }
+
+    fn estimate_swap_routed(
+        e: Env,
+        tokens: Vec<Address>,
+        token_in: Address,
+        token_out: Address,
+        in_amount: u128,
+    ) -> (BytesN<32>, Address, u128) {
         let salt = pool_salt(&e, tokens.clone());
         let pools = get_pools_plain(&e, &salt);
 
@@ -474,9 +1650,15 @@ impl SwapRouterInterface for LiquidityPoolRouter {
         let mut pools_vec: Vec<Address> = Vec::new(&e);
         let mut pools_reversed: Map<Address, BytesN<32>> = Map::new(&e);
         for (key, value) in pools {
+            if get_pool_state(&e, &value) != PoolState::Active {
+                continue;
+            }
             pools_vec.push_back(value.clone());
             pools_reversed.set(value, key);
         }
+        if pools_vec.is_empty() {
+            panic!("no active pools")
+        }
 
         let (best_pool_address, swap_result) = SwapRouterClient::new(&e, &swap_router)
             .estimate_swap(
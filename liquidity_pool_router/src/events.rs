@@ -1,3 +1,5 @@
+use crate::storage::{LiquidityPoolType, PoolCreationPolicy, PoolState};
+use rewards::storage::ScheduledRewardConfig;
 use soroban_sdk::{Address, BytesN, Env, Symbol, Val, Vec};
 
 #[derive(Clone)]
@@ -15,6 +17,16 @@ impl Events {
     }
 }
 
+// incentive deposited into the pool
+pub(crate) struct IncentiveDeposit {
+    pub(crate) depositor: Address,
+    pub(crate) pool_id: Address,
+    pub(crate) stream: u32,
+    pub(crate) token: Address,
+    pub(crate) amount: u128,
+    pub(crate) duration: u64,
+}
+
 pub(crate) trait LiquidityPoolRouterEvents {
     fn deposit(
         &self,
@@ -53,6 +65,208 @@ pub(crate) trait LiquidityPoolRouterEvents {
         subpool_salt: BytesN<32>,
         init_args: Vec<Val>,
     );
+
+    fn remove_pool(&self, tokens: Vec<Address>, pool_address: Address, pool_index: BytesN<32>);
+
+    fn set_pool_state(
+        &self,
+        tokens: Vec<Address>,
+        pool_address: Address,
+        pool_index: BytesN<32>,
+        state: PoolState,
+    );
+
+    fn claim(&self, tokens: Vec<Address>, user: Address, pool_id: Address, reward: u128);
+
+    fn claim_to(
+        &self,
+        tokens: Vec<Address>,
+        user: Address,
+        pool_id: Address,
+        recipient: Address,
+        reward: u128,
+    );
+
+    fn set_claim_operator(&self, user: Address, operator: Address, approved: bool);
+
+    fn set_rewards_config(
+        &self,
+        tokens: Vec<Address>,
+        admin: Address,
+        pool_id: Address,
+        expired_at: u64,
+        tps: u128,
+    );
+
+    fn claim_all_rewards(
+        &self,
+        tokens: Vec<Address>,
+        user: Address,
+        pool_id: Address,
+        rewards: Vec<u128>,
+    );
+
+    fn add_reward_stream(
+        &self,
+        tokens: Vec<Address>,
+        admin: Address,
+        pool_id: Address,
+        stream: u32,
+        reward_token: Address,
+        reward_storage: Address,
+    );
+
+    fn set_stream_rewards_config(
+        &self,
+        tokens: Vec<Address>,
+        admin: Address,
+        pool_id: Address,
+        stream: u32,
+        expired_at: u64,
+        tps: u128,
+    );
+
+    fn set_pool_voting_escrow(
+        &self,
+        tokens: Vec<Address>,
+        admin: Address,
+        pool_id: Address,
+        voting_escrow: Address,
+    );
+
+    fn enable_pool_rewards_checkpoint(
+        &self,
+        tokens: Vec<Address>,
+        admin: Address,
+        pool_id: Address,
+    );
+
+    fn set_pool_reward_precision(
+        &self,
+        tokens: Vec<Address>,
+        admin: Address,
+        pool_id: Address,
+        precision: u128,
+    );
+
+    fn schedule_rewards_config(
+        &self,
+        tokens: Vec<Address>,
+        admin: Address,
+        pool_id: Address,
+        stream: u32,
+        config: ScheduledRewardConfig,
+    );
+
+    fn cancel_scheduled_rewards_config(
+        &self,
+        tokens: Vec<Address>,
+        admin: Address,
+        pool_id: Address,
+        stream: u32,
+        start_at: u64,
+    );
+
+    fn stop_rewards(&self, tokens: Vec<Address>, admin: Address, pool_id: Address, stream: u32);
+
+    fn reclaim_unallocated_rewards(
+        &self,
+        tokens: Vec<Address>,
+        admin: Address,
+        pool_id: Address,
+        stream: u32,
+        to: Address,
+        amount: u128,
+    );
+
+    fn deposit_incentive(&self, tokens: Vec<Address>, deposit: IncentiveDeposit);
+
+    fn set_pool_incentive_token(
+        &self,
+        tokens: Vec<Address>,
+        admin: Address,
+        pool_id: Address,
+        token: Address,
+        min_amount: Option<u128>,
+    );
+
+    fn settle_incentive(
+        &self,
+        tokens: Vec<Address>,
+        depositor: Address,
+        pool_id: Address,
+        stream: u32,
+        leftover: u128,
+    );
+
+    fn set_volume_mining_config(
+        &self,
+        tokens: Vec<Address>,
+        admin: Address,
+        pool_id: Address,
+        epoch_length: u64,
+        budget: u128,
+    );
+
+    fn claim_volume_reward(
+        &self,
+        tokens: Vec<Address>,
+        user: Address,
+        pool_id: Address,
+        epoch: u32,
+        amount: u128,
+    );
+
+    fn sweep_volume_epoch(&self, tokens: Vec<Address>, pool_id: Address, epoch: u32, amount: u128);
+
+    fn set_pool_gauge(
+        &self,
+        tokens: Vec<Address>,
+        admin: Address,
+        pool_id: Address,
+        gauge: Address,
+    );
+
+    fn remove_pool_gauge(&self, tokens: Vec<Address>, admin: Address, pool_id: Address);
+
+    fn set_pools_plane(&self, plane: Address);
+
+    fn set_swap_router(&self, router: Address);
+
+    fn set_merkle_distributor(&self, distributor: Address);
+
+    fn configure_init_pool_payment(&self, token: Address, amount: u128, to: Address);
+
+    fn set_reward_token(&self, reward_token: Address);
+
+    fn set_pool_creation_policy(&self, pool_type: LiquidityPoolType, policy: PoolCreationPolicy);
+
+    fn upgrade_pool(
+        &self,
+        tokens: Vec<Address>,
+        pool_address: Address,
+        new_wasm_hash: BytesN<32>,
+        version: u32,
+    );
+
+    fn upgrade_pool_failed(
+        &self,
+        tokens: Vec<Address>,
+        pool_address: Address,
+        new_wasm_hash: BytesN<32>,
+    );
+
+    fn commit_pool_admin(&self, tokens: Vec<Address>, pool_address: Address, new_admin: Address);
+
+    fn set_pool_admin(&self, tokens: Vec<Address>, pool_address: Address, new_admin: Address);
+
+    fn set_token_hash(&self, new_hash: BytesN<32>);
+
+    fn set_pool_hash(&self, new_hash: BytesN<32>);
+
+    fn set_stableswap_pool_hash(&self, num_tokens: u32, new_hash: BytesN<32>);
+
+    fn upgrade(&self, new_wasm_hash: BytesN<32>);
 }
 
 impl LiquidityPoolRouterEvents for Events {
@@ -113,4 +327,450 @@ impl LiquidityPoolRouterEvents for Events {
             (pool_address, pool_type, subpool_salt, init_args),
         );
     }
+
+    fn set_pool_state(
+        &self,
+        tokens: Vec<Address>,
+        pool_address: Address,
+        pool_index: BytesN<32>,
+        state: PoolState,
+    ) {
+        self.env().events().publish(
+            (Symbol::new(self.env(), "set_pool_state"), tokens),
+            (pool_address, pool_index, state),
+        );
+    }
+
+    fn remove_pool(&self, tokens: Vec<Address>, pool_address: Address, pool_index: BytesN<32>) {
+        self.env().events().publish(
+            (Symbol::new(self.env(), "remove_pool"), tokens),
+            (pool_address, pool_index),
+        );
+    }
+
+    fn claim(&self, tokens: Vec<Address>, user: Address, pool_id: Address, reward: u128) {
+        self.env().events().publish(
+            (Symbol::new(self.env(), "claim"), tokens, user),
+            (pool_id, reward),
+        );
+    }
+
+    fn claim_to(
+        &self,
+        tokens: Vec<Address>,
+        user: Address,
+        pool_id: Address,
+        recipient: Address,
+        reward: u128,
+    ) {
+        self.env().events().publish(
+            (Symbol::new(self.env(), "claim_to"), tokens, user),
+            (pool_id, recipient, reward),
+        );
+    }
+
+    fn set_claim_operator(&self, user: Address, operator: Address, approved: bool) {
+        self.env().events().publish(
+            (Symbol::new(self.env(), "set_claim_operator"), user),
+            (operator, approved),
+        );
+    }
+
+    fn set_rewards_config(
+        &self,
+        tokens: Vec<Address>,
+        admin: Address,
+        pool_id: Address,
+        expired_at: u64,
+        tps: u128,
+    ) {
+        self.env().events().publish(
+            (Symbol::new(self.env(), "set_rewards_config"), tokens, admin),
+            (pool_id, expired_at, tps),
+        );
+    }
+
+    fn claim_all_rewards(
+        &self,
+        tokens: Vec<Address>,
+        user: Address,
+        pool_id: Address,
+        rewards: Vec<u128>,
+    ) {
+        self.env().events().publish(
+            (Symbol::new(self.env(), "claim_all_rewards"), tokens, user),
+            (pool_id, rewards),
+        );
+    }
+
+    fn add_reward_stream(
+        &self,
+        tokens: Vec<Address>,
+        admin: Address,
+        pool_id: Address,
+        stream: u32,
+        reward_token: Address,
+        reward_storage: Address,
+    ) {
+        self.env().events().publish(
+            (Symbol::new(self.env(), "add_reward_stream"), tokens, admin),
+            (pool_id, stream, reward_token, reward_storage),
+        );
+    }
+
+    fn set_stream_rewards_config(
+        &self,
+        tokens: Vec<Address>,
+        admin: Address,
+        pool_id: Address,
+        stream: u32,
+        expired_at: u64,
+        tps: u128,
+    ) {
+        self.env().events().publish(
+            (
+                Symbol::new(self.env(), "set_stream_rewards_config"),
+                tokens,
+                admin,
+            ),
+            (pool_id, stream, expired_at, tps),
+        );
+    }
+
+    fn set_pool_voting_escrow(
+        &self,
+        tokens: Vec<Address>,
+        admin: Address,
+        pool_id: Address,
+        voting_escrow: Address,
+    ) {
+        self.env().events().publish(
+            (
+                Symbol::new(self.env(), "set_pool_voting_escrow"),
+                tokens,
+                admin,
+            ),
+            (pool_id, voting_escrow),
+        );
+    }
+
+    fn enable_pool_rewards_checkpoint(
+        &self,
+        tokens: Vec<Address>,
+        admin: Address,
+        pool_id: Address,
+    ) {
+        self.env().events().publish(
+            (
+                Symbol::new(self.env(), "enable_pool_rewards_checkpoint"),
+                tokens,
+                admin,
+            ),
+            (pool_id,),
+        );
+    }
+
+    fn set_pool_reward_precision(
+        &self,
+        tokens: Vec<Address>,
+        admin: Address,
+        pool_id: Address,
+        precision: u128,
+    ) {
+        self.env().events().publish(
+            (
+                Symbol::new(self.env(), "set_pool_reward_precision"),
+                tokens,
+                admin,
+            ),
+            (pool_id, precision),
+        );
+    }
+
+    fn schedule_rewards_config(
+        &self,
+        tokens: Vec<Address>,
+        admin: Address,
+        pool_id: Address,
+        stream: u32,
+        config: ScheduledRewardConfig,
+    ) {
+        self.env().events().publish(
+            (
+                Symbol::new(self.env(), "schedule_rewards_config"),
+                tokens,
+                admin,
+            ),
+            (
+                pool_id,
+                stream,
+                config.start_at,
+                config.expired_at,
+                config.tps,
+            ),
+        );
+    }
+
+    fn cancel_scheduled_rewards_config(
+        &self,
+        tokens: Vec<Address>,
+        admin: Address,
+        pool_id: Address,
+        stream: u32,
+        start_at: u64,
+    ) {
+        self.env().events().publish(
+            (
+                Symbol::new(self.env(), "cancel_scheduled_rewards_config"),
+                tokens,
+                admin,
+            ),
+            (pool_id, stream, start_at),
+        );
+    }
+
+    fn stop_rewards(&self, tokens: Vec<Address>, admin: Address, pool_id: Address, stream: u32) {
+        self.env().events().publish(
+            (Symbol::new(self.env(), "stop_rewards"), tokens, admin),
+            (pool_id, stream),
+        );
+    }
+
+    fn reclaim_unallocated_rewards(
+        &self,
+        tokens: Vec<Address>,
+        admin: Address,
+        pool_id: Address,
+        stream: u32,
+        to: Address,
+        amount: u128,
+    ) {
+        self.env().events().publish(
+            (
+                Symbol::new(self.env(), "reclaim_unallocated_rewards"),
+                tokens,
+                admin,
+            ),
+            (pool_id, stream, to, amount),
+        );
+    }
+
+    fn deposit_incentive(&self, tokens: Vec<Address>, deposit: IncentiveDeposit) {
+        self.env().events().publish(
+            (
+                Symbol::new(self.env(), "deposit_incentive"),
+                tokens,
+                deposit.depositor,
+            ),
+            (
+                deposit.pool_id,
+                deposit.stream,
+                deposit.token,
+                deposit.amount,
+                deposit.duration,
+            ),
+        );
+    }
+
+    fn set_pool_incentive_token(
+        &self,
+        tokens: Vec<Address>,
+        admin: Address,
+        pool_id: Address,
+        token: Address,
+        min_amount: Option<u128>,
+    ) {
+        self.env().events().publish(
+            (
+                Symbol::new(self.env(), "set_pool_incentive_token"),
+                tokens,
+                admin,
+            ),
+            (pool_id, token, min_amount),
+        );
+    }
+
+    fn settle_incentive(
+        &self,
+        tokens: Vec<Address>,
+        depositor: Address,
+        pool_id: Address,
+        stream: u32,
+        leftover: u128,
+    ) {
+        self.env().events().publish(
+            (
+                Symbol::new(self.env(), "settle_incentive"),
+                tokens,
+                depositor,
+            ),
+            (pool_id, stream, leftover),
+        );
+    }
+
+    fn set_volume_mining_config(
+        &self,
+        tokens: Vec<Address>,
+        admin: Address,
+        pool_id: Address,
+        epoch_length: u64,
+        budget: u128,
+    ) {
+        self.env().events().publish(
+            (
+                Symbol::new(self.env(), "set_volume_mining_config"),
+                tokens,
+                admin,
+            ),
+            (pool_id, epoch_length, budget),
+        );
+    }
+
+    fn claim_volume_reward(
+        &self,
+        tokens: Vec<Address>,
+        user: Address,
+        pool_id: Address,
+        epoch: u32,
+        amount: u128,
+    ) {
+        self.env().events().publish(
+            (Symbol::new(self.env(), "claim_volume_reward"), tokens, user),
+            (pool_id, epoch, amount),
+        );
+    }
+
+    fn sweep_volume_epoch(&self, tokens: Vec<Address>, pool_id: Address, epoch: u32, amount: u128) {
+        self.env().events().publish(
+            (Symbol::new(self.env(), "sweep_volume_epoch"), tokens),
+            (pool_id, epoch, amount),
+        );
+    }
+
+    fn set_pool_gauge(
+        &self,
+        tokens: Vec<Address>,
+        admin: Address,
+        pool_id: Address,
+        gauge: Address,
+    ) {
+        self.env().events().publish(
+            (Symbol::new(self.env(), "set_pool_gauge"), tokens, admin),
+            (pool_id, gauge),
+        );
+    }
+
+    fn remove_pool_gauge(&self, tokens: Vec<Address>, admin: Address, pool_id: Address) {
+        self.env().events().publish(
+            (Symbol::new(self.env(), "remove_pool_gauge"), tokens, admin),
+            (pool_id,),
+        );
+    }
+
+    fn set_pools_plane(&self, plane: Address) {
+        self.env()
+            .events()
+            .publish((Symbol::new(self.env(), "set_pools_plane"),), (plane,));
+    }
+
+    fn set_swap_router(&self, router: Address) {
+        self.env()
+            .events()
+            .publish((Symbol::new(self.env(), "set_swap_router"),), (router,));
+    }
+
+    fn set_merkle_distributor(&self, distributor: Address) {
+        self.env().events().publish(
+            (Symbol::new(self.env(), "set_merkle_distributor"),),
+            (distributor,),
+        );
+    }
+
+    fn configure_init_pool_payment(&self, token: Address, amount: u128, to: Address) {
+        self.env().events().publish(
+            (Symbol::new(self.env(), "configure_init_pool_payment"),),
+            (token, amount, to),
+        );
+    }
+
+    fn set_reward_token(&self, reward_token: Address) {
+        self.env().events().publish(
+            (Symbol::new(self.env(), "set_reward_token"),),
+            (reward_token,),
+        );
+    }
+
+    fn set_pool_creation_policy(&self, pool_type: LiquidityPoolType, policy: PoolCreationPolicy) {
+        self.env().events().publish(
+            (
+                Symbol::new(self.env(), "set_pool_creation_policy"),
+                pool_type,
+            ),
+            (policy,),
+        );
+    }
+
+    fn upgrade_pool(
+        &self,
+        tokens: Vec<Address>,
+        pool_address: Address,
+        new_wasm_hash: BytesN<32>,
+        version: u32,
+    ) {
+        self.env().events().publish(
+            (Symbol::new(self.env(), "upgrade_pool"), tokens),
+            (pool_address, new_wasm_hash, version),
+        );
+    }
+
+    fn upgrade_pool_failed(
+        &self,
+        tokens: Vec<Address>,
+        pool_address: Address,
+        new_wasm_hash: BytesN<32>,
+    ) {
+        self.env().events().publish(
+            (Symbol::new(self.env(), "upgrade_pool_failed"), tokens),
+            (pool_address, new_wasm_hash),
+        );
+    }
+
+    fn commit_pool_admin(&self, tokens: Vec<Address>, pool_address: Address, new_admin: Address) {
+        self.env().events().publish(
+            (Symbol::new(self.env(), "commit_pool_admin"), tokens),
+            (pool_address, new_admin),
+        );
+    }
+
+    fn set_pool_admin(&self, tokens: Vec<Address>, pool_address: Address, new_admin: Address) {
+        self.env().events().publish(
+            (Symbol::new(self.env(), "set_pool_admin"), tokens),
+            (pool_address, new_admin),
+        );
+    }
+
+    fn set_token_hash(&self, new_hash: BytesN<32>) {
+        self.env()
+            .events()
+            .publish((Symbol::new(self.env(), "set_token_hash"),), (new_hash,));
+    }
+
+    fn set_pool_hash(&self, new_hash: BytesN<32>) {
+        self.env()
+            .events()
+            .publish((Symbol::new(self.env(), "set_pool_hash"),), (new_hash,));
+    }
+
+    fn set_stableswap_pool_hash(&self, num_tokens: u32, new_hash: BytesN<32>) {
+        self.env().events().publish(
+            (Symbol::new(self.env(), "set_stableswap_pool_hash"),),
+            (num_tokens, new_hash),
+        );
+    }
+
+    fn upgrade(&self, new_wasm_hash: BytesN<32>) {
+        self.env()
+            .events()
+            .publish((Symbol::new(self.env(), "upgrade"),), (new_wasm_hash,));
+    }
 }
@@ -3,11 +3,13 @@
 mod constants;
 mod contract;
 mod events;
+mod merkle_distributor;
 mod pool_contract;
 mod pool_interface;
 mod pool_utils;
 mod rewards;
 mod router_interface;
+mod staking_gauge;
 mod storage;
 mod swap_router;
 mod test;
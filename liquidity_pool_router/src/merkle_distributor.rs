@@ -0,0 +1,25 @@
+use soroban_sdk::{contractclient, contracttype, Address, BytesN, Env, Vec};
+
+// Merkle distributor leaf claimed by router batch claim on behalf of the user
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct MerkleClaim {
+    pub epoch: u32,
+    pub index: u32,
+    pub amount: u128,
+    pub proof: Vec<BytesN<32>>,
+}
+
+// Part of merkle distributor interface used by router batch claim
+#[allow(dead_code)]
+#[contractclient(name = "MerkleDistributorClient")]
+pub trait MerkleDistributorInterface {
+    fn claim(
+        e: Env,
+        epoch: u32,
+        index: u32,
+        account: Address,
+        amount: u128,
+        proof: Vec<BytesN<32>>,
+    ) -> u128;
+}
@@ -1,3 +1,8 @@
+use crate::merkle_distributor::MerkleClaim;
+use crate::storage::{LiquidityPoolInfo, PoolState};
+use rewards::storage::{
+    Incentive, RewardsFunding, RewardsInfo, ScheduledRewardConfig, VolumeEpoch, VolumeMiningConfig,
+};
 use soroban_sdk::{Address, BytesN, Env, Map, Symbol, Val, Vec};
 
 pub trait LiquidityPoolInterfaceTrait {
@@ -85,13 +90,13 @@ pub trait RewardsInterfaceTrait {
     );
 
     // Get rewards status for the pool,
-    // including amount available for the user
+    // including amount available for the user. Nothing is written
     fn get_rewards_info(
         e: Env,
         user: Address,
         tokens: Vec<Address>,
         pool_index: BytesN<32>,
-    ) -> Map<Symbol, i128>;
+    ) -> RewardsInfo;
 
     // Get amount of reward tokens available for the user to claim.
     fn get_user_reward(e: Env, user: Address, tokens: Vec<Address>, pool_index: BytesN<32>)
@@ -100,10 +105,346 @@ pub trait RewardsInterfaceTrait {
     // Claim reward as a user.
     // returns amount of tokens rewarded to the user
     fn claim(e: Env, user: Address, tokens: Vec<Address>, pool_index: BytesN<32>) -> u128;
+
+    // Claim reward as a user, transferring it to recipient.
+    // returns amount of tokens rewarded
+    fn claim_to(
+        e: Env,
+        user: Address,
+        tokens: Vec<Address>,
+        pool_index: BytesN<32>,
+        recipient: Address,
+    ) -> u128;
+
+    // Claim user reward by operator approved by the user in the router, transferring it to recipient.
+    // Router claims from pools it manages itself, pools with external admin need approval there.
+    // returns amount of tokens rewarded
+    fn claim_as_operator(
+        e: Env,
+        operator: Address,
+        user: Address,
+        tokens: Vec<Address>,
+        pool_index: BytesN<32>,
+        recipient: Address,
+    ) -> u128;
+
+    // Allow or disallow operator to claim rewards on behalf of the user
+    fn set_claim_operator(e: Env, user: Address, operator: Address, approved: bool);
+
+    // Check if operator is allowed to claim rewards on behalf of the user
+    fn is_claim_operator(e: Env, user: Address, operator: Address) -> bool;
+
+    // Add reward stream distributing another token alongside the primary one.
+    // reward_storage: address from which stream rewards are transferred on claim
+    // Returns stream number
+    fn add_reward_stream(
+        e: Env,
+        admin: Address,
+        tokens: Vec<Address>,
+        pool_index: BytesN<32>,
+        reward_token: Address,
+        reward_storage: Address,
+    ) -> u32;
+
+    // Configure rewards of specific pool stream. Stream 0 is the primary one
+    fn set_stream_rewards_config(
+        e: Env,
+        admin: Address,
+        tokens: Vec<Address>,
+        pool_index: BytesN<32>,
+        stream: u32,
+        expired_at: u64,
+        tps: u128,
+    );
+
+    // Get reward token and rewards status of every pool stream,
+    // including amount available for the user
+    fn get_all_rewards_info(
+        e: Env,
+        user: Address,
+        tokens: Vec<Address>,
+        pool_index: BytesN<32>,
+    ) -> Vec<(Address, RewardsInfo)>;
+
+    // Get amounts of reward tokens available for the user to claim for every pool stream
+    fn get_all_user_rewards(
+        e: Env,
+        user: Address,
+        tokens: Vec<Address>,
+        pool_index: BytesN<32>,
+    ) -> Vec<u128>;
+
+    // Claim rewards of every pool stream.
+    // returns amounts of tokens rewarded to the user for every stream
+    fn claim_all_rewards(
+        e: Env,
+        user: Address,
+        tokens: Vec<Address>,
+        pool_index: BytesN<32>,
+    ) -> Vec<u128>;
+
+    // Enable pool rewards boost based on vote-escrow locks
+    fn set_pool_voting_escrow(
+        e: Env,
+        admin: Address,
+        tokens: Vec<Address>,
+        pool_index: BytesN<32>,
+        voting_escrow: Address,
+    );
+
+    // Enable rewards checkpoint on share transfers for pool created before it was introduced
+    fn enable_pool_rewards_checkpoint(
+        e: Env,
+        admin: Address,
+        tokens: Vec<Address>,
+        pool_index: BytesN<32>,
+    );
+
+    // Increase pool reward per share precision. New precision should be a multiple of current one
+    fn set_pool_reward_precision(
+        e: Env,
+        admin: Address,
+        tokens: Vec<Address>,
+        pool_index: BytesN<32>,
+        precision: u128,
+    );
+
+    // Queue pool stream rewards config to be applied automatically at start_at timestamp
+    fn schedule_rewards_config(
+        e: Env,
+        admin: Address,
+        tokens: Vec<Address>,
+        pool_index: BytesN<32>,
+        stream: u32,
+        config: ScheduledRewardConfig,
+    );
+
+    // Remove not started rewards config from pool stream queue
+    fn cancel_scheduled_rewards_config(
+        e: Env,
+        admin: Address,
+        tokens: Vec<Address>,
+        pool_index: BytesN<32>,
+        stream: u32,
+        start_at: u64,
+    );
+
+    // Get pool stream rewards configs waiting for activation
+    fn get_scheduled_rewards_configs(
+        e: Env,
+        tokens: Vec<Address>,
+        pool_index: BytesN<32>,
+        stream: u32,
+    ) -> Vec<ScheduledRewardConfig>;
+
+    // End pool stream reward period now. Scheduled configs are kept
+    fn stop_rewards(
+        e: Env,
+        admin: Address,
+        tokens: Vec<Address>,
+        pool_index: BytesN<32>,
+        stream: u32,
+    );
+
+    // Get committed, paid and available amounts of pool stream reward token
+    fn get_rewards_funding(
+        e: Env,
+        tokens: Vec<Address>,
+        pool_index: BytesN<32>,
+        stream: u32,
+    ) -> RewardsFunding;
+
+    // Get reward per second for single working share of pool stream,
+    // multiplied by pool reward precision
+    fn get_rewards_tps_per_share(
+        e: Env,
+        tokens: Vec<Address>,
+        pool_index: BytesN<32>,
+        stream: u32,
+    ) -> u128;
+
+    // Get amount of rewards to be generated by active and scheduled configs of pool stream
+    fn get_remaining_emission(
+        e: Env,
+        tokens: Vec<Address>,
+        pool_index: BytesN<32>,
+        stream: u32,
+    ) -> u128;
+
+    // Transfer pool reward tokens not committed to any stream to the given address.
+    // Returns amount transferred
+    fn reclaim_unallocated_rewards(
+        e: Env,
+        admin: Address,
+        tokens: Vec<Address>,
+        pool_index: BytesN<32>,
+        stream: u32,
+        to: Address,
+    ) -> u128;
+
+    // Stream amount of token to pool liquidity providers during duration seconds.
+    // Callable by anyone, token should be allowed for incentives in the pool.
+    // Undistributed leftover is returned to depositor once incentive is finished.
+    // Returns pool stream number
+    fn deposit_incentive(
+        e: Env,
+        depositor: Address,
+        tokens: Vec<Address>,
+        pool_index: BytesN<32>,
+        token: Address,
+        amount: u128,
+        duration: u64,
+    ) -> u32;
+
+    // Allow token for pool incentives with minimum amount, or disallow it if min_amount is none
+    fn set_pool_incentive_token(
+        e: Env,
+        admin: Address,
+        tokens: Vec<Address>,
+        pool_index: BytesN<32>,
+        token: Address,
+        min_amount: Option<u128>,
+    );
+
+    // Get minimum amount of pool incentive in the token. None if token is not allowed
+    fn get_pool_incentive_min_amount(
+        e: Env,
+        tokens: Vec<Address>,
+        pool_index: BytesN<32>,
+        token: Address,
+    ) -> Option<u128>;
+
+    // Return undistributed leftover of finished pool incentive to its depositor.
+    // Callable by anyone. Returns leftover amount
+    fn settle_incentive(e: Env, tokens: Vec<Address>, pool_index: BytesN<32>, stream: u32) -> u128;
+
+    // Get incentive of pool stream which is not settled yet
+    fn get_incentive(
+        e: Env,
+        tokens: Vec<Address>,
+        pool_index: BytesN<32>,
+        stream: u32,
+    ) -> Option<Incentive>;
+
+    // Configure pool trading volume mining. Every epoch of epoch_length seconds budget of pool
+    // reward token is split between traders proportionally to the swap fees they paid.
+    // Zero budget disables it
+    fn set_volume_mining_config(
+        e: Env,
+        admin: Address,
+        tokens: Vec<Address>,
+        pool_index: BytesN<32>,
+        epoch_length: u64,
+        budget: u128,
+    );
+
+    // Get pool volume mining config. None if volume mining is disabled
+    fn get_volume_mining_config(
+        e: Env,
+        tokens: Vec<Address>,
+        pool_index: BytesN<32>,
+    ) -> Option<VolumeMiningConfig>;
+
+    // Get amount of pool volume mining epochs started. The last one may be still in progress
+    fn get_volume_epochs_count(e: Env, tokens: Vec<Address>, pool_index: BytesN<32>) -> u32;
+
+    // Get pool volume mining epoch state
+    fn get_volume_epoch(
+        e: Env,
+        tokens: Vec<Address>,
+        pool_index: BytesN<32>,
+        epoch: u32,
+    ) -> Option<VolumeEpoch>;
+
+    // Get points collected by the user in the pool epoch
+    fn get_user_volume_points(
+        e: Env,
+        user: Address,
+        tokens: Vec<Address>,
+        pool_index: BytesN<32>,
+        epoch: u32,
+    ) -> u128;
+
+    // Get user reward for the finished pool epoch
+    fn get_user_volume_reward(
+        e: Env,
+        user: Address,
+        tokens: Vec<Address>,
+        pool_index: BytesN<32>,
+        epoch: u32,
+    ) -> u128;
+
+    // Claim user reward for the finished pool epoch. Returns amount transferred.
+    // Reward can be claimed during 30 days after epoch end
+    fn claim_volume_reward(
+        e: Env,
+        user: Address,
+        tokens: Vec<Address>,
+        pool_index: BytesN<32>,
+        epoch: u32,
+    ) -> u128;
+
+    // Release pool epoch budget left unclaimed once its claim period is over.
+    // Callable by anyone. Returns amount released
+    fn sweep_pool_volume_epoch(
+        e: Env,
+        tokens: Vec<Address>,
+        pool_index: BytesN<32>,
+        epoch: u32,
+    ) -> u128;
+
+    // Link staking gauge to the pool. Gauge should stake pool share token.
+    // Allows to incentivize pools without embedded rewards
+    fn set_pool_gauge(
+        e: Env,
+        admin: Address,
+        tokens: Vec<Address>,
+        pool_index: BytesN<32>,
+        gauge: Address,
+    );
+
+    // Unlink staking gauge from the pool
+    fn remove_pool_gauge(e: Env, admin: Address, tokens: Vec<Address>, pool_index: BytesN<32>);
+
+    // Get staking gauge linked to the pool, if any
+    fn get_pool_gauge(e: Env, tokens: Vec<Address>, pool_index: BytesN<32>) -> Option<Address>;
+
+    // Get amounts of reward tokens available for the user to claim for every pool in the list.
+    // pools: list of (tokens, pool_index) pairs
+    fn get_total_user_reward(
+        e: Env,
+        user: Address,
+        pools: Vec<(Vec<Address>, BytesN<32>)>,
+    ) -> Vec<u128>;
+
+    // Claim rewards from multiple pools at once.
+    // Rewards of pools using router as reward storage are paid out with single transfer.
+    // Pools deployed by the router are their own reward storage, so they aren't aggregated
+    // and every such pool pays out its reward with a separate transfer
+    // pools: list of (tokens, pool_index) pairs, up to 10 pools
+    // returns amounts of tokens rewarded to the user for every pool
+    fn claim_all(e: Env, user: Address, pools: Vec<(Vec<Address>, BytesN<32>)>) -> Vec<u128>;
+    // Set merkle distributor of off-chain computed rewards claimed through the router
+    fn set_merkle_distributor(e: Env, admin: Address, distributor: Address);
+
+    // Get merkle distributor address, if any
+    fn get_merkle_distributor(e: Env) -> Option<Address>;
+
+    // Claim rewards from multiple pools and merkle distributor at once.
+    // pools: list of (tokens, pool_index) pairs
+    // claims: merkle distributor leaves of the user with their proofs
+    // returns amounts rewarded for every pool and every distributor claim
+    fn claim_all_with_distributions(
+        e: Env,
+        user: Address,
+        pools: Vec<(Vec<Address>, BytesN<32>)>,
+        claims: Vec<MerkleClaim>,
+    ) -> (Vec<u128>, Vec<u128>);
 }
 
 pub trait PoolsManagementTrait {
-    // Initialize standard pool with default arguments
+    // Initialize standard pool with default arguments.
+    // Available only while standard pools creation is permissionless and free
     fn init_pool(e: Env, tokens: Vec<Address>) -> (BytesN<32>, Address);
 
     // Initialize standard pool with custom arguments.
@@ -132,6 +473,19 @@ pub trait PoolsManagementTrait {
     // Get pools for given pair
     fn get_pools(e: Env, tokens: Vec<Address>) -> Map<BytesN<32>, Address>;
 
+    // Get pools for given pair along with parameters they were created with
+    fn get_pools_with_info(e: Env, tokens: Vec<Address>) -> Map<BytesN<32>, LiquidityPoolInfo>;
+
+    // Find pool by type name (`constant`, `stable` or custom pool type) and creation params:
+    // [fee_fraction] for constant product, [fee_fraction, a, admin_fee] for stableswap,
+    // init_args for custom pools
+    fn find_pool(
+        e: Env,
+        tokens: Vec<Address>,
+        pool_type: Symbol,
+        params: Vec<Val>,
+    ) -> LiquidityPoolInfo;
+
     // Add initialized custom pool to the list for given pair
     fn add_custom_pool(
         e: Env,
@@ -142,8 +496,49 @@ pub trait PoolsManagementTrait {
         init_args: Vec<Val>,
     ) -> BytesN<32>;
 
-    // Remove pool from the list
+    // Remove pool from the list. Pool remains available for withdrawals and reward claims
     fn remove_pool(e: Env, user: Address, tokens: Vec<Address>, pool_hash: BytesN<32>);
+
+    // Change pool lifecycle state. Deprecated pools accept only withdrawals and reward claims
+    // and are skipped by routed swaps. Removed state is final
+    fn set_pool_state(
+        e: Env,
+        user: Address,
+        tokens: Vec<Address>,
+        pool_index: BytesN<32>,
+        state: PoolState,
+    );
+
+    // Get pool lifecycle state
+    fn get_pool_state(e: Env, tokens: Vec<Address>, pool_index: BytesN<32>) -> PoolState;
+
+    // Get amount of tokens sets ever registered in the router
+    fn get_tokens_sets_count(e: Env) -> u128;
+
+    // Get registered tokens sets page. Sets are listed in order of registration
+    // offset: index of first tokens set to return
+    // limit: maximum amount of tokens sets to return
+    fn get_tokens_sets(e: Env, offset: u128, limit: u128) -> Vec<Vec<Address>>;
+
+    // Get total amount of pools registered in the router
+    fn get_pools_count(e: Env) -> u128;
+
+    // Register tokens sets with pools created before the tokens sets index was introduced.
+    // Pools already indexed are skipped. Returns amount of pools added to the index
+    fn index_tokens_sets(e: Env, admin: Address, tokens_sets: Vec<Vec<Address>>) -> u32;
+
+    // Get amount of tokens sets containing given token
+    fn get_token_tokens_sets_count(e: Env, token: Address) -> u128;
+
+    // Get tokens sets containing given token along with their pools
+    // offset: position of first tokens set in the token index
+    // limit: maximum amount of tokens sets to return
+    fn get_pools_for_token(
+        e: Env,
+        token: Address,
+        offset: u128,
+        limit: u128,
+    ) -> Vec<(Vec<Address>, Map<BytesN<32>, Address>)>;
 }
 
 pub trait PoolPlaneInterface {
@@ -1,12 +1,17 @@
+use crate::constants::MAX_CLAIM_POOLS;
 use crate::events::{Events, LiquidityPoolRouterEvents};
 use crate::pool_contract::StandardLiquidityPoolClient;
 use crate::rewards::get_rewards_manager;
 use crate::storage::{
-    add_pool, get_constant_product_pool_hash, get_pool_plane, get_stableswap_next_counter,
-    get_stableswap_pool_hash, get_token_hash, LiquidityPoolType,
+    add_pool, get_constant_product_pool_hash, get_pool, get_pool_plane, get_pool_state,
+    get_stableswap_next_counter, get_stableswap_pool_hash, get_token_hash, has_pool,
+    is_pool_managed, set_pool_managed, LiquidityPoolInfo, LiquidityPoolType, PoolCreationPolicy,
+    PoolState,
 };
 use access_control::access::{AccessControl, AccessControlTrait};
+use access_control::role::Role;
 use rewards::storage::RewardsStorageTrait;
+use soroban_sdk::token::Client as SorobanTokenClient;
 use soroban_sdk::{
     symbol_short, xdr::ToXdr, Address, Bytes, BytesN, Env, IntoVal, Symbol, Val, Vec,
 };
@@ -40,6 +45,70 @@ pub fn get_custom_salt(e: &Env, pool_type: &Symbol, init_args: &Vec<Val>) -> Byt
     e.crypto().sha256(&salt)
 }
 
+// check user is allowed to create pool and charge creation payment if configured
+pub fn apply_pool_creation_policy(e: &Env, user: &Address, policy: &PoolCreationPolicy) {
+    if !policy.permissionless {
+        AccessControl::new(e).check_role(Role::OperationsAdmin, user);
+    }
+
+    for payment in policy.payments.iter() {
+        if payment.amount > 0 {
+            SorobanTokenClient::new(e, &payment.token).transfer_from(
+                &e.current_contract_address(),
+                user,
+                &payment.to,
+                &(payment.amount as i128),
+            );
+        }
+    }
+}
+
+pub fn require_active_pool(e: &Env, pool: &Address) {
+    if get_pool_state(e, pool) != PoolState::Active {
+        panic!("pool is not active")
+    }
+}
+
+// address to authorize pool admin actions with:
+// router itself for pools it manages, router user for pools with external admin
+pub fn get_pool_admin_account(e: &Env, pool: &Address, user: &Address) -> Address {
+    match is_pool_managed(e, pool) {
+        true => e.current_contract_address(),
+        false => user.clone(),
+    }
+}
+
+// call pool admin method on behalf of router user. admin account is prepended to arguments
+pub fn invoke_pool_admin_method(
+    e: &Env,
+    pool: &Address,
+    user: &Address,
+    func: &str,
+    args: Vec<Val>,
+) {
+    let mut call_args = Vec::from_array(e, [get_pool_admin_account(e, pool, user).into_val(e)]);
+    call_args.append(&args);
+    e.invoke_contract::<()>(pool, &Symbol::new(e, func), call_args);
+}
+
+pub fn check_fee_fraction(policy: &PoolCreationPolicy, fee_fraction: u32) {
+    if !policy.fee_fractions.is_empty() && !policy.fee_fractions.contains(fee_fraction) {
+        panic!("non-standard fee");
+    }
+    if fee_fraction < policy.min_fee_fraction || fee_fraction > policy.max_fee_fraction {
+        panic!("fee is out of allowed range");
+    }
+}
+
+pub fn check_stableswap_params(policy: &PoolCreationPolicy, a: u128, admin_fee: u32) {
+    if a < policy.min_a || a > policy.max_a {
+        panic!("a is out of allowed range");
+    }
+    if admin_fee > policy.max_admin_fee {
+        panic!("admin fee is out of allowed range");
+    }
+}
+
 pub fn merge_salt(e: &Env, left: BytesN<32>, right: BytesN<32>) -> BytesN<32> {
     let mut salt = Bytes::new(e);
     salt.append(&left.to_xdr(e));
@@ -51,23 +120,45 @@ pub fn deploy_standard_pool(
     e: &Env,
     tokens: Vec<Address>,
     fee_fraction: u32,
+    reward_precision: u128,
 ) -> (BytesN<32>, Address) {
     let salt = pool_salt(e, tokens.clone());
     let liquidity_pool_wasm_hash = get_constant_product_pool_hash(e);
     let subpool_salt = get_standard_pool_salt(e, &fee_fraction);
+    // removed pool occupies its deployment address forever
+    if has_pool(e, &salt, subpool_salt.clone()) {
+        panic!("pool is removed")
+    }
 
     let pool_contract_id = e
         .deployer()
         .with_current_contract(merge_salt(e, salt.clone(), subpool_salt.clone()))
         .deploy(liquidity_pool_wasm_hash);
-    init_standard_pool(e, &tokens, &pool_contract_id, fee_fraction);
+    init_standard_pool(
+        e,
+        &tokens,
+        &pool_contract_id,
+        fee_fraction,
+        reward_precision,
+    );
+    set_pool_managed(e, &pool_contract_id, true);
 
+    let init_args = Vec::<Val>::from_array(e, [fee_fraction.into_val(e)]);
     add_pool(
         e,
+        &tokens,
         &salt,
-        subpool_salt.clone(),
-        LiquidityPoolType::ConstantProduct,
-        pool_contract_id.clone(),
+        &LiquidityPoolInfo {
+            pool_index: subpool_salt.clone(),
+            pool_type: LiquidityPoolType::ConstantProduct,
+            pool_type_name: symbol_short!("constant"),
+            address: pool_contract_id.clone(),
+            fee_fraction,
+            a: 0,
+            admin_fee: 0,
+            init_args: init_args.clone(),
+            created_at: e.ledger().timestamp(),
+        },
     );
 
     Events::new(e).add_pool(
@@ -75,7 +166,7 @@ pub fn deploy_standard_pool(
         pool_contract_id.clone(),
         symbol_short!("constant"),
         subpool_salt.clone(),
-        Vec::<Val>::from_array(e, [fee_fraction.into_val(e)]),
+        init_args,
     );
 
     (subpool_salt, pool_contract_id)
@@ -87,6 +178,7 @@ pub fn deploy_stableswap_pool(
     a: u128,
     fee_fraction: u32,
     admin_fee: u32,
+    reward_precision: u128,
 ) -> (BytesN<32>, Address) {
     let salt = pool_salt(e, tokens.clone());
 
@@ -97,15 +189,41 @@ pub fn deploy_stableswap_pool(
         .deployer()
         .with_current_contract(merge_salt(e, salt.clone(), subpool_salt.clone()))
         .deploy(liquidity_pool_wasm_hash);
-    init_stableswap_pool(e, &tokens, &pool_contract_id, a, fee_fraction, admin_fee);
+    init_stableswap_pool(
+        e,
+        &tokens,
+        &pool_contract_id,
+        a,
+        fee_fraction,
+        admin_fee,
+        reward_precision,
+    );
+    set_pool_managed(e, &pool_contract_id, true);
 
+    let init_args = Vec::<Val>::from_array(
+        e,
+        [
+            fee_fraction.into_val(e),
+            a.into_val(e),
+            admin_fee.into_val(e),
+        ],
+    );
     // if STABLESWAP_MAX_POOLS
     add_pool(
         e,
+        &tokens,
         &salt,
-        subpool_salt.clone(),
-        LiquidityPoolType::StableSwap,
-        pool_contract_id.clone(),
+        &LiquidityPoolInfo {
+            pool_index: subpool_salt.clone(),
+            pool_type: LiquidityPoolType::StableSwap,
+            pool_type_name: symbol_short!("stable"),
+            address: pool_contract_id.clone(),
+            fee_fraction,
+            a,
+            admin_fee,
+            init_args: init_args.clone(),
+            created_at: e.ledger().timestamp(),
+        },
     );
 
     Events::new(e).add_pool(
@@ -113,14 +231,7 @@ pub fn deploy_stableswap_pool(
         pool_contract_id.clone(),
         symbol_short!("stable"),
         subpool_salt.clone(),
-        Vec::<Val>::from_array(
-            e,
-            [
-                fee_fraction.into_val(e),
-                a.into_val(e),
-                admin_fee.into_val(e),
-            ],
-        ),
+        init_args,
     );
 
     (subpool_salt, pool_contract_id)
@@ -131,12 +242,13 @@ fn init_standard_pool(
     tokens: &Vec<Address>,
     pool_contract_id: &Address,
     fee_fraction: u32,
+    reward_precision: u128,
 ) {
     let token_wasm_hash = get_token_hash(e);
     let rewards = get_rewards_manager(e);
     let reward_token = rewards.storage().get_reward_token();
-    let access_control = AccessControl::new(e);
-    let admin = access_control.get_admin().unwrap();
+    // router manages deployed pools, so pool admin follows router admin
+    let admin = e.current_contract_address();
     let liq_pool_client = StandardLiquidityPoolClient::new(e, pool_contract_id);
     let plane = get_pool_plane(e);
     liq_pool_client.initialize_all(
@@ -144,6 +256,7 @@ fn init_standard_pool(
         &token_wasm_hash,
         tokens,
         &fee_fraction,
+        &reward_precision,
         &reward_token,
         &liq_pool_client.address,
         &plane,
@@ -157,12 +270,13 @@ fn init_stableswap_pool(
     a: u128,
     fee_fraction: u32,
     admin_fee_fraction: u32,
+    reward_precision: u128,
 ) {
     let token_wasm_hash = get_token_hash(e);
     let rewards = get_rewards_manager(e);
     let reward_token = rewards.storage().get_reward_token();
-    let access_control = AccessControl::new(e);
-    let admin = access_control.get_admin().unwrap();
+    // router manages deployed pools, so pool admin follows router admin
+    let admin = e.current_contract_address();
     let plane = get_pool_plane(e);
     e.invoke_contract::<()>(
         pool_contract_id,
@@ -176,6 +290,7 @@ fn init_stableswap_pool(
                 a.into_val(e),
                 fee_fraction.into_val(e),
                 admin_fee_fraction.into_val(e),
+                reward_precision.into_val(e),
                 reward_token.into_val(e),
                 pool_contract_id.clone().into_val(e),
                 plane.into_val(e),
@@ -197,3 +312,56 @@ pub fn pool_salt(e: &Env, tokens: Vec<Address>) -> BytesN<32> {
     }
     e.crypto().sha256(&salt)
 }
+
+// claim primary stream rewards of every pool in the list.
+// rewards of pools using router as reward storage are paid out with single transfer.
+// router deployed pools are their own reward storage, so they pay out rewards one by one
+pub fn claim_pools_rewards(
+    e: &Env,
+    user: &Address,
+    pools: Vec<(Vec<Address>, BytesN<32>)>,
+) -> Vec<u128> {
+    if pools.len() > MAX_CLAIM_POOLS {
+        panic!("too many pools at once")
+    }
+
+    let rewards = get_rewards_manager(e);
+    let reward_token = rewards.storage().get_reward_token();
+    let mut result = Vec::new(e);
+    let mut aggregated_reward = 0;
+
+    for (tokens, pool_index) in pools {
+        let pool_id = get_pool(e, tokens.clone(), pool_index).expect("Pool doesn't exist");
+        let pool_reward_token: Address =
+            e.invoke_contract(&pool_id, &Symbol::new(e, "get_reward_token"), Vec::new(e));
+        let pool_reward_storage: Address =
+            e.invoke_contract(&pool_id, &Symbol::new(e, "get_reward_storage"), Vec::new(e));
+
+        // router is the reward storage - collect reward to pay it out later with single transfer
+        let reward_shared = pool_reward_token == reward_token
+            && pool_reward_storage == e.current_contract_address();
+        let reward: u128 = e.invoke_contract(
+            &pool_id,
+            &match reward_shared {
+                true => Symbol::new(e, "collect_reward"),
+                false => symbol_short!("claim"),
+            },
+            Vec::from_array(e, [user.clone().into_val(e)]),
+        );
+        if reward_shared {
+            aggregated_reward += reward;
+        }
+
+        Events::new(e).claim(tokens, user.clone(), pool_id, reward);
+        result.push_back(reward);
+    }
+
+    if aggregated_reward > 0 {
+        SorobanTokenClient::new(e, &reward_token).transfer(
+            &e.current_contract_address(),
+            user,
+            &(aggregated_reward as i128),
+        );
+    }
+    result
+}
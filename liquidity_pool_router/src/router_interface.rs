@@ -1,4 +1,5 @@
-use soroban_sdk::{Address, BytesN, Env};
+use crate::storage::{LiquidityPoolType, PoolCreationPolicy};
+use soroban_sdk::{Address, BytesN, Env, Vec};
 
 pub trait UpgradeableContract {
     // Get contract version
@@ -6,6 +7,77 @@ pub trait UpgradeableContract {
 
     // Upgrade contract with new wasm code
     fn upgrade(e: Env, new_wasm_hash: BytesN<32>);
+
+    // Upgrade pools of given type with new wasm code, page by page over registered tokens sets.
+    // Router or router admin should be admin of every pool. Removed pools are skipped.
+    // Pools the router is unable to upgrade, e.g. constant product pools deployed before
+    // upgrades were supported, are reported with `upgrade_pool_failed` event.
+    // tokens_count: only tokens sets of this size are processed, stableswap code depends on it
+    // offset: index of first tokens set to process
+    // limit: maximum amount of tokens sets to process
+    // Returns address and code version of every upgraded pool
+    fn upgrade_pools(
+        e: Env,
+        pool_type: LiquidityPoolType,
+        tokens_count: u32,
+        offset: u128,
+        limit: u128,
+        new_wasm_hash: BytesN<32>,
+    ) -> Vec<(Address, u32)>;
+
+    // Get pool code version
+    fn get_pool_version(e: Env, tokens: Vec<Address>, pool_index: BytesN<32>) -> u32;
+}
+
+pub trait PoolsAdminInterface {
+    // Commit admin rights transfer of batch of pools to new admin. Pass router address as new admin
+    // to make router manage pools created before router became pools admin.
+    // Router admin should be admin of every pool not managed by router.
+    // Transfer can be applied once transfer delay of every pool has passed
+    fn commit_pools_admin(
+        e: Env,
+        admin: Address,
+        pools: Vec<(Vec<Address>, BytesN<32>)>,
+        new_admin: Address,
+    );
+
+    // Apply committed admin rights transfer of batch of pools
+    fn apply_pools_admin(e: Env, admin: Address, pools: Vec<(Vec<Address>, BytesN<32>)>);
+
+    // Check if pool admin actions are performed by router on behalf of its admins
+    fn is_pool_managed(e: Env, tokens: Vec<Address>, pool_index: BytesN<32>) -> bool;
+
+    // Stop pool operations except withdrawals. Only emergency pause admin
+    fn kill_pool(e: Env, user: Address, tokens: Vec<Address>, pool_index: BytesN<32>);
+
+    // Resume pool operations. Only emergency pause admin
+    fn unkill_pool(e: Env, user: Address, tokens: Vec<Address>, pool_index: BytesN<32>);
+
+    // Commit new fee for stableswap pool. Only operations admin
+    fn commit_pool_fee(
+        e: Env,
+        user: Address,
+        tokens: Vec<Address>,
+        pool_index: BytesN<32>,
+        new_fee: u32,
+        new_admin_fee: u32,
+    );
+
+    // Apply committed pool fee. Only operations admin
+    fn apply_pool_fee(e: Env, user: Address, tokens: Vec<Address>, pool_index: BytesN<32>);
+
+    // Revert committed pool fee. Only operations admin
+    fn revert_pool_fee(e: Env, user: Address, tokens: Vec<Address>, pool_index: BytesN<32>);
+
+    // Withdraw pool admin fees to destination address. Only router admin
+    // returns amounts withdrawn for every pool token
+    fn withdraw_pool_admin_fees(
+        e: Env,
+        admin: Address,
+        tokens: Vec<Address>,
+        pool_index: BytesN<32>,
+        to: Address,
+    ) -> Vec<u128>;
 }
 
 pub trait AdminInterface {
@@ -24,6 +96,13 @@ pub trait AdminInterface {
     // Configure stableswap init payment: token address, amount and destination address
     fn configure_init_pool_payment(e: Env, token: Address, amount: u128, to: Address);
 
+    // Set pool creation rules for given pool type: whether anyone can create pools,
+    // creation payment, allowed fee tiers, bounds for pool parameters and reward precision
+    fn set_pool_creation_policy(e: Env, pool_type: LiquidityPoolType, policy: PoolCreationPolicy);
+
+    // Get pool creation rules for given pool type
+    fn get_pool_creation_policy(e: Env, pool_type: LiquidityPoolType) -> PoolCreationPolicy;
+
     // Set reward token address
     fn set_reward_token(e: Env, reward_token: Address);
 }
@@ -0,0 +1,8 @@
+use soroban_sdk::{contractclient, Address, Env};
+
+// Part of staking gauge interface used to link gauge to the pool
+#[allow(dead_code)]
+#[contractclient(name = "StakingGaugeClient")]
+pub trait StakingGaugeInterface {
+    fn get_lp_token(e: Env) -> Address;
+}
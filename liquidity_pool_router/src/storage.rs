@@ -1,7 +1,14 @@
-use crate::constants::{MAX_POOLS_FOR_PAIR, STABLESWAP_MAX_POOLS};
+use crate::constants::{
+    CONSTANT_PRODUCT_DEFAULT_FEE_FRACTIONS, CONSTANT_PRODUCT_MAX_FEE_FRACTION, MAX_POOLS_FOR_PAIR,
+    STABLESWAP_MAX_A, STABLESWAP_MAX_ADMIN_FEE, STABLESWAP_MAX_FEE_FRACTION, STABLESWAP_MAX_POOLS,
+    TOKEN_TOKENS_SETS_PAGE_SIZE,
+};
 use crate::pool_utils::pool_salt;
 use paste::paste;
-use soroban_sdk::{contracterror, contracttype, Address, BytesN, Env, Map, Vec};
+use rewards::DEFAULT_REWARD_PRECISION;
+use soroban_sdk::{
+    contracterror, contracttype, symbol_short, Address, BytesN, Env, Map, Symbol, Val, Vec,
+};
 use utils::bump::{bump_instance, bump_persistent};
 use utils::{
     generate_instance_storage_getter, generate_instance_storage_getter_and_setter,
@@ -19,6 +26,17 @@ pub enum LiquidityPoolType {
     Custom = 3,
 }
 
+// Pool lifecycle state. Deprecated pools accept only withdrawals and reward claims,
+// removed pools are additionally hidden from pools lists
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum PoolState {
+    Active = 0,
+    Deprecated = 1,
+    Removed = 2,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct LiquidityPoolData {
@@ -26,6 +44,56 @@ pub struct LiquidityPoolData {
     pub address: Address,
 }
 
+// Pool registry record with parameters pool was created with
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LiquidityPoolInfo {
+    pub pool_index: BytesN<32>,
+    pub pool_type: LiquidityPoolType,
+    // `constant` and `stable` for pools deployed by router, custom pool type otherwise
+    pub pool_type_name: Symbol,
+    pub address: Address,
+    pub fee_fraction: u32,
+    // stableswap only
+    pub a: u128,
+    // stableswap only
+    pub admin_fee: u32,
+    // arguments pool was registered with, same as in `add_pool` event
+    pub init_args: Vec<Val>,
+    // zero for pools registered before metadata was introduced
+    pub created_at: u64,
+}
+
+// Payment charged from the user for pool creation
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PoolCreationPayment {
+    pub token: Address,
+    pub amount: u128,
+    pub to: Address,
+}
+
+// Rules applied on pool creation for specific pool type
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PoolCreationPolicy {
+    // if disabled, only admin is able to create pools
+    pub permissionless: bool,
+    // payments charged for pool creation. pool creation is free if empty
+    pub payments: Vec<PoolCreationPayment>,
+    // allowed fee tiers. any fee within bounds is accepted if empty
+    pub fee_fractions: Vec<u32>,
+    pub min_fee_fraction: u32,
+    pub max_fee_fraction: u32,
+    // amplification coefficient bounds. stableswap only
+    pub min_a: u128,
+    pub max_a: u128,
+    // admin fee upper bound. stableswap only
+    pub max_admin_fee: u32,
+    // reward per share precision of created pools
+    pub reward_precision: u128,
+}
+
 #[derive(Clone)]
 #[contracttype]
 enum DataKey {
@@ -39,6 +107,19 @@ enum DataKey {
     StableSwapCounter,
     PoolPlane,
     SwapRouter,
+    TokensSetsCount,
+    TokensSet(u128),
+    TokensSetIndex(BytesN<32>),
+    TokenTokensSetsCount(Address),
+    TokenTokensSetsPage(Address, u32),
+    PoolsCount,
+    PoolCreationPolicy(LiquidityPoolType),
+    PoolVersion(Address),
+    PoolInfo(Address),
+    PoolState(Address),
+    PoolManaged(Address),
+    PoolGauge(Address),
+    MerkleDistributor,
 }
 
 #[contracterror]
@@ -49,7 +130,7 @@ pub enum PoolError {
     PoolNotFound = 404,
 }
 
-fn get_pools(e: &Env, salt: &BytesN<32>) -> Map<BytesN<32>, LiquidityPoolData> {
+pub fn get_pools(e: &Env, salt: &BytesN<32>) -> Map<BytesN<32>, LiquidityPoolData> {
     let key = DataKey::TokensPairPools(salt.clone());
     match e.storage().persistent().get(&key) {
         Some(value) => {
@@ -66,29 +147,32 @@ generate_instance_storage_getter_and_setter!(
     BytesN<32>
 );
 generate_instance_storage_getter_and_setter!(token_hash, DataKey::TokenHash, BytesN<32>);
-generate_instance_storage_getter_and_setter!(
-    init_pool_payment_token,
-    DataKey::InitPoolPaymentToken,
-    Address
-);
-generate_instance_storage_getter_and_setter!(
-    init_pool_payment_amount,
-    DataKey::InitPoolPaymentAmount,
-    u128
-);
-generate_instance_storage_getter_and_setter!(
-    init_pool_payment_address,
-    DataKey::InitPoolPaymentAddress,
-    Address
-);
 generate_instance_storage_getter_and_setter_with_default!(
     stableswap_counter,
     DataKey::StableSwapCounter,
     u128,
     0
 );
+generate_instance_storage_getter_and_setter_with_default!(
+    tokens_sets_count,
+    DataKey::TokensSetsCount,
+    u128,
+    0
+);
+generate_instance_storage_getter_and_setter_with_default!(
+    pools_count,
+    DataKey::PoolsCount,
+    u128,
+    0
+);
 generate_instance_storage_getter_and_setter!(pool_plane, DataKey::PoolPlane, Address);
 generate_instance_storage_getter_and_setter!(swap_router, DataKey::SwapRouter, Address);
+generate_instance_storage_setter!(merkle_distributor, DataKey::MerkleDistributor, Address);
+
+pub fn get_merkle_distributor(e: &Env) -> Option<Address> {
+    bump_instance(e);
+    e.storage().instance().get(&DataKey::MerkleDistributor)
+}
 
 // pool hash
 pub fn get_stableswap_pool_hash(e: &Env, num_tokens: u32) -> BytesN<32> {
@@ -109,15 +193,195 @@ pub fn set_stableswap_pool_hash(e: &Env, num_tokens: u32, pool_hash: &BytesN<32>
         .set(&DataKey::StableSwapPoolHash(num_tokens), pool_hash)
 }
 
+// pool creation policy
+fn get_default_pool_creation_policy(e: &Env, pool_type: LiquidityPoolType) -> PoolCreationPolicy {
+    match pool_type {
+        LiquidityPoolType::ConstantProduct => PoolCreationPolicy {
+            permissionless: true,
+            payments: Vec::new(e),
+            fee_fractions: Vec::from_array(e, CONSTANT_PRODUCT_DEFAULT_FEE_FRACTIONS),
+            min_fee_fraction: 0,
+            max_fee_fraction: CONSTANT_PRODUCT_MAX_FEE_FRACTION,
+            min_a: 0,
+            max_a: 0,
+            max_admin_fee: 0,
+            reward_precision: DEFAULT_REWARD_PRECISION,
+        },
+        LiquidityPoolType::StableSwap => PoolCreationPolicy {
+            permissionless: true,
+            payments: get_legacy_init_pool_payments(e),
+            fee_fractions: Vec::new(e),
+            min_fee_fraction: 0,
+            max_fee_fraction: STABLESWAP_MAX_FEE_FRACTION,
+            min_a: 1,
+            max_a: STABLESWAP_MAX_A - 1,
+            max_admin_fee: STABLESWAP_MAX_ADMIN_FEE,
+            reward_precision: DEFAULT_REWARD_PRECISION,
+        },
+        _ => panic!("pool type doesn't support creation policy"),
+    }
+}
+
+// stableswap pool creation payment configured before creation policies were introduced
+fn get_legacy_init_pool_payments(e: &Env) -> Vec<PoolCreationPayment> {
+    let token = match e.storage().instance().get(&DataKey::InitPoolPaymentToken) {
+        Some(value) => value,
+        None => return Vec::new(e),
+    };
+    Vec::from_array(
+        e,
+        [PoolCreationPayment {
+            token,
+            amount: e
+                .storage()
+                .instance()
+                .get(&DataKey::InitPoolPaymentAmount)
+                .unwrap(),
+            to: e
+                .storage()
+                .instance()
+                .get(&DataKey::InitPoolPaymentAddress)
+                .unwrap(),
+        }],
+    )
+}
+
+pub fn get_pool_creation_policy(e: &Env, pool_type: LiquidityPoolType) -> PoolCreationPolicy {
+    bump_instance(e);
+    match e
+        .storage()
+        .instance()
+        .get(&DataKey::PoolCreationPolicy(pool_type))
+    {
+        Some(value) => value,
+        None => get_default_pool_creation_policy(e, pool_type),
+    }
+}
+
+pub fn set_pool_creation_policy(
+    e: &Env,
+    pool_type: LiquidityPoolType,
+    policy: &PoolCreationPolicy,
+) {
+    bump_instance(e);
+    e.storage()
+        .instance()
+        .set(&DataKey::PoolCreationPolicy(pool_type), policy)
+}
+
+// pools which are not removed
 pub fn get_pools_plain(e: &Env, salt: &BytesN<32>) -> Map<BytesN<32>, Address> {
     let pools = get_pools(e, salt);
     let mut pools_plain = Map::new(e);
     for (key, value) in pools {
-        pools_plain.set(key, value.address);
+        if get_pool_state(e, &value.address) != PoolState::Removed {
+            pools_plain.set(key, value.address);
+        }
     }
     pools_plain
 }
 
+pub fn get_pool_state(e: &Env, pool: &Address) -> PoolState {
+    let key = DataKey::PoolState(pool.clone());
+    match e.storage().persistent().get(&key) {
+        Some(value) => {
+            bump_persistent(e, &key);
+            value
+        }
+        None => PoolState::Active,
+    }
+}
+
+pub fn set_pool_state(e: &Env, pool: &Address, state: PoolState) {
+    let key = DataKey::PoolState(pool.clone());
+    e.storage().persistent().set(&key, &state);
+    bump_persistent(e, &key);
+}
+
+// pool is managed by router when router is its admin
+pub fn is_pool_managed(e: &Env, pool: &Address) -> bool {
+    let key = DataKey::PoolManaged(pool.clone());
+    match e.storage().persistent().get(&key) {
+        Some(value) => {
+            bump_persistent(e, &key);
+            value
+        }
+        None => false,
+    }
+}
+
+pub fn set_pool_managed(e: &Env, pool: &Address, value: bool) {
+    let key = DataKey::PoolManaged(pool.clone());
+    e.storage().persistent().set(&key, &value);
+    bump_persistent(e, &key);
+}
+
+// staking gauge distributing incentives to pool liquidity providers
+pub fn get_pool_gauge(e: &Env, pool: &Address) -> Option<Address> {
+    let key = DataKey::PoolGauge(pool.clone());
+    let value = e.storage().persistent().get(&key);
+    if value.is_some() {
+        bump_persistent(e, &key);
+    }
+    value
+}
+
+pub fn set_pool_gauge(e: &Env, pool: &Address, gauge: &Address) {
+    let key = DataKey::PoolGauge(pool.clone());
+    e.storage().persistent().set(&key, gauge);
+    bump_persistent(e, &key);
+}
+
+pub fn remove_pool_gauge(e: &Env, pool: &Address) {
+    e.storage()
+        .persistent()
+        .remove(&DataKey::PoolGauge(pool.clone()));
+}
+
+// registry records for all pools of given tokens set
+pub fn get_pools_info(e: &Env, salt: &BytesN<32>) -> Map<BytesN<32>, LiquidityPoolInfo> {
+    let mut result = Map::new(e);
+    for (pool_index, data) in get_pools(e, salt) {
+        if get_pool_state(e, &data.address) == PoolState::Removed {
+            continue;
+        }
+        let key = DataKey::PoolInfo(data.address.clone());
+        let info = match e.storage().persistent().get(&key) {
+            Some(value) => {
+                bump_persistent(e, &key);
+                value
+            }
+            None => get_legacy_pool_info(e, pool_index.clone(), data),
+        };
+        result.set(pool_index, info);
+    }
+    result
+}
+
+// pools registered before metadata was introduced have no creation parameters saved
+fn get_legacy_pool_info(
+    e: &Env,
+    pool_index: BytesN<32>,
+    data: LiquidityPoolData,
+) -> LiquidityPoolInfo {
+    let pool_type_name = match data.pool_type {
+        LiquidityPoolType::ConstantProduct => symbol_short!("constant"),
+        LiquidityPoolType::StableSwap => symbol_short!("stable"),
+        _ => symbol_short!("custom"),
+    };
+    LiquidityPoolInfo {
+        pool_index,
+        pool_type: data.pool_type,
+        pool_type_name,
+        address: data.address,
+        fee_fraction: 0,
+        a: 0,
+        admin_fee: 0,
+        init_args: Vec::new(e),
+        created_at: 0,
+    }
+}
+
 pub fn put_pools(e: &Env, salt: &BytesN<32>, pools: &Map<BytesN<32>, LiquidityPoolData>) {
     let key = DataKey::TokensPairPools(salt.clone());
     e.storage().persistent().set(&key, pools);
@@ -141,26 +405,26 @@ pub fn get_pool(
     }
 }
 
-pub fn add_pool(
-    e: &Env,
-    salt: &BytesN<32>,
-    pool_index: BytesN<32>,
-    pool_type: LiquidityPoolType,
-    pool_address: Address,
-) {
+pub fn add_pool(e: &Env, tokens: &Vec<Address>, salt: &BytesN<32>, info: &LiquidityPoolInfo) {
+    let pool_type = info.pool_type;
     let mut pools = get_pools(e, salt);
+    if pools.contains_key(info.pool_index.clone()) {
+        panic!("pool already exists")
+    }
     pools.set(
-        pool_index,
+        info.pool_index.clone(),
         LiquidityPoolData {
             pool_type,
-            address: pool_address,
+            address: info.address.clone(),
         },
     );
 
     if pool_type == LiquidityPoolType::StableSwap {
         let mut stableswap_pools_amt = 0;
         for (_key, value) in pools.clone() {
-            if value.pool_type == LiquidityPoolType::StableSwap {
+            if value.pool_type == LiquidityPoolType::StableSwap
+                && get_pool_state(e, &value.address) != PoolState::Removed
+            {
                 stableswap_pools_amt += 1;
             }
         }
@@ -169,16 +433,168 @@ pub fn add_pool(
         }
     }
 
-    if pools.len() > MAX_POOLS_FOR_PAIR {
+    let mut pools_amt = 0;
+    for (_key, value) in pools.clone() {
+        if get_pool_state(e, &value.address) != PoolState::Removed {
+            pools_amt += 1;
+        }
+    }
+    if pools_amt > MAX_POOLS_FOR_PAIR {
         panic!("pools amount is over max")
     }
     put_pools(e, salt, &pools);
+    let info_key = DataKey::PoolInfo(info.address.clone());
+    e.storage().persistent().set(&info_key, info);
+    bump_persistent(e, &info_key);
+    add_tokens_set(e, tokens, salt);
+    set_pools_count(e, &(get_pools_count(e) + 1));
 }
 
-pub fn remove_pool(e: &Env, salt: &BytesN<32>, pool_index: BytesN<32>) {
-    let mut pools = get_pools(e, salt);
-    pools.remove(pool_index);
-    put_pools(e, salt, &pools);
+// pool stays in registry to keep it accessible for withdrawals and claims
+pub fn remove_pool(e: &Env, pool: &Address) {
+    if get_pool_state(e, pool) == PoolState::Removed {
+        return;
+    }
+    set_pool_state(e, pool, PoolState::Removed);
+    // pools registered before the index was introduced are counted only once backfilled
+    if has_pool_info(e, pool) {
+        set_pools_count(e, &(get_pools_count(e) - 1));
+    }
+}
+
+fn has_pool_info(e: &Env, pool: &Address) -> bool {
+    e.storage()
+        .persistent()
+        .has(&DataKey::PoolInfo(pool.clone()))
+}
+
+// register tokens set and its pools created before the index was introduced.
+// pools already present in the index are skipped. returns amount of pools indexed
+pub fn index_legacy_pools(e: &Env, tokens: &Vec<Address>, salt: &BytesN<32>) -> u32 {
+    let pools = get_pools(e, salt);
+    if pools.is_empty() {
+        panic!("pool not found")
+    }
+    add_tokens_set(e, tokens, salt);
+
+    let mut indexed = 0;
+    let mut pools_count = get_pools_count(e);
+    for (pool_index, data) in pools {
+        if has_pool_info(e, &data.address) {
+            continue;
+        }
+        if get_pool_state(e, &data.address) != PoolState::Removed {
+            pools_count += 1;
+        }
+        let info_key = DataKey::PoolInfo(data.address.clone());
+        e.storage()
+            .persistent()
+            .set(&info_key, &get_legacy_pool_info(e, pool_index, data));
+        bump_persistent(e, &info_key);
+        indexed += 1;
+    }
+    set_pools_count(e, &pools_count);
+    indexed
+}
+
+// tokens sets index. append-only: once registered, tokens set is never removed from the list
+fn add_tokens_set(e: &Env, tokens: &Vec<Address>, salt: &BytesN<32>) {
+    let index_key = DataKey::TokensSetIndex(salt.clone());
+    if e.storage().persistent().has(&index_key) {
+        bump_persistent(e, &index_key);
+        return;
+    }
+
+    let index = get_tokens_sets_count(e);
+    let set_key = DataKey::TokensSet(index);
+    e.storage().persistent().set(&set_key, tokens);
+    bump_persistent(e, &set_key);
+    e.storage().persistent().set(&index_key, &index);
+    bump_persistent(e, &index_key);
+    set_tokens_sets_count(e, &(index + 1));
+
+    for token in tokens.iter() {
+        let token_count = get_token_tokens_sets_count(e, &token);
+        let page = (token_count / TOKEN_TOKENS_SETS_PAGE_SIZE) as u32;
+        let mut token_sets = get_token_tokens_sets_page(e, &token, page);
+        token_sets.push_back(index);
+        let page_key = DataKey::TokenTokensSetsPage(token.clone(), page);
+        e.storage().persistent().set(&page_key, &token_sets);
+        bump_persistent(e, &page_key);
+        let count_key = DataKey::TokenTokensSetsCount(token);
+        e.storage().persistent().set(&count_key, &(token_count + 1));
+        bump_persistent(e, &count_key);
+    }
+}
+
+pub fn get_tokens_set(e: &Env, index: u128) -> Vec<Address> {
+    let key = DataKey::TokensSet(index);
+    match e.storage().persistent().get(&key) {
+        Some(value) => {
+            bump_persistent(e, &key);
+            value
+        }
+        None => panic!("tokens set not found"),
+    }
+}
+
+// pool code version recorded on last upgrade through the router
+pub fn get_pool_version(e: &Env, pool: &Address) -> Option<u32> {
+    let key = DataKey::PoolVersion(pool.clone());
+    let value = e.storage().persistent().get(&key);
+    if value.is_some() {
+        bump_persistent(e, &key);
+    }
+    value
+}
+
+pub fn set_pool_version(e: &Env, pool: &Address, version: u32) {
+    let key = DataKey::PoolVersion(pool.clone());
+    e.storage().persistent().set(&key, &version);
+    bump_persistent(e, &key);
+}
+
+// amount of tokens sets containing given token
+pub fn get_token_tokens_sets_count(e: &Env, token: &Address) -> u128 {
+    let key = DataKey::TokenTokensSetsCount(token.clone());
+    match e.storage().persistent().get(&key) {
+        Some(value) => {
+            bump_persistent(e, &key);
+            value
+        }
+        None => 0,
+    }
+}
+
+// indexes of tokens sets containing given token are stored in fixed size pages
+fn get_token_tokens_sets_page(e: &Env, token: &Address, page: u32) -> Vec<u128> {
+    let key = DataKey::TokenTokensSetsPage(token.clone(), page);
+    match e.storage().persistent().get(&key) {
+        Some(value) => {
+            bump_persistent(e, &key);
+            value
+        }
+        None => Vec::new(e),
+    }
+}
+
+// indexes of tokens sets containing given token, starting from offset
+pub fn get_token_tokens_sets(e: &Env, token: &Address, offset: u128, limit: u128) -> Vec<u128> {
+    let end = get_token_tokens_sets_count(e, token).min(offset.saturating_add(limit));
+    let mut result = Vec::new(e);
+    let mut position = offset;
+    while position < end {
+        let page = (position / TOKEN_TOKENS_SETS_PAGE_SIZE) as u32;
+        let page_sets = get_token_tokens_sets_page(e, token, page);
+        let page_end = end.min((page as u128 + 1) * TOKEN_TOKENS_SETS_PAGE_SIZE);
+        while position < page_end {
+            result.push_back(
+                page_sets.get_unchecked((position % TOKEN_TOKENS_SETS_PAGE_SIZE) as u32),
+            );
+            position += 1;
+        }
+    }
+    result
 }
 
 pub fn get_stableswap_next_counter(e: &Env) -> u128 {
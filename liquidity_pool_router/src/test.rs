@@ -1,12 +1,22 @@
 #![cfg(test)]
 extern crate std;
 
-use crate::constants::{CONSTANT_PRODUCT_FEE_AVAILABLE, MAX_POOLS_FOR_PAIR, STABLESWAP_MAX_POOLS};
+use crate::constants::{
+    CONSTANT_PRODUCT_DEFAULT_FEE_FRACTIONS, MAX_POOLS_FOR_PAIR, STABLESWAP_MAX_POOLS,
+};
+use crate::merkle_distributor::MerkleClaim;
+use crate::pool_utils::pool_salt;
+use crate::storage::{
+    put_pools, LiquidityPoolData, LiquidityPoolType, PoolCreationPayment, PoolCreationPolicy,
+    PoolState,
+};
 use crate::LiquidityPoolRouterClient;
+use access_control::role::Role;
+use rewards::storage::{RewardsFunding, ScheduledRewardConfig, VolumeMiningConfig};
 use soroban_sdk::testutils::{Events, Ledger, LedgerInfo};
 use soroban_sdk::{
-    symbol_short, testutils::Address as _, vec, Address, BytesN, Env, FromVal, IntoVal, Symbol,
-    Val, Vec,
+    symbol_short, testutils::Address as _, vec, xdr::ToXdr, Address, Bytes, BytesN, Env, FromVal,
+    IntoVal, Map, Symbol, Val, Vec,
 };
 
 pub(crate) mod test_token {
@@ -54,6 +64,16 @@ fn install_stableswap_three_tokens_liq_pool_hash(e: &Env) -> BytesN<32> {
     e.deployer().upload_contract_wasm(WASM)
 }
 
+mod standard_pool {
+    soroban_sdk::contractimport!(
+        file = "../target/wasm32-unknown-unknown/release/soroban_liquidity_pool_contract.wasm"
+    );
+}
+
+fn create_standard_pool_contract<'a>(e: &Env) -> standard_pool::Client<'a> {
+    standard_pool::Client::new(e, &e.register_contract_wasm(None, standard_pool::WASM))
+}
+
 mod pool_plane {
     soroban_sdk::contractimport!(
         file =
@@ -76,6 +96,22 @@ fn create_swap_router_contract<'a>(e: &Env) -> swap_router::Client<'a> {
     swap_router::Client::new(e, &e.register_contract_wasm(None, swap_router::WASM))
 }
 
+mod staking_gauge {
+    soroban_sdk::contractimport!(
+        file = "../target/wasm32-unknown-unknown/release/soroban_staking_gauge_contract.wasm"
+    );
+}
+
+mod merkle_distributor {
+    soroban_sdk::contractimport!(
+        file = "../target/wasm32-unknown-unknown/release/soroban_merkle_distributor_contract.wasm"
+    );
+}
+
+fn create_staking_gauge_contract<'a>(e: &Env) -> staking_gauge::Client<'a> {
+    staking_gauge::Client::new(e, &e.register_contract_wasm(None, staking_gauge::WASM))
+}
+
 fn jump(e: &Env, time: u64) {
     e.ledger().set(LedgerInfo {
         timestamp: e.ledger().timestamp().saturating_add(time),
@@ -263,7 +299,7 @@ fn test_stableswap_pools_amount_over_max() {
     assert_eq!(reward_token.balance(&payment_for_creation_address), 0);
 
     // init constant product pools to make sure we don't affect stableswap counter
-    for fee_fraction in CONSTANT_PRODUCT_FEE_AVAILABLE {
+    for fee_fraction in CONSTANT_PRODUCT_DEFAULT_FEE_FRACTIONS {
         router.init_standard_pool(&admin, &tokens, &fee_fraction);
     }
     reward_token.mint(&admin, &10000000_0000000);
@@ -319,7 +355,7 @@ fn test_stableswap_pools_amount_ok() {
     assert_eq!(reward_token.balance(&payment_for_creation_address), 0);
 
     // init constant product pools to make sure we don't affect stableswap counter
-    for fee_fraction in CONSTANT_PRODUCT_FEE_AVAILABLE {
+    for fee_fraction in CONSTANT_PRODUCT_DEFAULT_FEE_FRACTIONS {
         router.init_standard_pool(&admin, &tokens, &fee_fraction);
     }
     reward_token.mint(&admin, &10000000_0000000);
@@ -1031,7 +1067,7 @@ fn test_unexpected_fee() {
     router.set_token_hash(&token_hash);
     router.set_reward_token(&reward_token.address);
 
-    let fee = CONSTANT_PRODUCT_FEE_AVAILABLE[1] + 1;
+    let fee = CONSTANT_PRODUCT_DEFAULT_FEE_FRACTIONS[1] + 1;
     router.init_standard_pool(&user1, &tokens, &fee);
 }
 
@@ -1089,7 +1125,7 @@ fn test_event_correct() {
     let (_pool_hash, custom_pool_address) = router_1.init_standard_pool(&user1, &tokens, &30);
     reward_token.mint(&user1, &10000000_0000000);
     reward_token.approve(&user1, &router.address, &10000000_0000000, &99999);
-    let fee = CONSTANT_PRODUCT_FEE_AVAILABLE[1];
+    let fee = CONSTANT_PRODUCT_DEFAULT_FEE_FRACTIONS[1];
     let admin_fee = 0;
 
     let (pool_hash, pool_address) =
@@ -1177,6 +1213,7 @@ fn test_event_correct() {
     );
 
     reward_token.mint(&router.address, &1_000_000_0000000);
+    reward_token.mint(&pool_address, &1_000_000_0000000);
     let reward_1_tps = 10_5000000_u128;
     router.set_rewards_config(
         &admin,
@@ -1406,3 +1443,3452 @@ fn test_swap_routed() {
     e.budget().print();
     assert_eq!(swap_result, best_result);
 }
+
+#[test]
+fn test_pools_registry() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.budget().reset_unlimited();
+
+    let token_admin = Address::generate(&e);
+    let mut tokens_list = std::vec![
+        create_token_contract(&e, &token_admin).address,
+        create_token_contract(&e, &token_admin).address,
+        create_token_contract(&e, &token_admin).address,
+    ];
+    tokens_list.sort();
+    let (token1, token2, token3) = (
+        tokens_list[0].clone(),
+        tokens_list[1].clone(),
+        tokens_list[2].clone(),
+    );
+    let tokens_1_2 = Vec::from_array(&e, [token1.clone(), token2.clone()]);
+    let tokens_2_3 = Vec::from_array(&e, [token2.clone(), token3.clone()]);
+
+    let reward_admin = Address::generate(&e);
+    let admin = Address::generate(&e);
+
+    let reward_token = create_token_contract(&e, &reward_admin);
+
+    let pool_hash = install_liq_pool_hash(&e);
+    let token_hash = install_token_wasm(&e);
+    let plane = create_plane_contract(&e);
+    let router = create_liqpool_router_contract(&e);
+    router.init_admin(&admin);
+    router.set_pool_hash(&pool_hash);
+    router.set_token_hash(&token_hash);
+    router.set_reward_token(&reward_token.address);
+    router.set_pools_plane(&admin, &plane.address);
+
+    assert_eq!(router.get_tokens_sets_count(), 0);
+    assert_eq!(router.get_pools_count(), 0);
+    assert_eq!(router.get_tokens_sets(&0, &10), Vec::new(&e));
+
+    let (pool_1_2_10, address_1_2_10) = router.init_standard_pool(&admin, &tokens_1_2, &10);
+    let (pool_1_2_30, address_1_2_30) = router.init_standard_pool(&admin, &tokens_1_2, &30);
+    let (pool_2_3_30, address_2_3_30) = router.init_standard_pool(&admin, &tokens_2_3, &30);
+    // existing pool shouldn't be registered twice
+    router.init_standard_pool(&admin, &tokens_2_3, &30);
+
+    assert_eq!(router.get_tokens_sets_count(), 2);
+    assert_eq!(router.get_pools_count(), 3);
+    assert_eq!(
+        router.get_tokens_sets(&0, &10),
+        Vec::from_array(&e, [tokens_1_2.clone(), tokens_2_3.clone()])
+    );
+    assert_eq!(
+        router.get_tokens_sets(&1, &1),
+        Vec::from_array(&e, [tokens_2_3.clone()])
+    );
+    assert_eq!(router.get_tokens_sets(&2, &10), Vec::new(&e));
+
+    let token1_pools = router.get_pools_for_token(&token1, &0, &10);
+    assert_eq!(token1_pools.len(), 1);
+    let (token1_set, token1_set_pools) = token1_pools.get(0).unwrap();
+    assert_eq!(token1_set, tokens_1_2);
+    assert_eq!(token1_set_pools.len(), 2);
+    assert_eq!(
+        token1_set_pools.get(pool_1_2_10.clone()).unwrap(),
+        address_1_2_10
+    );
+    assert_eq!(
+        token1_set_pools.get(pool_1_2_30.clone()).unwrap(),
+        address_1_2_30
+    );
+
+    let token2_pools = router.get_pools_for_token(&token2, &0, &10);
+    assert_eq!(token2_pools.len(), 2);
+    assert_eq!(token2_pools.get(0).unwrap().0, tokens_1_2);
+    assert_eq!(token2_pools.get(1).unwrap().0, tokens_2_3);
+    assert_eq!(
+        token2_pools.get(1).unwrap().1.get(pool_2_3_30.clone()),
+        Some(address_2_3_30)
+    );
+
+    assert_eq!(
+        router
+            .get_pools_for_token(&reward_token.address, &0, &10)
+            .len(),
+        0
+    );
+
+    // removed pool stays out of the pools list, but tokens set remains indexed
+    router.remove_pool(&admin, &tokens_2_3, &pool_2_3_30);
+    assert_eq!(router.get_pools_count(), 2);
+    assert_eq!(router.get_tokens_sets_count(), 2);
+    assert_eq!(
+        router
+            .get_pools_for_token(&token3, &0, &10)
+            .get(0)
+            .unwrap()
+            .1
+            .len(),
+        0
+    );
+    // removing pool twice doesn't affect pools count
+    router.remove_pool(&admin, &tokens_2_3, &pool_2_3_30);
+    assert_eq!(router.get_pools_count(), 2);
+
+    assert_eq!(router.get_token_tokens_sets_count(&token2), 2);
+    assert_eq!(
+        router
+            .get_pools_for_token(&token2, &1, &10)
+            .get(0)
+            .unwrap()
+            .0,
+        tokens_2_3
+    );
+    assert_eq!(router.get_pools_for_token(&token2, &2, &10).len(), 0);
+    assert!(router.try_get_pools_for_token(&token2, &0, &21).is_err());
+}
+
+#[test]
+fn test_index_legacy_pools() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let router = create_liqpool_router_contract(&e);
+    router.init_admin(&admin);
+
+    let mut tokens_list = [
+        Address::generate(&e),
+        Address::generate(&e),
+        Address::generate(&e),
+    ];
+    tokens_list.sort();
+    let [token1, token2, token3] = tokens_list;
+    let tokens_1_2 = Vec::from_array(&e, [token1.clone(), token2.clone()]);
+    let tokens_1_3 = Vec::from_array(&e, [token1.clone(), token3.clone()]);
+    let (pool_1, pool_2, pool_3) = (
+        Address::generate(&e),
+        Address::generate(&e),
+        Address::generate(&e),
+    );
+
+    // pools registered before the tokens sets index was introduced
+    e.as_contract(&router.address, || {
+        let mut pools = Map::new(&e);
+        for (index, pool) in [(1_u8, &pool_1), (2_u8, &pool_2)] {
+            pools.set(
+                BytesN::from_array(&e, &[index; 32]),
+                LiquidityPoolData {
+                    pool_type: LiquidityPoolType::ConstantProduct,
+                    address: pool.clone(),
+                },
+            );
+        }
+        put_pools(&e, &pool_salt(&e, tokens_1_2.clone()), &pools);
+        put_pools(
+            &e,
+            &pool_salt(&e, tokens_1_3.clone()),
+            &Map::from_array(
+                &e,
+                [(
+                    BytesN::from_array(&e, &[3; 32]),
+                    LiquidityPoolData {
+                        pool_type: LiquidityPoolType::StableSwap,
+                        address: pool_3.clone(),
+                    },
+                )],
+            ),
+        );
+    });
+    assert_eq!(router.get_pools_count(), 0);
+    assert_eq!(router.get_pools_for_token(&token1, &0, &10).len(), 0);
+
+    // legacy pool removed before backfill is neither counted nor subtracted
+    router.remove_pool(&admin, &tokens_1_3, &BytesN::from_array(&e, &[3; 32]));
+    assert_eq!(router.get_pools_count(), 0);
+
+    assert!(router
+        .try_index_tokens_sets(
+            &Address::generate(&e),
+            &Vec::from_array(&e, [tokens_1_2.clone()])
+        )
+        .is_err());
+    assert_eq!(
+        router.index_tokens_sets(
+            &admin,
+            &Vec::from_array(&e, [tokens_1_2.clone(), tokens_1_3.clone()])
+        ),
+        3
+    );
+    assert_eq!(router.get_pools_count(), 2);
+    assert_eq!(router.get_tokens_sets_count(), 2);
+    assert_eq!(router.get_token_tokens_sets_count(&token1), 2);
+    assert_eq!(
+        router
+            .get_pools_for_token(&token1, &0, &10)
+            .get(0)
+            .unwrap()
+            .1
+            .len(),
+        2
+    );
+
+    // backfill is idempotent
+    assert_eq!(
+        router.index_tokens_sets(&admin, &Vec::from_array(&e, [tokens_1_2.clone()])),
+        0
+    );
+    assert_eq!(router.get_pools_count(), 2);
+    assert_eq!(router.get_tokens_sets_count(), 2);
+
+    router.remove_pool(&admin, &tokens_1_2, &BytesN::from_array(&e, &[1; 32]));
+    assert_eq!(router.get_pools_count(), 1);
+    // tokens set without pools can't be indexed
+    assert!(router
+        .try_index_tokens_sets(
+            &admin,
+            &Vec::from_array(&e, [Vec::from_array(&e, [token2, token3])])
+        )
+        .is_err());
+}
+
+#[test]
+fn test_token_tokens_sets_pages() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let router = create_liqpool_router_contract(&e);
+    router.init_admin(&admin);
+    let token = Address::generate(&e);
+
+    // more tokens sets than fit a single index page
+    let mut sets = Vec::new(&e);
+    e.as_contract(&router.address, || {
+        for i in 0..60_u8 {
+            let other = Address::generate(&e);
+            let tokens = match token < other {
+                true => Vec::from_array(&e, [token.clone(), other]),
+                false => Vec::from_array(&e, [other, token.clone()]),
+            };
+            put_pools(
+                &e,
+                &pool_salt(&e, tokens.clone()),
+                &Map::from_array(
+                    &e,
+                    [(
+                        BytesN::from_array(&e, &[i; 32]),
+                        LiquidityPoolData {
+                            pool_type: LiquidityPoolType::ConstantProduct,
+                            address: Address::generate(&e),
+                        },
+                    )],
+                ),
+            );
+            sets.push_back(tokens);
+        }
+    });
+    for i in 0..3 {
+        router.index_tokens_sets(&admin, &sets.slice(i * 20..(i + 1) * 20));
+    }
+    assert_eq!(router.get_pools_count(), 60);
+    assert_eq!(router.get_token_tokens_sets_count(&token), 60);
+
+    let page = router.get_pools_for_token(&token, &45, &10);
+    assert_eq!(page.len(), 10);
+    for i in 0..10 {
+        assert_eq!(page.get(i).unwrap().0, sets.get(45 + i).unwrap());
+    }
+    assert_eq!(router.get_pools_for_token(&token, &55, &10).len(), 5);
+}
+
+#[test]
+fn test_config_events() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.budget().reset_unlimited();
+
+    let mut admin1 = Address::generate(&e);
+    let mut admin2 = Address::generate(&e);
+
+    let mut token1 = create_token_contract(&e, &admin1);
+    let mut token2 = create_token_contract(&e, &admin2);
+    if &token2.address < &token1.address {
+        std::mem::swap(&mut token1, &mut token2);
+        std::mem::swap(&mut admin1, &mut admin2);
+    }
+    let tokens = Vec::from_array(&e, [token1.address.clone(), token2.address.clone()]);
+
+    let reward_admin = Address::generate(&e);
+    let admin = Address::generate(&e);
+    let user1 = Address::generate(&e);
+    let payment_for_creation_address = Address::generate(&e);
+
+    let reward_token = create_token_contract(&e, &reward_admin);
+
+    let pool_hash = install_liq_pool_hash(&e);
+    let stableswap_pool_hash = install_stableswap_two_tokens_liq_pool_hash(&e);
+    let token_hash = install_token_wasm(&e);
+    let plane = create_plane_contract(&e);
+    let swap_router = create_swap_router_contract(&e);
+    let router = create_liqpool_router_contract(&e);
+    router.init_admin(&admin);
+
+    router.set_pool_hash(&pool_hash);
+    assert_eq!(
+        vec![&e, e.events().all().last().unwrap()],
+        vec![
+            &e,
+            (
+                router.address.clone(),
+                (Symbol::new(&e, "set_pool_hash"),).into_val(&e),
+                (pool_hash.clone(),).into_val(&e),
+            ),
+        ]
+    );
+
+    router.set_stableswap_pool_hash(&2, &stableswap_pool_hash);
+    assert_eq!(
+        vec![&e, e.events().all().last().unwrap()],
+        vec![
+            &e,
+            (
+                router.address.clone(),
+                (Symbol::new(&e, "set_stableswap_pool_hash"),).into_val(&e),
+                (2_u32, stableswap_pool_hash.clone()).into_val(&e),
+            ),
+        ]
+    );
+
+    router.set_token_hash(&token_hash);
+    assert_eq!(
+        vec![&e, e.events().all().last().unwrap()],
+        vec![
+            &e,
+            (
+                router.address.clone(),
+                (Symbol::new(&e, "set_token_hash"),).into_val(&e),
+                (token_hash.clone(),).into_val(&e),
+            ),
+        ]
+    );
+
+    router.set_reward_token(&reward_token.address);
+    assert_eq!(
+        vec![&e, e.events().all().last().unwrap()],
+        vec![
+            &e,
+            (
+                router.address.clone(),
+                (Symbol::new(&e, "set_reward_token"),).into_val(&e),
+                (reward_token.address.clone(),).into_val(&e),
+            ),
+        ]
+    );
+
+    router.configure_init_pool_payment(
+        &reward_token.address,
+        &1000_0000000,
+        &payment_for_creation_address,
+    );
+    assert_eq!(
+        vec![&e, e.events().all().last().unwrap()],
+        vec![
+            &e,
+            (
+                router.address.clone(),
+                (Symbol::new(&e, "configure_init_pool_payment"),).into_val(&e),
+                (
+                    reward_token.address.clone(),
+                    1000_0000000_u128,
+                    payment_for_creation_address.clone()
+                )
+                    .into_val(&e),
+            ),
+        ]
+    );
+
+    router.set_pools_plane(&admin, &plane.address);
+    assert_eq!(
+        vec![&e, e.events().all().last().unwrap()],
+        vec![
+            &e,
+            (
+                router.address.clone(),
+                (Symbol::new(&e, "set_pools_plane"),).into_val(&e),
+                (plane.address.clone(),).into_val(&e),
+            ),
+        ]
+    );
+
+    router.set_swap_router(&admin, &swap_router.address);
+    assert_eq!(
+        vec![&e, e.events().all().last().unwrap()],
+        vec![
+            &e,
+            (
+                router.address.clone(),
+                (Symbol::new(&e, "set_swap_router"),).into_val(&e),
+                (swap_router.address.clone(),).into_val(&e),
+            ),
+        ]
+    );
+
+    let (pool_hash, pool_address) = router.init_standard_pool(&user1, &tokens, &30);
+
+    reward_token.mint(&pool_address, &1_000_000_0000000);
+    let reward_1_tps = 10_5000000_u128;
+    let expired_at = e.ledger().timestamp().saturating_add(60);
+    router.set_rewards_config(&admin, &tokens, &pool_hash, &expired_at, &reward_1_tps);
+    assert_eq!(
+        vec![&e, e.events().all().last().unwrap()],
+        vec![
+            &e,
+            (
+                router.address.clone(),
+                (
+                    Symbol::new(&e, "set_rewards_config"),
+                    tokens.clone(),
+                    admin.clone()
+                )
+                    .into_val(&e),
+                (pool_address.clone(), expired_at, reward_1_tps).into_val(&e),
+            ),
+        ]
+    );
+
+    token1.mint(&user1, &1000);
+    token2.mint(&user1, &1000);
+    token1.approve(&user1, &pool_address, &1000, &99999);
+    token2.approve(&user1, &pool_address, &1000, &99999);
+    router.deposit(
+        &user1,
+        &tokens,
+        &pool_hash,
+        &Vec::from_array(&e, [100, 100]),
+    );
+    jump(&e, 30);
+
+    let reward = router.claim(&user1, &tokens, &pool_hash);
+    assert_eq!(reward, reward_1_tps * 30);
+    assert_eq!(
+        vec![&e, e.events().all().last().unwrap()],
+        vec![
+            &e,
+            (
+                router.address.clone(),
+                (Symbol::new(&e, "claim"), tokens.clone(), user1.clone()).into_val(&e),
+                (pool_address.clone(), reward).into_val(&e),
+            ),
+        ]
+    );
+
+    router.remove_pool(&admin, &tokens, &pool_hash);
+    assert_eq!(
+        vec![&e, e.events().all().last().unwrap()],
+        vec![
+            &e,
+            (
+                router.address.clone(),
+                (Symbol::new(&e, "remove_pool"), tokens.clone()).into_val(&e),
+                (pool_address.clone(), pool_hash.clone()).into_val(&e),
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_claim_all() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.budget().reset_unlimited();
+
+    let mut admin1 = Address::generate(&e);
+    let mut admin2 = Address::generate(&e);
+
+    let mut token1 = create_token_contract(&e, &admin1);
+    let mut token2 = create_token_contract(&e, &admin2);
+    if &token2.address < &token1.address {
+        std::mem::swap(&mut token1, &mut token2);
+        std::mem::swap(&mut admin1, &mut admin2);
+    }
+    let tokens = Vec::from_array(&e, [token1.address.clone(), token2.address.clone()]);
+
+    let reward_admin = Address::generate(&e);
+    let admin = Address::generate(&e);
+
+    let reward_token = create_token_contract(&e, &reward_admin);
+
+    let user1 = Address::generate(&e);
+
+    let pool_hash = install_liq_pool_hash(&e);
+    let token_hash = install_token_wasm(&e);
+    let plane = create_plane_contract(&e);
+    let router = create_liqpool_router_contract(&e);
+    router.init_admin(&admin);
+    router.set_pool_hash(&pool_hash);
+    router.set_token_hash(&token_hash);
+    router.set_reward_token(&reward_token.address);
+    router.set_pools_plane(&admin, &plane.address);
+
+    // pool paying rewards from it's own balance
+    let (standard_pool_hash, standard_pool_address) =
+        router.init_standard_pool(&user1, &tokens, &30);
+    reward_token.mint(&standard_pool_address, &1_000_000_0000000);
+
+    // pools using router as reward storage
+    let mut shared_pools = Vec::new(&e);
+    for fee_fraction in [10_u32, 100] {
+        let pool = create_standard_pool_contract(&e);
+        pool.initialize_all(
+            &admin,
+            &token_hash,
+            &tokens,
+            &fee_fraction,
+            &1_000_000_000_000,
+            &reward_token.address,
+            &router.address,
+            &plane.address,
+        );
+        // router should be trusted by the pool to collect rewards
+        pool.grant_role(&admin, &standard_pool::Role::RewardsAdmin, &router.address);
+        let pool_index = router.add_custom_pool(
+            &admin,
+            &tokens,
+            &pool.address,
+            &symbol_short!("custom"),
+            &Vec::<Val>::from_array(&e, [fee_fraction.into_val(&e)]),
+        );
+        shared_pools.push_back((pool_index, pool.address));
+    }
+    reward_token.mint(&router.address, &1_000_000_0000000);
+
+    let mut pools = Vec::from_array(&e, [(tokens.clone(), standard_pool_hash.clone())]);
+    for (pool_index, _) in shared_pools.iter() {
+        pools.push_back((tokens.clone(), pool_index));
+    }
+
+    let reward_1_tps = 10_5000000_u128;
+    let expired_at = e.ledger().timestamp().saturating_add(60);
+    token1.mint(&user1, &3000);
+    token2.mint(&user1, &3000);
+    for (tokens, pool_index) in pools.iter() {
+        let pool_address = router.get_pool(&tokens, &pool_index);
+        router.set_rewards_config(&admin, &tokens, &pool_index, &expired_at, &reward_1_tps);
+        token1.approve(&user1, &pool_address, &1000, &99999);
+        token2.approve(&user1, &pool_address, &1000, &99999);
+        router.deposit(
+            &user1,
+            &tokens,
+            &pool_index,
+            &Vec::from_array(&e, [100, 100]),
+        );
+    }
+
+    jump(&e, 30);
+    let expected_rewards = Vec::from_array(
+        &e,
+        [reward_1_tps * 30, reward_1_tps * 30, reward_1_tps * 30],
+    );
+    assert_eq!(
+        router.get_total_user_reward(&user1, &pools),
+        expected_rewards
+    );
+    assert_eq!(router.claim_all(&user1, &pools), expected_rewards);
+    assert_eq!(reward_token.balance(&user1) as u128, reward_1_tps * 30 * 3);
+    assert_eq!(
+        reward_token.balance(&standard_pool_address) as u128,
+        1_000_000_0000000 - reward_1_tps * 30
+    );
+    assert_eq!(
+        reward_token.balance(&router.address) as u128,
+        1_000_000_0000000 - reward_1_tps * 30 * 2
+    );
+
+    // nothing left to claim
+    assert_eq!(
+        router.claim_all(&user1, &pools),
+        Vec::from_array(&e, [0, 0, 0])
+    );
+    assert_eq!(reward_token.balance(&user1) as u128, reward_1_tps * 30 * 3);
+
+    let mut too_many_pools = Vec::new(&e);
+    for _ in 0..11 {
+        too_many_pools.push_back((tokens.clone(), standard_pool_hash.clone()));
+    }
+    assert!(router.try_claim_all(&user1, &too_many_pools).is_err());
+}
+
+#[test]
+fn test_claim_all_router_deployed_pools() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.budget().reset_unlimited();
+
+    let mut admin1 = Address::generate(&e);
+    let mut admin2 = Address::generate(&e);
+
+    let mut token1 = create_token_contract(&e, &admin1);
+    let mut token2 = create_token_contract(&e, &admin2);
+    if token2.address < token1.address {
+        std::mem::swap(&mut token1, &mut token2);
+        std::mem::swap(&mut admin1, &mut admin2);
+    }
+    let tokens = Vec::from_array(&e, [token1.address.clone(), token2.address.clone()]);
+
+    let reward_admin = Address::generate(&e);
+    let admin = Address::generate(&e);
+
+    let reward_token = create_token_contract(&e, &reward_admin);
+
+    let user1 = Address::generate(&e);
+
+    let pool_hash = install_liq_pool_hash(&e);
+    let token_hash = install_token_wasm(&e);
+    let plane = create_plane_contract(&e);
+    let router = create_liqpool_router_contract(&e);
+    router.init_admin(&admin);
+    router.set_pool_hash(&pool_hash);
+    router.set_token_hash(&token_hash);
+    router.set_reward_token(&reward_token.address);
+    router.set_pools_plane(&admin, &plane.address);
+
+    // router deployed pools are their own reward storage
+    let reward_1_tps = 10_5000000_u128;
+    let expired_at = e.ledger().timestamp().saturating_add(60);
+    token1.mint(&user1, &3000);
+    token2.mint(&user1, &3000);
+    let mut pools = Vec::new(&e);
+    let mut pool_addresses = Vec::new(&e);
+    for fee_fraction in [10_u32, 30, 100] {
+        let (pool_index, pool_address) = router.init_standard_pool(&user1, &tokens, &fee_fraction);
+        reward_token.mint(&pool_address, &1_000_000_0000000);
+        router.set_rewards_config(&admin, &tokens, &pool_index, &expired_at, &reward_1_tps);
+        token1.approve(&user1, &pool_address, &1000, &99999);
+        token2.approve(&user1, &pool_address, &1000, &99999);
+        router.deposit(
+            &user1,
+            &tokens,
+            &pool_index,
+            &Vec::from_array(&e, [100, 100]),
+        );
+        pools.push_back((tokens.clone(), pool_index));
+        pool_addresses.push_back(pool_address);
+    }
+
+    jump(&e, 30);
+    let expected_rewards = Vec::from_array(
+        &e,
+        [reward_1_tps * 30, reward_1_tps * 30, reward_1_tps * 30],
+    );
+    assert_eq!(router.claim_all(&user1, &pools), expected_rewards);
+    assert_eq!(reward_token.balance(&user1) as u128, reward_1_tps * 30 * 3);
+    assert_eq!(reward_token.balance(&router.address), 0);
+
+    // nothing is aggregated, every pool pays out its own reward
+    let mut transfers = 0;
+    for event in e.events().all().iter() {
+        if event.0 == reward_token.address
+            && event.1.get(0).map(|topic| Symbol::from_val(&e, &topic))
+                == Some(symbol_short!("transfer"))
+        {
+            transfers += 1;
+        }
+    }
+    assert_eq!(transfers, 3);
+    for pool_address in pool_addresses.iter() {
+        assert_eq!(
+            reward_token.balance(&pool_address) as u128,
+            1_000_000_0000000 - reward_1_tps * 30
+        );
+    }
+}
+
+#[test]
+fn test_claim_to_and_operator() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.budget().reset_unlimited();
+
+    let mut admin1 = Address::generate(&e);
+    let mut admin2 = Address::generate(&e);
+
+    let mut token1 = create_token_contract(&e, &admin1);
+    let mut token2 = create_token_contract(&e, &admin2);
+    if &token2.address < &token1.address {
+        std::mem::swap(&mut token1, &mut token2);
+        std::mem::swap(&mut admin1, &mut admin2);
+    }
+    let tokens = Vec::from_array(&e, [token1.address.clone(), token2.address.clone()]);
+
+    let reward_admin = Address::generate(&e);
+    let admin = Address::generate(&e);
+
+    let reward_token = create_token_contract(&e, &reward_admin);
+
+    let user1 = Address::generate(&e);
+    let operator = Address::generate(&e);
+    let treasury = Address::generate(&e);
+
+    let pool_hash = install_liq_pool_hash(&e);
+    let token_hash = install_token_wasm(&e);
+    let plane = create_plane_contract(&e);
+    let router = create_liqpool_router_contract(&e);
+    router.init_admin(&admin);
+    router.set_pool_hash(&pool_hash);
+    router.set_token_hash(&token_hash);
+    router.set_reward_token(&reward_token.address);
+    router.set_pools_plane(&admin, &plane.address);
+
+    // pool paying rewards from it's own balance
+    let (standard_pool_hash, standard_pool_address) =
+        router.init_standard_pool(&user1, &tokens, &30);
+    reward_token.mint(&standard_pool_address, &1_000_000_0000000);
+
+    // pool using router as reward storage
+    let shared_pool = create_standard_pool_contract(&e);
+    shared_pool.initialize_all(
+        &admin,
+        &token_hash,
+        &tokens,
+        &10,
+        &1_000_000_000_000,
+        &reward_token.address,
+        &router.address,
+        &plane.address,
+    );
+    shared_pool.grant_role(&admin, &standard_pool::Role::RewardsAdmin, &router.address);
+    let shared_pool_hash = router.add_custom_pool(
+        &admin,
+        &tokens,
+        &shared_pool.address,
+        &symbol_short!("custom"),
+        &Vec::<Val>::from_array(&e, [10_u32.into_val(&e)]),
+    );
+    reward_token.mint(&router.address, &1_000_000_0000000);
+
+    let reward_1_tps = 10_5000000_u128;
+    let expired_at = e.ledger().timestamp().saturating_add(60);
+    token1.mint(&user1, &2000);
+    token2.mint(&user1, &2000);
+    for pool_index in [standard_pool_hash.clone(), shared_pool_hash.clone()] {
+        let pool_address = router.get_pool(&tokens, &pool_index);
+        router.set_rewards_config(&admin, &tokens, &pool_index, &expired_at, &reward_1_tps);
+        token1.approve(&user1, &pool_address, &1000, &99999);
+        token2.approve(&user1, &pool_address, &1000, &99999);
+        router.deposit(
+            &user1,
+            &tokens,
+            &pool_index,
+            &Vec::from_array(&e, [100, 100]),
+        );
+    }
+
+    jump(&e, 30);
+    assert_eq!(
+        router.claim_to(&user1, &tokens, &standard_pool_hash, &treasury),
+        reward_1_tps * 30
+    );
+    assert_eq!(
+        vec![&e, e.events().all().last().unwrap()],
+        vec![
+            &e,
+            (
+                router.address.clone(),
+                (Symbol::new(&e, "claim_to"), tokens.clone(), user1.clone()).into_val(&e),
+                (
+                    standard_pool_address.clone(),
+                    treasury.clone(),
+                    reward_1_tps * 30
+                )
+                    .into_val(&e),
+            ),
+        ]
+    );
+    assert_eq!(reward_token.balance(&treasury) as u128, reward_1_tps * 30);
+    assert_eq!(reward_token.balance(&user1), 0);
+
+    // operator is not approved yet
+    assert!(!router.is_claim_operator(&user1, &operator));
+    assert!(router
+        .try_claim_as_operator(&operator, &user1, &tokens, &shared_pool_hash, &treasury)
+        .is_err());
+
+    router.set_claim_operator(&user1, &operator, &true);
+    assert!(router.is_claim_operator(&user1, &operator));
+
+    // reward of pool using router as reward storage is paid out by router
+    assert_eq!(
+        router.claim_as_operator(&operator, &user1, &tokens, &shared_pool_hash, &treasury),
+        reward_1_tps * 30
+    );
+    assert_eq!(
+        reward_token.balance(&treasury) as u128,
+        reward_1_tps * 30 * 2
+    );
+    assert_eq!(
+        reward_token.balance(&router.address) as u128,
+        1_000_000_0000000 - reward_1_tps * 30
+    );
+
+    // pool paying rewards itself trusts router approval
+    jump(&e, 10);
+    let standard_pool = standard_pool::Client::new(&e, &standard_pool_address);
+    assert!(!standard_pool.is_claim_operator(&user1, &operator));
+    assert_eq!(
+        router.claim_as_operator(&operator, &user1, &tokens, &standard_pool_hash, &treasury),
+        reward_1_tps * 10
+    );
+    assert_eq!(reward_token.balance(&treasury) as u128, reward_1_tps * 70);
+    assert_eq!(reward_token.balance(&user1), 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+fn test_collect_reward_not_storage() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.budget().reset_unlimited();
+
+    let mut admin1 = Address::generate(&e);
+    let mut admin2 = Address::generate(&e);
+
+    let mut token1 = create_token_contract(&e, &admin1);
+    let mut token2 = create_token_contract(&e, &admin2);
+    if &token2.address < &token1.address {
+        std::mem::swap(&mut token1, &mut token2);
+        std::mem::swap(&mut admin1, &mut admin2);
+    }
+    let tokens = Vec::from_array(&e, [token1.address.clone(), token2.address.clone()]);
+
+    let admin = Address::generate(&e);
+    let reward_token = create_token_contract(&e, &admin);
+    let router = Address::generate(&e);
+    let user1 = Address::generate(&e);
+
+    let token_hash = install_token_wasm(&e);
+    let plane = create_plane_contract(&e);
+    let pool = create_standard_pool_contract(&e);
+    pool.initialize_all(
+        &admin,
+        &token_hash,
+        &tokens,
+        &30,
+        &1_000_000_000_000,
+        &reward_token.address,
+        &router,
+        &plane.address,
+    );
+
+    // only reward storage is allowed to collect reward
+    e.set_auths(&[]);
+    pool.collect_reward(&user1);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #103)")]
+fn test_collect_reward_untrusted_storage() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.budget().reset_unlimited();
+
+    let mut admin1 = Address::generate(&e);
+    let mut admin2 = Address::generate(&e);
+
+    let mut token1 = create_token_contract(&e, &admin1);
+    let mut token2 = create_token_contract(&e, &admin2);
+    if token2.address < token1.address {
+        std::mem::swap(&mut token1, &mut token2);
+        std::mem::swap(&mut admin1, &mut admin2);
+    }
+    let tokens = Vec::from_array(&e, [token1.address.clone(), token2.address.clone()]);
+
+    let admin = Address::generate(&e);
+    let reward_token = create_token_contract(&e, &admin);
+    let reward_storage = Address::generate(&e);
+    let user1 = Address::generate(&e);
+
+    let token_hash = install_token_wasm(&e);
+    let plane = create_plane_contract(&e);
+    let pool = create_standard_pool_contract(&e);
+    pool.initialize_all(
+        &admin,
+        &token_hash,
+        &tokens,
+        &30,
+        &1_000_000_000_000,
+        &reward_token.address,
+        &reward_storage,
+        &plane.address,
+    );
+
+    // reward storage without rewards admin role can't mark rewards as claimed
+    pool.collect_reward(&user1);
+}
+
+#[test]
+fn test_pool_creation_policy() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.budget().reset_unlimited();
+
+    let mut admin1 = Address::generate(&e);
+    let mut admin2 = Address::generate(&e);
+
+    let mut token1 = create_token_contract(&e, &admin1);
+    let mut token2 = create_token_contract(&e, &admin2);
+    if &token2.address < &token1.address {
+        std::mem::swap(&mut token1, &mut token2);
+        std::mem::swap(&mut admin1, &mut admin2);
+    }
+    let tokens = Vec::from_array(&e, [token1.address.clone(), token2.address.clone()]);
+
+    let reward_admin = Address::generate(&e);
+    let admin = Address::generate(&e);
+
+    let reward_token = create_token_contract(&e, &reward_admin);
+
+    let user1 = Address::generate(&e);
+
+    let pool_hash = install_liq_pool_hash(&e);
+    let stableswap_pool_hash = install_stableswap_two_tokens_liq_pool_hash(&e);
+    let token_hash = install_token_wasm(&e);
+    let plane = create_plane_contract(&e);
+    let router = create_liqpool_router_contract(&e);
+    router.init_admin(&admin);
+    router.set_pool_hash(&pool_hash);
+    router.set_stableswap_pool_hash(&2, &stableswap_pool_hash);
+    router.set_token_hash(&token_hash);
+    router.set_reward_token(&reward_token.address);
+    router.set_pools_plane(&admin, &plane.address);
+    let payment_for_creation_address = Address::generate(&e);
+
+    // default policy keeps previous behavior
+    let default_policy = router.get_pool_creation_policy(&LiquidityPoolType::ConstantProduct);
+    assert!(default_policy.permissionless);
+    assert_eq!(default_policy.payments, Vec::new(&e));
+    assert_eq!(
+        default_policy.fee_fractions,
+        Vec::from_array(&e, CONSTANT_PRODUCT_DEFAULT_FEE_FRACTIONS)
+    );
+
+    // custom fee tiers with creation payment for constant product pools
+    let policy = PoolCreationPolicy {
+        permissionless: true,
+        payments: Vec::from_array(
+            &e,
+            [PoolCreationPayment {
+                token: reward_token.address.clone(),
+                amount: 100_0000000,
+                to: payment_for_creation_address.clone(),
+            }],
+        ),
+        fee_fractions: Vec::from_array(&e, [5, 50]),
+        min_fee_fraction: 0,
+        max_fee_fraction: 100,
+        min_a: 0,
+        max_a: 0,
+        max_admin_fee: 0,
+        reward_precision: 1_000_000_000_000,
+    };
+    router.set_pool_creation_policy(&LiquidityPoolType::ConstantProduct, &policy);
+    assert_eq!(
+        vec![&e, e.events().all().last().unwrap()],
+        vec![
+            &e,
+            (
+                router.address.clone(),
+                (
+                    Symbol::new(&e, "set_pool_creation_policy"),
+                    LiquidityPoolType::ConstantProduct,
+                )
+                    .into_val(&e),
+                (policy.clone(),).into_val(&e),
+            ),
+        ]
+    );
+    assert_eq!(
+        router.get_pool_creation_policy(&LiquidityPoolType::ConstantProduct),
+        policy
+    );
+
+    reward_token.mint(&user1, &1000_0000000);
+    reward_token.approve(&user1, &router.address, &1000_0000000, &99999);
+    let (pool_index, pool_address) = router.init_standard_pool(&user1, &tokens, &5);
+    assert_eq!(
+        reward_token.balance(&payment_for_creation_address),
+        100_0000000
+    );
+
+    // existing pool is returned without payment
+    assert_eq!(
+        router.init_standard_pool(&user1, &tokens, &5),
+        (pool_index.clone(), pool_address)
+    );
+    assert_eq!(
+        reward_token.balance(&payment_for_creation_address),
+        100_0000000
+    );
+
+    // stableswap parameters are checked against bounds
+    let stableswap_policy = PoolCreationPolicy {
+        permissionless: true,
+        payments: Vec::new(&e),
+        fee_fractions: Vec::new(&e),
+        min_fee_fraction: 1,
+        max_fee_fraction: 100,
+        min_a: 10,
+        max_a: 200,
+        max_admin_fee: 5000,
+        reward_precision: 1_000_000_000_000_000,
+    };
+    router.set_pool_creation_policy(&LiquidityPoolType::StableSwap, &stableswap_policy);
+    let (stableswap_index, _) = router.init_stableswap_pool(&user1, &tokens, &85, &6, &5000);
+    assert_eq!(router.get_pools(&tokens).len(), 2);
+
+    // pools are created with reward precision of the policy
+    assert_eq!(
+        router
+            .get_rewards_info(&user1, &tokens, &pool_index)
+            .precision,
+        1_000_000_000_000
+    );
+    assert_eq!(
+        router
+            .get_rewards_info(&user1, &tokens, &stableswap_index)
+            .precision,
+        1_000_000_000_000_000
+    );
+    assert!(router
+        .try_set_pool_creation_policy(
+            &LiquidityPoolType::StableSwap,
+            &PoolCreationPolicy {
+                reward_precision: 1_000_000_000_000_000_000_000,
+                ..stableswap_policy
+            },
+        )
+        .is_err());
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #103)")]
+fn test_pool_creation_policy_restricted() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.budget().reset_unlimited();
+
+    let mut admin1 = Address::generate(&e);
+    let mut admin2 = Address::generate(&e);
+
+    let mut token1 = create_token_contract(&e, &admin1);
+    let mut token2 = create_token_contract(&e, &admin2);
+    if &token2.address < &token1.address {
+        std::mem::swap(&mut token1, &mut token2);
+        std::mem::swap(&mut admin1, &mut admin2);
+    }
+    let tokens = Vec::from_array(&e, [token1.address.clone(), token2.address.clone()]);
+
+    let reward_admin = Address::generate(&e);
+    let admin = Address::generate(&e);
+
+    let reward_token = create_token_contract(&e, &reward_admin);
+
+    let user1 = Address::generate(&e);
+
+    let pool_hash = install_liq_pool_hash(&e);
+    let stableswap_pool_hash = install_stableswap_two_tokens_liq_pool_hash(&e);
+    let token_hash = install_token_wasm(&e);
+    let plane = create_plane_contract(&e);
+    let router = create_liqpool_router_contract(&e);
+    router.init_admin(&admin);
+    router.set_pool_hash(&pool_hash);
+    router.set_stableswap_pool_hash(&2, &stableswap_pool_hash);
+    router.set_token_hash(&token_hash);
+    router.set_reward_token(&reward_token.address);
+    router.set_pools_plane(&admin, &plane.address);
+
+    let mut policy = router.get_pool_creation_policy(&LiquidityPoolType::ConstantProduct);
+    policy.permissionless = false;
+    router.set_pool_creation_policy(&LiquidityPoolType::ConstantProduct, &policy);
+
+    assert!(router.try_init_pool(&tokens).is_err());
+    router.init_standard_pool(&admin, &tokens, &30);
+    router.init_standard_pool(&user1, &tokens, &10);
+}
+
+#[test]
+#[should_panic(expected = "a is out of allowed range")]
+fn test_pool_creation_policy_a_out_of_range() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.budget().reset_unlimited();
+
+    let mut admin1 = Address::generate(&e);
+    let mut admin2 = Address::generate(&e);
+
+    let mut token1 = create_token_contract(&e, &admin1);
+    let mut token2 = create_token_contract(&e, &admin2);
+    if &token2.address < &token1.address {
+        std::mem::swap(&mut token1, &mut token2);
+        std::mem::swap(&mut admin1, &mut admin2);
+    }
+    let tokens = Vec::from_array(&e, [token1.address.clone(), token2.address.clone()]);
+
+    let reward_admin = Address::generate(&e);
+    let admin = Address::generate(&e);
+
+    let reward_token = create_token_contract(&e, &reward_admin);
+
+    let user1 = Address::generate(&e);
+
+    let pool_hash = install_liq_pool_hash(&e);
+    let stableswap_pool_hash = install_stableswap_two_tokens_liq_pool_hash(&e);
+    let token_hash = install_token_wasm(&e);
+    let plane = create_plane_contract(&e);
+    let router = create_liqpool_router_contract(&e);
+    router.init_admin(&admin);
+    router.set_pool_hash(&pool_hash);
+    router.set_stableswap_pool_hash(&2, &stableswap_pool_hash);
+    router.set_token_hash(&token_hash);
+    router.set_reward_token(&reward_token.address);
+    router.set_pools_plane(&admin, &plane.address);
+
+    let mut policy = router.get_pool_creation_policy(&LiquidityPoolType::StableSwap);
+    policy.max_a = 100;
+    router.set_pool_creation_policy(&LiquidityPoolType::StableSwap, &policy);
+
+    router.init_stableswap_pool(&user1, &tokens, &101, &6, &0);
+}
+
+#[test]
+#[should_panic(expected = "fee is out of allowed range")]
+fn test_pool_creation_policy_fee_out_of_range() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.budget().reset_unlimited();
+
+    let mut admin1 = Address::generate(&e);
+    let mut admin2 = Address::generate(&e);
+
+    let mut token1 = create_token_contract(&e, &admin1);
+    let mut token2 = create_token_contract(&e, &admin2);
+    if &token2.address < &token1.address {
+        std::mem::swap(&mut token1, &mut token2);
+        std::mem::swap(&mut admin1, &mut admin2);
+    }
+    let tokens = Vec::from_array(&e, [token1.address.clone(), token2.address.clone()]);
+
+    let reward_admin = Address::generate(&e);
+    let admin = Address::generate(&e);
+
+    let reward_token = create_token_contract(&e, &reward_admin);
+
+    let user1 = Address::generate(&e);
+
+    let pool_hash = install_liq_pool_hash(&e);
+    let stableswap_pool_hash = install_stableswap_two_tokens_liq_pool_hash(&e);
+    let token_hash = install_token_wasm(&e);
+    let plane = create_plane_contract(&e);
+    let router = create_liqpool_router_contract(&e);
+    router.init_admin(&admin);
+    router.set_pool_hash(&pool_hash);
+    router.set_stableswap_pool_hash(&2, &stableswap_pool_hash);
+    router.set_token_hash(&token_hash);
+    router.set_reward_token(&reward_token.address);
+    router.set_pools_plane(&admin, &plane.address);
+
+    router.init_stableswap_pool(&user1, &tokens, &85, &5001, &0);
+}
+
+#[test]
+fn test_upgrade_pools() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.budget().reset_unlimited();
+
+    let mut admin1 = Address::generate(&e);
+    let mut admin2 = Address::generate(&e);
+
+    let mut token1 = create_token_contract(&e, &admin1);
+    let mut token2 = create_token_contract(&e, &admin2);
+    if &token2.address < &token1.address {
+        std::mem::swap(&mut token1, &mut token2);
+        std::mem::swap(&mut admin1, &mut admin2);
+    }
+    let tokens = Vec::from_array(&e, [token1.address.clone(), token2.address.clone()]);
+
+    let reward_admin = Address::generate(&e);
+    let admin = Address::generate(&e);
+
+    let reward_token = create_token_contract(&e, &reward_admin);
+
+    let user1 = Address::generate(&e);
+
+    let pool_hash = install_liq_pool_hash(&e);
+    let token_hash = install_token_wasm(&e);
+    let plane = create_plane_contract(&e);
+    let router = create_liqpool_router_contract(&e);
+    router.init_admin(&admin);
+    router.set_pool_hash(&pool_hash);
+    router.set_token_hash(&token_hash);
+    router.set_reward_token(&reward_token.address);
+    router.set_pools_plane(&admin, &plane.address);
+
+    let (pool_index1, pool_address1) = router.init_standard_pool(&user1, &tokens, &10);
+    let (pool_index2, pool_address2) = router.init_standard_pool(&user1, &tokens, &30);
+    assert_eq!(router.get_pool_version(&tokens, &pool_index1), 100);
+    // removed pool is not upgraded
+    let (pool_index3, _) = router.init_standard_pool(&user1, &tokens, &100);
+    router.set_pool_state(&admin, &tokens, &pool_index3, &PoolState::Removed);
+
+    // pool without `upgrade` export registered before upgrades were supported
+    let legacy_tokens = Vec::from_array(&e, [token1.address.clone(), reward_token.address.clone()]);
+    let legacy_tokens = match token1.address < reward_token.address {
+        true => legacy_tokens,
+        false => Vec::from_array(&e, [reward_token.address.clone(), token1.address.clone()]),
+    };
+    e.as_contract(&router.address, || {
+        put_pools(
+            &e,
+            &pool_salt(&e, legacy_tokens.clone()),
+            &Map::from_array(
+                &e,
+                [(
+                    BytesN::from_array(&e, &[0; 32]),
+                    LiquidityPoolData {
+                        pool_type: LiquidityPoolType::ConstantProduct,
+                        address: plane.address.clone(),
+                    },
+                )],
+            ),
+        );
+    });
+    router.index_tokens_sets(&admin, &Vec::from_array(&e, [legacy_tokens.clone()]));
+
+    // only pools of requested type and tokens count are upgraded
+    assert_eq!(
+        router.upgrade_pools(&LiquidityPoolType::StableSwap, &2, &0, &10, &pool_hash),
+        Vec::new(&e)
+    );
+    assert_eq!(
+        router.upgrade_pools(&LiquidityPoolType::ConstantProduct, &3, &0, &10, &pool_hash),
+        Vec::new(&e)
+    );
+    // second tokens set holds legacy pool only, which fails to upgrade
+    assert_eq!(
+        router.upgrade_pools(&LiquidityPoolType::ConstantProduct, &2, &1, &10, &pool_hash),
+        Vec::new(&e)
+    );
+    assert_eq!(
+        vec![&e, e.events().all().last().unwrap()],
+        vec![
+            &e,
+            (
+                router.address.clone(),
+                (
+                    Symbol::new(&e, "upgrade_pool_failed"),
+                    legacy_tokens.clone()
+                )
+                    .into_val(&e),
+                (plane.address.clone(), pool_hash.clone()).into_val(&e),
+            ),
+        ]
+    );
+    let upgraded =
+        router.upgrade_pools(&LiquidityPoolType::ConstantProduct, &2, &0, &10, &pool_hash);
+    assert_eq!(upgraded.len(), 2);
+    assert!(upgraded.contains((pool_address1, 100)));
+    assert!(upgraded.contains((pool_address2, 100)));
+    assert_eq!(router.get_pool_version(&tokens, &pool_index1), 100);
+    assert_eq!(router.get_pool_version(&tokens, &pool_index2), 100);
+
+    let events = e.events().all();
+    let mut upgrade_events = Vec::new(&e);
+    for event in events.iter() {
+        if event.1.get(0).map(|topic| Symbol::from_val(&e, &topic))
+            == Some(Symbol::new(&e, "upgrade_pool"))
+        {
+            upgrade_events.push_back(event);
+        }
+    }
+    assert_eq!(upgrade_events.len(), 2);
+    assert_eq!(
+        vec![&e, upgrade_events.get(0).unwrap()],
+        vec![
+            &e,
+            (
+                router.address.clone(),
+                (Symbol::new(&e, "upgrade_pool"), tokens.clone()).into_val(&e),
+                (upgraded.get(0).unwrap().0, pool_hash.clone(), 100_u32).into_val(&e),
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_pools_with_info() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.budget().reset_unlimited();
+
+    let mut admin1 = Address::generate(&e);
+    let mut admin2 = Address::generate(&e);
+
+    let mut token1 = create_token_contract(&e, &admin1);
+    let mut token2 = create_token_contract(&e, &admin2);
+    if &token2.address < &token1.address {
+        std::mem::swap(&mut token1, &mut token2);
+        std::mem::swap(&mut admin1, &mut admin2);
+    }
+    let tokens = Vec::from_array(&e, [token1.address.clone(), token2.address.clone()]);
+
+    let reward_admin = Address::generate(&e);
+    let admin = Address::generate(&e);
+
+    let reward_token = create_token_contract(&e, &reward_admin);
+
+    let user1 = Address::generate(&e);
+
+    let pool_hash = install_liq_pool_hash(&e);
+    let stableswap_pool_hash = install_stableswap_two_tokens_liq_pool_hash(&e);
+    let token_hash = install_token_wasm(&e);
+    let plane = create_plane_contract(&e);
+    let router = create_liqpool_router_contract(&e);
+    router.init_admin(&admin);
+    router.set_pool_hash(&pool_hash);
+    router.set_stableswap_pool_hash(&2, &stableswap_pool_hash);
+    router.set_token_hash(&token_hash);
+    router.set_reward_token(&reward_token.address);
+    router.set_pools_plane(&admin, &plane.address);
+    router.configure_init_pool_payment(&reward_token.address, &0, &admin);
+    reward_token.approve(&user1, &router.address, &0, &99999);
+
+    jump(&e, 100);
+    let created_at = e.ledger().timestamp();
+    let (standard_index, standard_address) = router.init_standard_pool(&user1, &tokens, &30);
+    let (stable_index, stable_address) =
+        router.init_stableswap_pool(&user1, &tokens, &85, &6, &5000);
+    let custom_address = Address::generate(&e);
+    let custom_args = Vec::from_array(&e, [symbol_short!("arg").into_val(&e)]);
+    let custom_index = router.add_custom_pool(
+        &admin,
+        &tokens,
+        &custom_address,
+        &symbol_short!("custom"),
+        &custom_args,
+    );
+
+    let pools = router.get_pools_with_info(&tokens);
+    assert_eq!(pools.len(), 3);
+    let standard_info = pools.get(standard_index.clone()).unwrap();
+    assert_eq!(standard_info.pool_type, LiquidityPoolType::ConstantProduct);
+    assert_eq!(standard_info.pool_type_name, symbol_short!("constant"));
+    assert_eq!(standard_info.address, standard_address);
+    assert_eq!(standard_info.fee_fraction, 30);
+    assert_eq!(standard_info.created_at, created_at);
+    let stable_info = pools.get(stable_index.clone()).unwrap();
+    assert_eq!(stable_info.pool_type, LiquidityPoolType::StableSwap);
+    assert_eq!(stable_info.address, stable_address);
+    assert_eq!(
+        (
+            stable_info.fee_fraction,
+            stable_info.a,
+            stable_info.admin_fee
+        ),
+        (6, 85, 5000)
+    );
+    let custom_info = pools.get(custom_index.clone()).unwrap();
+    assert_eq!(custom_info.pool_type, LiquidityPoolType::Custom);
+    assert_eq!(custom_info.address, custom_address);
+    assert_eq!(custom_info.init_args, custom_args);
+
+    assert_eq!(
+        router.find_pool(
+            &tokens,
+            &symbol_short!("constant"),
+            &Vec::from_array(&e, [30_u32.into_val(&e)]),
+        ),
+        standard_info
+    );
+    assert_eq!(
+        router.find_pool(
+            &tokens,
+            &symbol_short!("stable"),
+            &Vec::from_array(
+                &e,
+                [
+                    6_u32.into_val(&e),
+                    85_u128.into_val(&e),
+                    5000_u32.into_val(&e)
+                ]
+            ),
+        ),
+        stable_info
+    );
+    assert_eq!(
+        router.find_pool(&tokens, &symbol_short!("custom"), &custom_args),
+        custom_info
+    );
+
+    router.remove_pool(&admin, &tokens, &custom_index);
+    assert_eq!(router.get_pools_with_info(&tokens).len(), 2);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #404)")]
+fn test_find_pool_not_found() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.budget().reset_unlimited();
+
+    let mut admin1 = Address::generate(&e);
+    let mut admin2 = Address::generate(&e);
+
+    let mut token1 = create_token_contract(&e, &admin1);
+    let mut token2 = create_token_contract(&e, &admin2);
+    if &token2.address < &token1.address {
+        std::mem::swap(&mut token1, &mut token2);
+        std::mem::swap(&mut admin1, &mut admin2);
+    }
+    let tokens = Vec::from_array(&e, [token1.address.clone(), token2.address.clone()]);
+
+    let reward_admin = Address::generate(&e);
+    let admin = Address::generate(&e);
+
+    let reward_token = create_token_contract(&e, &reward_admin);
+
+    let user1 = Address::generate(&e);
+
+    let pool_hash = install_liq_pool_hash(&e);
+    let token_hash = install_token_wasm(&e);
+    let plane = create_plane_contract(&e);
+    let router = create_liqpool_router_contract(&e);
+    router.init_admin(&admin);
+    router.set_pool_hash(&pool_hash);
+    router.set_token_hash(&token_hash);
+    router.set_reward_token(&reward_token.address);
+    router.set_pools_plane(&admin, &plane.address);
+
+    router.init_standard_pool(&user1, &tokens, &30);
+    router.find_pool(
+        &tokens,
+        &symbol_short!("constant"),
+        &Vec::from_array(&e, [10_u32.into_val(&e)]),
+    );
+}
+
+#[test]
+fn test_pool_lifecycle() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.budget().reset_unlimited();
+
+    let mut admin1 = Address::generate(&e);
+    let mut admin2 = Address::generate(&e);
+
+    let mut token1 = create_token_contract(&e, &admin1);
+    let mut token2 = create_token_contract(&e, &admin2);
+    if &token2.address < &token1.address {
+        std::mem::swap(&mut token1, &mut token2);
+        std::mem::swap(&mut admin1, &mut admin2);
+    }
+    let tokens = Vec::from_array(&e, [token1.address.clone(), token2.address.clone()]);
+
+    let reward_admin = Address::generate(&e);
+    let admin = Address::generate(&e);
+
+    let reward_token = create_token_contract(&e, &reward_admin);
+
+    let user1 = Address::generate(&e);
+
+    let pool_hash = install_liq_pool_hash(&e);
+    let token_hash = install_token_wasm(&e);
+    let router = create_liqpool_router_contract(&e);
+    let plane = create_plane_contract(&e);
+    let swap_router = create_swap_router_contract(&e);
+    swap_router.init_admin(&admin);
+    swap_router.set_pools_plane(&admin, &plane.address);
+    router.init_admin(&admin);
+    router.set_pool_hash(&pool_hash);
+    router.set_token_hash(&token_hash);
+    router.set_reward_token(&reward_token.address);
+    router.set_pools_plane(&admin, &plane.address);
+    router.set_swap_router(&admin, &swap_router.address);
+
+    token1.mint(&user1, &100000_0000000);
+    token2.mint(&user1, &100000_0000000);
+
+    let (standard1_pool_hash, standard1_pool_address) =
+        router.init_standard_pool(&user1, &tokens, &10);
+    token1.approve(&user1, &standard1_pool_address, &1000_0000000, &99999);
+    token2.approve(&user1, &standard1_pool_address, &1000_0000000, &99999);
+    router.deposit(
+        &user1,
+        &tokens,
+        &standard1_pool_hash,
+        &Vec::from_array(&e, [1000_0000000_u128, 1000_0000000_u128]),
+    );
+
+    let (standard2_pool_hash, standard2_pool_address) =
+        router.init_standard_pool(&user1, &tokens, &30);
+    token1.approve(&user1, &standard2_pool_address, &1000_0000000, &99999);
+    token2.approve(&user1, &standard2_pool_address, &1000_0000000, &99999);
+    router.deposit(
+        &user1,
+        &tokens,
+        &standard2_pool_hash,
+        &Vec::from_array(&e, [1000_0000000_u128, 1000_0000000_u128]),
+    );
+
+    assert_eq!(
+        router.get_pool_state(&tokens, &standard1_pool_hash),
+        PoolState::Active
+    );
+    assert_eq!(
+        router
+            .estimate_swap_routed(&tokens, &token1.address, &token2.address, &9_0000000)
+            .0,
+        standard1_pool_hash
+    );
+
+    // deprecated pool is skipped by routing
+    router.set_pool_state(
+        &admin,
+        &tokens,
+        &standard1_pool_hash,
+        &PoolState::Deprecated,
+    );
+    assert_eq!(
+        vec![&e, e.events().all().last().unwrap()],
+        vec![
+            &e,
+            (
+                router.address.clone(),
+                (Symbol::new(&e, "set_pool_state"), tokens.clone()).into_val(&e),
+                (
+                    standard1_pool_address.clone(),
+                    standard1_pool_hash.clone(),
+                    PoolState::Deprecated,
+                )
+                    .into_val(&e),
+            ),
+        ]
+    );
+    assert_eq!(
+        router.get_pool_state(&tokens, &standard1_pool_hash),
+        PoolState::Deprecated
+    );
+    assert_eq!(
+        router
+            .estimate_swap_routed(&tokens, &token1.address, &token2.address, &9_0000000)
+            .0,
+        standard2_pool_hash
+    );
+
+    // deprecated pool is still listed and accepts withdrawals and claims
+    assert_eq!(router.get_pools(&tokens).len(), 2);
+    router.claim(&user1, &tokens, &standard1_pool_hash);
+    let token_share = test_token::Client::new(&e, &router.share_id(&tokens, &standard1_pool_hash));
+    token_share.approve(&user1, &standard1_pool_address, &200_0000000, &99999);
+    router.withdraw(
+        &user1,
+        &tokens,
+        &standard1_pool_hash,
+        &100_0000000,
+        &Vec::from_array(&e, [0, 0]),
+    );
+
+    // removed pool is hidden from pools list, but still accessible for withdrawals
+    router.set_pool_state(&admin, &tokens, &standard1_pool_hash, &PoolState::Removed);
+    assert_eq!(router.get_pools(&tokens).len(), 1);
+    assert_eq!(router.get_pools_with_info(&tokens).len(), 1);
+    assert_eq!(router.get_pools_count(), 1);
+    router.withdraw(
+        &user1,
+        &tokens,
+        &standard1_pool_hash,
+        &100_0000000,
+        &Vec::from_array(&e, [0, 0]),
+    );
+
+    // active pool can be deprecated and activated back
+    router.set_pool_state(
+        &admin,
+        &tokens,
+        &standard2_pool_hash,
+        &PoolState::Deprecated,
+    );
+    router.set_pool_state(&admin, &tokens, &standard2_pool_hash, &PoolState::Active);
+    token1.approve(&user1, &standard2_pool_address, &1_0000000, &99999);
+    token2.approve(&user1, &standard2_pool_address, &1_0000000, &99999);
+    router.deposit(
+        &user1,
+        &tokens,
+        &standard2_pool_hash,
+        &Vec::from_array(&e, [1_0000000_u128, 1_0000000_u128]),
+    );
+}
+
+#[test]
+#[should_panic(expected = "pool is not active")]
+fn test_deprecated_pool_deposit() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.budget().reset_unlimited();
+
+    let mut admin1 = Address::generate(&e);
+    let mut admin2 = Address::generate(&e);
+
+    let mut token1 = create_token_contract(&e, &admin1);
+    let mut token2 = create_token_contract(&e, &admin2);
+    if &token2.address < &token1.address {
+        std::mem::swap(&mut token1, &mut token2);
+        std::mem::swap(&mut admin1, &mut admin2);
+    }
+    let tokens = Vec::from_array(&e, [token1.address.clone(), token2.address.clone()]);
+
+    let reward_admin = Address::generate(&e);
+    let admin = Address::generate(&e);
+
+    let reward_token = create_token_contract(&e, &reward_admin);
+
+    let user1 = Address::generate(&e);
+
+    let pool_hash = install_liq_pool_hash(&e);
+    let token_hash = install_token_wasm(&e);
+    let router = create_liqpool_router_contract(&e);
+    let plane = create_plane_contract(&e);
+    let swap_router = create_swap_router_contract(&e);
+    swap_router.init_admin(&admin);
+    swap_router.set_pools_plane(&admin, &plane.address);
+    router.init_admin(&admin);
+    router.set_pool_hash(&pool_hash);
+    router.set_token_hash(&token_hash);
+    router.set_reward_token(&reward_token.address);
+    router.set_pools_plane(&admin, &plane.address);
+    router.set_swap_router(&admin, &swap_router.address);
+
+    token1.mint(&user1, &100000_0000000);
+    token2.mint(&user1, &100000_0000000);
+
+    let (standard1_pool_hash, standard1_pool_address) =
+        router.init_standard_pool(&user1, &tokens, &10);
+    token1.approve(&user1, &standard1_pool_address, &1000_0000000, &99999);
+    token2.approve(&user1, &standard1_pool_address, &1000_0000000, &99999);
+    router.deposit(
+        &user1,
+        &tokens,
+        &standard1_pool_hash,
+        &Vec::from_array(&e, [1000_0000000_u128, 1000_0000000_u128]),
+    );
+
+    let (standard2_pool_hash, standard2_pool_address) =
+        router.init_standard_pool(&user1, &tokens, &30);
+    token1.approve(&user1, &standard2_pool_address, &1000_0000000, &99999);
+    token2.approve(&user1, &standard2_pool_address, &1000_0000000, &99999);
+    router.deposit(
+        &user1,
+        &tokens,
+        &standard2_pool_hash,
+        &Vec::from_array(&e, [1000_0000000_u128, 1000_0000000_u128]),
+    );
+
+    router.set_pool_state(
+        &admin,
+        &tokens,
+        &standard1_pool_hash,
+        &PoolState::Deprecated,
+    );
+    router.deposit(
+        &user1,
+        &tokens,
+        &standard1_pool_hash,
+        &Vec::from_array(&e, [1_0000000_u128, 1_0000000_u128]),
+    );
+}
+
+#[test]
+#[should_panic(expected = "pool is not active")]
+fn test_deprecated_pool_swap() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.budget().reset_unlimited();
+
+    let mut admin1 = Address::generate(&e);
+    let mut admin2 = Address::generate(&e);
+
+    let mut token1 = create_token_contract(&e, &admin1);
+    let mut token2 = create_token_contract(&e, &admin2);
+    if &token2.address < &token1.address {
+        std::mem::swap(&mut token1, &mut token2);
+        std::mem::swap(&mut admin1, &mut admin2);
+    }
+    let tokens = Vec::from_array(&e, [token1.address.clone(), token2.address.clone()]);
+
+    let reward_admin = Address::generate(&e);
+    let admin = Address::generate(&e);
+
+    let reward_token = create_token_contract(&e, &reward_admin);
+
+    let user1 = Address::generate(&e);
+
+    let pool_hash = install_liq_pool_hash(&e);
+    let token_hash = install_token_wasm(&e);
+    let router = create_liqpool_router_contract(&e);
+    let plane = create_plane_contract(&e);
+    let swap_router = create_swap_router_contract(&e);
+    swap_router.init_admin(&admin);
+    swap_router.set_pools_plane(&admin, &plane.address);
+    router.init_admin(&admin);
+    router.set_pool_hash(&pool_hash);
+    router.set_token_hash(&token_hash);
+    router.set_reward_token(&reward_token.address);
+    router.set_pools_plane(&admin, &plane.address);
+    router.set_swap_router(&admin, &swap_router.address);
+
+    token1.mint(&user1, &100000_0000000);
+    token2.mint(&user1, &100000_0000000);
+
+    let (standard1_pool_hash, standard1_pool_address) =
+        router.init_standard_pool(&user1, &tokens, &10);
+    token1.approve(&user1, &standard1_pool_address, &1000_0000000, &99999);
+    token2.approve(&user1, &standard1_pool_address, &1000_0000000, &99999);
+    router.deposit(
+        &user1,
+        &tokens,
+        &standard1_pool_hash,
+        &Vec::from_array(&e, [1000_0000000_u128, 1000_0000000_u128]),
+    );
+
+    let (standard2_pool_hash, standard2_pool_address) =
+        router.init_standard_pool(&user1, &tokens, &30);
+    token1.approve(&user1, &standard2_pool_address, &1000_0000000, &99999);
+    token2.approve(&user1, &standard2_pool_address, &1000_0000000, &99999);
+    router.deposit(
+        &user1,
+        &tokens,
+        &standard2_pool_hash,
+        &Vec::from_array(&e, [1000_0000000_u128, 1000_0000000_u128]),
+    );
+
+    router.set_pool_state(
+        &admin,
+        &tokens,
+        &standard2_pool_hash,
+        &PoolState::Deprecated,
+    );
+    router.swap(
+        &user1,
+        &tokens,
+        &token1.address,
+        &token2.address,
+        &standard2_pool_hash,
+        &1_0000000,
+        &0,
+    );
+}
+
+#[test]
+#[should_panic(expected = "pool is removed")]
+fn test_removed_pool_state_final() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.budget().reset_unlimited();
+
+    let mut admin1 = Address::generate(&e);
+    let mut admin2 = Address::generate(&e);
+
+    let mut token1 = create_token_contract(&e, &admin1);
+    let mut token2 = create_token_contract(&e, &admin2);
+    if &token2.address < &token1.address {
+        std::mem::swap(&mut token1, &mut token2);
+        std::mem::swap(&mut admin1, &mut admin2);
+    }
+    let tokens = Vec::from_array(&e, [token1.address.clone(), token2.address.clone()]);
+
+    let reward_admin = Address::generate(&e);
+    let admin = Address::generate(&e);
+
+    let reward_token = create_token_contract(&e, &reward_admin);
+
+    let user1 = Address::generate(&e);
+
+    let pool_hash = install_liq_pool_hash(&e);
+    let token_hash = install_token_wasm(&e);
+    let router = create_liqpool_router_contract(&e);
+    let plane = create_plane_contract(&e);
+    let swap_router = create_swap_router_contract(&e);
+    swap_router.init_admin(&admin);
+    swap_router.set_pools_plane(&admin, &plane.address);
+    router.init_admin(&admin);
+    router.set_pool_hash(&pool_hash);
+    router.set_token_hash(&token_hash);
+    router.set_reward_token(&reward_token.address);
+    router.set_pools_plane(&admin, &plane.address);
+    router.set_swap_router(&admin, &swap_router.address);
+
+    token1.mint(&user1, &100000_0000000);
+    token2.mint(&user1, &100000_0000000);
+
+    let (standard1_pool_hash, standard1_pool_address) =
+        router.init_standard_pool(&user1, &tokens, &10);
+    token1.approve(&user1, &standard1_pool_address, &1000_0000000, &99999);
+    token2.approve(&user1, &standard1_pool_address, &1000_0000000, &99999);
+    router.deposit(
+        &user1,
+        &tokens,
+        &standard1_pool_hash,
+        &Vec::from_array(&e, [1000_0000000_u128, 1000_0000000_u128]),
+    );
+
+    let (standard2_pool_hash, standard2_pool_address) =
+        router.init_standard_pool(&user1, &tokens, &30);
+    token1.approve(&user1, &standard2_pool_address, &1000_0000000, &99999);
+    token2.approve(&user1, &standard2_pool_address, &1000_0000000, &99999);
+    router.deposit(
+        &user1,
+        &tokens,
+        &standard2_pool_hash,
+        &Vec::from_array(&e, [1000_0000000_u128, 1000_0000000_u128]),
+    );
+
+    router.remove_pool(&admin, &tokens, &standard1_pool_hash);
+    router.set_pool_state(&admin, &tokens, &standard1_pool_hash, &PoolState::Active);
+}
+
+#[test]
+fn test_roles() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.budget().reset_unlimited();
+
+    let mut admin1 = Address::generate(&e);
+    let mut admin2 = Address::generate(&e);
+
+    let mut token1 = create_token_contract(&e, &admin1);
+    let mut token2 = create_token_contract(&e, &admin2);
+    if &token2.address < &token1.address {
+        std::mem::swap(&mut token1, &mut token2);
+        std::mem::swap(&mut admin1, &mut admin2);
+    }
+    let tokens = Vec::from_array(&e, [token1.address.clone(), token2.address.clone()]);
+
+    let admin = Address::generate(&e);
+    let operations_admin = Address::generate(&e);
+    let pause_admin = Address::generate(&e);
+
+    let router = create_liqpool_router_contract(&e);
+    router.init_admin(&admin);
+
+    let pool_address = Address::generate(&e);
+    let pool_index = router.add_custom_pool(
+        &admin,
+        &tokens,
+        &pool_address,
+        &symbol_short!("custom"),
+        &Vec::new(&e),
+    );
+
+    assert!(router.has_role(&Role::Owner, &admin));
+    assert!(router.has_role(&Role::OperationsAdmin, &admin));
+    assert!(!router.has_role(&Role::OperationsAdmin, &operations_admin));
+
+    router.grant_role(&admin, &Role::OperationsAdmin, &operations_admin);
+    assert_eq!(
+        vec![&e, e.events().all().last().unwrap()],
+        vec![
+            &e,
+            (
+                router.address.clone(),
+                (Symbol::new(&e, "role_granted"), Role::OperationsAdmin).into_val(&e),
+                (operations_admin.clone(),).into_val(&e),
+            ),
+        ]
+    );
+    router.grant_role(&admin, &Role::EmergencyPauseAdmin, &pause_admin);
+    assert!(router.has_role(&Role::OperationsAdmin, &operations_admin));
+    assert!(!router.has_role(&Role::Owner, &operations_admin));
+    assert!(!router.has_role(&Role::OperationsAdmin, &pause_admin));
+
+    // emergency pause admin is able to deprecate pool, operations admin manages the rest
+    router.set_pool_state(&pause_admin, &tokens, &pool_index, &PoolState::Deprecated);
+    router.set_pool_state(&operations_admin, &tokens, &pool_index, &PoolState::Active);
+    router.remove_pool(&operations_admin, &tokens, &pool_index);
+    assert_eq!(router.get_pools(&tokens).len(), 0);
+
+    router.revoke_role(&admin, &Role::OperationsAdmin, &operations_admin);
+    assert_eq!(
+        vec![&e, e.events().all().last().unwrap()],
+        vec![
+            &e,
+            (
+                router.address.clone(),
+                (Symbol::new(&e, "role_revoked"), Role::OperationsAdmin).into_val(&e),
+                (operations_admin.clone(),).into_val(&e),
+            ),
+        ]
+    );
+    assert!(!router.has_role(&Role::OperationsAdmin, &operations_admin));
+
+    router.renounce_role(&pause_admin, &Role::EmergencyPauseAdmin);
+    assert!(!router.has_role(&Role::EmergencyPauseAdmin, &pause_admin));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #103)")]
+fn test_emergency_pause_admin_remove_pool() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.budget().reset_unlimited();
+
+    let mut admin1 = Address::generate(&e);
+    let mut admin2 = Address::generate(&e);
+
+    let mut token1 = create_token_contract(&e, &admin1);
+    let mut token2 = create_token_contract(&e, &admin2);
+    if &token2.address < &token1.address {
+        std::mem::swap(&mut token1, &mut token2);
+        std::mem::swap(&mut admin1, &mut admin2);
+    }
+    let tokens = Vec::from_array(&e, [token1.address.clone(), token2.address.clone()]);
+
+    let admin = Address::generate(&e);
+    let _operations_admin = Address::generate(&e);
+    let pause_admin = Address::generate(&e);
+
+    let router = create_liqpool_router_contract(&e);
+    router.init_admin(&admin);
+
+    let pool_address = Address::generate(&e);
+    let pool_index = router.add_custom_pool(
+        &admin,
+        &tokens,
+        &pool_address,
+        &symbol_short!("custom"),
+        &Vec::new(&e),
+    );
+
+    router.grant_role(&admin, &Role::EmergencyPauseAdmin, &pause_admin);
+    router.set_pool_state(&pause_admin, &tokens, &pool_index, &PoolState::Removed);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #102)")]
+fn test_operations_admin_grant_role() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.budget().reset_unlimited();
+
+    let mut admin1 = Address::generate(&e);
+    let mut admin2 = Address::generate(&e);
+
+    let mut token1 = create_token_contract(&e, &admin1);
+    let mut token2 = create_token_contract(&e, &admin2);
+    if &token2.address < &token1.address {
+        std::mem::swap(&mut token1, &mut token2);
+        std::mem::swap(&mut admin1, &mut admin2);
+    }
+    let tokens = Vec::from_array(&e, [token1.address.clone(), token2.address.clone()]);
+
+    let admin = Address::generate(&e);
+    let operations_admin = Address::generate(&e);
+    let pause_admin = Address::generate(&e);
+
+    let router = create_liqpool_router_contract(&e);
+    router.init_admin(&admin);
+
+    let pool_address = Address::generate(&e);
+    let _pool_index = router.add_custom_pool(
+        &admin,
+        &tokens,
+        &pool_address,
+        &symbol_short!("custom"),
+        &Vec::new(&e),
+    );
+
+    router.grant_role(&admin, &Role::OperationsAdmin, &operations_admin);
+    router.grant_role(&operations_admin, &Role::EmergencyPauseAdmin, &pause_admin);
+}
+
+#[test]
+fn test_transfer_ownership() {
+    let e = Env::default();
+    e.mock_all_auths();
+    jump(&e, 1_700_000_000);
+
+    let admin_original = Address::generate(&e);
+    let admin_new = Address::generate(&e);
+
+    let router = create_liqpool_router_contract(&e);
+    router.init_admin(&admin_original);
+
+    // delay increase is applied immediately
+    router.set_transfer_delay(&admin_original, &(4 * 86400));
+    assert_eq!(router.get_transfer_delay(), 4 * 86400);
+    assert_eq!(
+        vec![&e, e.events().all().last().unwrap()],
+        vec![
+            &e,
+            (
+                router.address.clone(),
+                (Symbol::new(&e, "set_transfer_delay"),).into_val(&e),
+                (4 * 86400_u64,).into_val(&e),
+            ),
+        ]
+    );
+
+    router.commit_transfer_ownership(&admin_original, &admin_new);
+    let deadline = e.ledger().timestamp() + 4 * 86400;
+    assert_eq!(router.get_future_admin(), Some(admin_new.clone()));
+    assert_eq!(router.get_transfer_ownership_deadline(), deadline);
+    assert_eq!(
+        vec![&e, e.events().all().last().unwrap()],
+        vec![
+            &e,
+            (
+                router.address.clone(),
+                (Symbol::new(&e, "commit_transfer_ownership"),).into_val(&e),
+                (admin_new.clone(), deadline).into_val(&e),
+            ),
+        ]
+    );
+
+    jump(&e, 4 * 86400 + 1);
+    router.apply_transfer_ownership(&admin_original);
+    assert_eq!(router.get_future_admin(), None);
+    assert_eq!(router.get_transfer_ownership_deadline(), 0);
+    assert!(router.has_role(&Role::Owner, &admin_new));
+    assert!(!router.has_role(&Role::Owner, &admin_original));
+
+    // nothing to revert
+    assert!(router.try_revert_transfer_ownership(&admin_new).is_err());
+}
+
+#[test]
+fn test_transfer_delay_decrease() {
+    let e = Env::default();
+    e.mock_all_auths();
+    jump(&e, 1_700_000_000);
+
+    let admin = Address::generate(&e);
+    let router = create_liqpool_router_contract(&e);
+    router.init_admin(&admin);
+
+    assert!(router.try_set_transfer_delay(&admin, &(86400 - 1)).is_err());
+
+    // decrease has to wait out the current delay
+    router.set_transfer_delay(&admin, &86400);
+    let effective_at = e.ledger().timestamp() + 3 * 86400;
+    assert_eq!(
+        vec![&e, e.events().all().last().unwrap()],
+        vec![
+            &e,
+            (
+                router.address.clone(),
+                (Symbol::new(&e, "commit_transfer_delay"),).into_val(&e),
+                (86400_u64, effective_at).into_val(&e),
+            ),
+        ]
+    );
+    assert_eq!(router.get_transfer_delay(), 3 * 86400);
+    jump(&e, 3 * 86400 - 1);
+    assert_eq!(router.get_transfer_delay(), 3 * 86400);
+    jump(&e, 1);
+    assert_eq!(router.get_transfer_delay(), 86400);
+
+    router.commit_transfer_ownership(&admin, &Address::generate(&e));
+    assert_eq!(
+        router.get_transfer_ownership_deadline(),
+        e.ledger().timestamp() + 86400
+    );
+    router.revert_transfer_ownership(&admin);
+
+    // increase cancels pending decrease
+    router.set_transfer_delay(&admin, &(2 * 86400));
+    router.set_transfer_delay(&admin, &(86400 + 1));
+    router.set_transfer_delay(&admin, &(3 * 86400));
+    jump(&e, 2 * 86400);
+    assert_eq!(router.get_transfer_delay(), 3 * 86400);
+}
+
+#[test]
+#[should_panic(expected = "insufficient time")]
+fn test_transfer_ownership_too_early() {
+    let e = Env::default();
+    e.mock_all_auths();
+    jump(&e, 1_700_000_000);
+
+    let admin_original = Address::generate(&e);
+    let admin_new = Address::generate(&e);
+
+    let router = create_liqpool_router_contract(&e);
+    router.init_admin(&admin_original);
+
+    router.commit_transfer_ownership(&admin_original, &admin_new);
+    jump(&e, 3 * 86400 - 1);
+    router.apply_transfer_ownership(&admin_original);
+}
+
+#[test]
+fn test_pools_managed_by_router() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.budget().reset_unlimited();
+    jump(&e, 1_700_000_000);
+
+    let mut admin1 = Address::generate(&e);
+    let mut admin2 = Address::generate(&e);
+
+    let mut token1 = create_token_contract(&e, &admin1);
+    let mut token2 = create_token_contract(&e, &admin2);
+    if &token2.address < &token1.address {
+        std::mem::swap(&mut token1, &mut token2);
+        std::mem::swap(&mut admin1, &mut admin2);
+    }
+    let tokens = Vec::from_array(&e, [token1.address.clone(), token2.address.clone()]);
+
+    let reward_admin = Address::generate(&e);
+    let admin = Address::generate(&e);
+    let admin_new = Address::generate(&e);
+    let operations_admin = Address::generate(&e);
+    let fees_receiver = Address::generate(&e);
+    let user1 = Address::generate(&e);
+
+    let reward_token = create_token_contract(&e, &reward_admin);
+
+    let router = create_liqpool_router_contract(&e);
+    router.init_admin(&admin);
+    router.set_pool_hash(&install_liq_pool_hash(&e));
+    router.set_stableswap_pool_hash(&2, &install_stableswap_two_tokens_liq_pool_hash(&e));
+    router.set_token_hash(&install_token_wasm(&e));
+    router.set_reward_token(&reward_token.address);
+    router.set_pools_plane(&admin, &create_plane_contract(&e).address);
+    router.grant_role(&admin, &Role::OperationsAdmin, &operations_admin);
+
+    let (pool_index, pool_address) = router.init_stableswap_pool(&user1, &tokens, &10, &30, &5000);
+    assert!(router.is_pool_managed(&tokens, &pool_index));
+
+    token1.mint(&user1, &1000_0000000);
+    token2.mint(&user1, &1000_0000000);
+    token1.approve(&user1, &pool_address, &1000_0000000, &99999);
+    token2.approve(&user1, &pool_address, &1000_0000000, &99999);
+    router.deposit(
+        &user1,
+        &tokens,
+        &pool_index,
+        &Vec::from_array(&e, [100_0000000, 100_0000000]),
+    );
+    router.swap(
+        &user1,
+        &tokens,
+        &token1.address,
+        &token2.address,
+        &pool_index,
+        &10_0000000,
+        &0,
+    );
+
+    // admin fees are withdrawn through router
+    let fees = router.withdraw_pool_admin_fees(&admin, &tokens, &pool_index, &fees_receiver);
+    assert_eq!(fees.get(0).unwrap(), 0);
+    assert!(fees.get(1).unwrap() > 0);
+    assert_eq!(token2.balance(&fees_receiver), fees.get(1).unwrap() as i128);
+    assert_eq!(token2.balance(&router.address), 0);
+
+    // fee change is managed by router operations admin
+    router.commit_pool_fee(&operations_admin, &tokens, &pool_index, &10, &0);
+    jump(&e, 3 * 86400 + 1);
+    router.apply_pool_fee(&operations_admin, &tokens, &pool_index);
+    assert_eq!(
+        u32::from_val(
+            &e,
+            &router
+                .get_info(&tokens, &pool_index)
+                .get(symbol_short!("fee"))
+                .unwrap()
+        ),
+        10
+    );
+
+    // pools follow router admin rotation
+    router.commit_transfer_ownership(&admin, &admin_new);
+    jump(&e, 3 * 86400 + 1);
+    router.apply_transfer_ownership(&admin);
+    reward_token.mint(&pool_address, &60);
+    router.set_rewards_config(
+        &admin_new,
+        &tokens,
+        &pool_index,
+        &e.ledger().timestamp().saturating_add(60),
+        &1,
+    );
+}
+
+#[test]
+fn test_kill_pool() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.budget().reset_unlimited();
+
+    let mut admin1 = Address::generate(&e);
+    let mut admin2 = Address::generate(&e);
+
+    let mut token1 = create_token_contract(&e, &admin1);
+    let mut token2 = create_token_contract(&e, &admin2);
+    if &token2.address < &token1.address {
+        std::mem::swap(&mut token1, &mut token2);
+        std::mem::swap(&mut admin1, &mut admin2);
+    }
+    let tokens = Vec::from_array(&e, [token1.address.clone(), token2.address.clone()]);
+
+    let reward_admin = Address::generate(&e);
+    let admin = Address::generate(&e);
+    let pause_admin = Address::generate(&e);
+    let user1 = Address::generate(&e);
+
+    let reward_token = create_token_contract(&e, &reward_admin);
+
+    let router = create_liqpool_router_contract(&e);
+    router.init_admin(&admin);
+    router.set_pool_hash(&install_liq_pool_hash(&e));
+    router.set_stableswap_pool_hash(&2, &install_stableswap_two_tokens_liq_pool_hash(&e));
+    router.set_token_hash(&install_token_wasm(&e));
+    router.set_reward_token(&reward_token.address);
+    router.set_pools_plane(&admin, &create_plane_contract(&e).address);
+    router.grant_role(&admin, &Role::EmergencyPauseAdmin, &pause_admin);
+
+    let (pool_index, pool_address) = router.init_stableswap_pool(&user1, &tokens, &10, &30, &0);
+    router.kill_pool(&pause_admin, &tokens, &pool_index);
+
+    token1.mint(&user1, &1000_0000000);
+    token2.mint(&user1, &1000_0000000);
+    token1.approve(&user1, &pool_address, &1000_0000000, &99999);
+    token2.approve(&user1, &pool_address, &1000_0000000, &99999);
+    let desired_amounts = Vec::from_array(&e, [100_0000000, 100_0000000]);
+    assert!(router
+        .try_deposit(&user1, &tokens, &pool_index, &desired_amounts)
+        .is_err());
+
+    router.unkill_pool(&pause_admin, &tokens, &pool_index);
+    router.deposit(&user1, &tokens, &pool_index, &desired_amounts);
+    assert_eq!(token1.balance(&pool_address), 100_0000000);
+}
+
+#[test]
+fn test_commit_pools_admin() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.budget().reset_unlimited();
+    jump(&e, 1_700_000_000);
+
+    let mut admin1 = Address::generate(&e);
+    let mut admin2 = Address::generate(&e);
+
+    let mut token1 = create_token_contract(&e, &admin1);
+    let mut token2 = create_token_contract(&e, &admin2);
+    if &token2.address < &token1.address {
+        std::mem::swap(&mut token1, &mut token2);
+        std::mem::swap(&mut admin1, &mut admin2);
+    }
+    let tokens = Vec::from_array(&e, [token1.address.clone(), token2.address.clone()]);
+
+    let reward_admin = Address::generate(&e);
+    let admin = Address::generate(&e);
+    let pool_admin_new = Address::generate(&e);
+    let reward_token = create_token_contract(&e, &reward_admin);
+
+    let plane = create_plane_contract(&e);
+    let router = create_liqpool_router_contract(&e);
+    router.init_admin(&admin);
+    router.set_reward_token(&reward_token.address);
+
+    // pool deployed with router admin as its admin
+    let pool = create_standard_pool_contract(&e);
+    pool.initialize_all(
+        &admin,
+        &install_token_wasm(&e),
+        &tokens,
+        &30,
+        &1_000_000_000_000,
+        &reward_token.address,
+        &pool.address,
+        &plane.address,
+    );
+    let pool_index = router.add_custom_pool(
+        &admin,
+        &tokens,
+        &pool.address,
+        &symbol_short!("custom"),
+        &Vec::new(&e),
+    );
+    assert!(!router.is_pool_managed(&tokens, &pool_index));
+    let pools = Vec::from_array(&e, [(tokens.clone(), pool_index.clone())]);
+
+    router.commit_pools_admin(&admin, &pools, &router.address);
+    assert_eq!(
+        vec![&e, e.events().all().last().unwrap()],
+        vec![
+            &e,
+            (
+                router.address.clone(),
+                (Symbol::new(&e, "commit_pool_admin"), tokens.clone()).into_val(&e),
+                (pool.address.clone(), router.address.clone()).into_val(&e),
+            ),
+        ]
+    );
+    // pool transfer delay has to pass
+    assert!(router.try_apply_pools_admin(&admin, &pools).is_err());
+    jump(&e, 3 * 86400 + 1);
+    router.apply_pools_admin(&admin, &pools);
+    assert!(router.is_pool_managed(&tokens, &pool_index));
+    assert!(pool.has_role(&standard_pool::Role::Owner, &router.address));
+    assert!(!pool.has_role(&standard_pool::Role::Owner, &admin));
+    assert_eq!(
+        vec![&e, e.events().all().last().unwrap()],
+        vec![
+            &e,
+            (
+                router.address.clone(),
+                (Symbol::new(&e, "set_pool_admin"), tokens.clone()).into_val(&e),
+                (pool.address.clone(), router.address.clone()).into_val(&e),
+            ),
+        ]
+    );
+
+    router.commit_pools_admin(&admin, &pools, &pool_admin_new);
+    jump(&e, 3 * 86400 + 1);
+    router.apply_pools_admin(&admin, &pools);
+    assert!(!router.is_pool_managed(&tokens, &pool_index));
+    assert!(pool.has_role(&standard_pool::Role::Owner, &pool_admin_new));
+    assert!(!pool.has_role(&standard_pool::Role::Owner, &router.address));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #102)")]
+fn test_commit_pools_admin_not_admin() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let user1 = Address::generate(&e);
+
+    let router = create_liqpool_router_contract(&e);
+    router.init_admin(&admin);
+    router.commit_pools_admin(&user1, &Vec::new(&e), &user1);
+}
+
+#[test]
+fn test_multiple_reward_streams() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.budget().reset_unlimited();
+
+    let mut admin1 = Address::generate(&e);
+    let mut admin2 = Address::generate(&e);
+
+    let mut token1 = create_token_contract(&e, &admin1);
+    let mut token2 = create_token_contract(&e, &admin2);
+    if &token2.address < &token1.address {
+        std::mem::swap(&mut token1, &mut token2);
+        std::mem::swap(&mut admin1, &mut admin2);
+    }
+    let tokens = Vec::from_array(&e, [token1.address.clone(), token2.address.clone()]);
+
+    let reward_admin = Address::generate(&e);
+    let admin = Address::generate(&e);
+    let rewards_admin = Address::generate(&e);
+    let partner = Address::generate(&e);
+    let user1 = Address::generate(&e);
+
+    let reward_token = create_token_contract(&e, &reward_admin);
+    let partner_token = create_token_contract(&e, &reward_admin);
+
+    let router = create_liqpool_router_contract(&e);
+    router.init_admin(&admin);
+    router.set_pool_hash(&install_liq_pool_hash(&e));
+    router.set_token_hash(&install_token_wasm(&e));
+    router.set_reward_token(&reward_token.address);
+    router.set_pools_plane(&admin, &create_plane_contract(&e).address);
+    router.grant_role(&admin, &Role::RewardsAdmin, &rewards_admin);
+
+    let (pool_index, pool_address) = router.init_standard_pool(&user1, &tokens, &30);
+
+    let partner_tps = 5_0000000_u128;
+    let total_partner_reward = partner_tps * 60;
+    partner_token.mint(&partner, &(total_partner_reward as i128));
+    partner_token.approve(
+        &partner,
+        &pool_address,
+        &(total_partner_reward as i128),
+        &99999,
+    );
+    let stream = router.add_reward_stream(
+        &rewards_admin,
+        &tokens,
+        &pool_index,
+        &partner_token.address,
+        &partner,
+    );
+    assert_eq!(stream, 1);
+    assert_eq!(
+        vec![&e, e.events().all().last().unwrap()],
+        vec![
+            &e,
+            (
+                router.address.clone(),
+                (
+                    Symbol::new(&e, "add_reward_stream"),
+                    tokens.clone(),
+                    rewards_admin.clone()
+                )
+                    .into_val(&e),
+                (
+                    pool_address.clone(),
+                    stream,
+                    partner_token.address.clone(),
+                    partner.clone()
+                )
+                    .into_val(&e),
+            ),
+        ]
+    );
+    router.set_stream_rewards_config(
+        &rewards_admin,
+        &tokens,
+        &pool_index,
+        &stream,
+        &e.ledger().timestamp().saturating_add(60),
+        &partner_tps,
+    );
+
+    token1.mint(&user1, &1000_0000000);
+    token2.mint(&user1, &1000_0000000);
+    token1.approve(&user1, &pool_address, &1000_0000000, &99999);
+    token2.approve(&user1, &pool_address, &1000_0000000, &99999);
+    router.deposit(
+        &user1,
+        &tokens,
+        &pool_index,
+        &Vec::from_array(&e, [100_0000000, 100_0000000]),
+    );
+
+    jump(&e, 60);
+    let rewards_info = router.get_all_rewards_info(&user1, &tokens, &pool_index);
+    assert_eq!(rewards_info.len(), 2);
+    assert_eq!(rewards_info.get(0).unwrap().0, reward_token.address);
+    assert_eq!(rewards_info.get(1).unwrap().0, partner_token.address);
+    assert_eq!(
+        router.get_all_user_rewards(&user1, &tokens, &pool_index),
+        Vec::from_array(&e, [0, total_partner_reward])
+    );
+    assert_eq!(
+        router.claim_all_rewards(&user1, &tokens, &pool_index),
+        Vec::from_array(&e, [0, total_partner_reward])
+    );
+    assert_eq!(partner_token.balance(&user1) as u128, total_partner_reward);
+}
+
+#[test]
+fn test_set_pool_voting_escrow() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.budget().reset_unlimited();
+
+    let mut admin1 = Address::generate(&e);
+    let mut admin2 = Address::generate(&e);
+
+    let mut token1 = create_token_contract(&e, &admin1);
+    let mut token2 = create_token_contract(&e, &admin2);
+    if &token2.address < &token1.address {
+        std::mem::swap(&mut token1, &mut token2);
+        std::mem::swap(&mut admin1, &mut admin2);
+    }
+    let tokens = Vec::from_array(&e, [token1.address.clone(), token2.address.clone()]);
+
+    let reward_admin = Address::generate(&e);
+    let admin = Address::generate(&e);
+    let rewards_admin = Address::generate(&e);
+    let user1 = Address::generate(&e);
+    let voting_escrow = Address::generate(&e);
+
+    let reward_token = create_token_contract(&e, &reward_admin);
+
+    let router = create_liqpool_router_contract(&e);
+    router.init_admin(&admin);
+    router.set_pool_hash(&install_liq_pool_hash(&e));
+    router.set_token_hash(&install_token_wasm(&e));
+    router.set_reward_token(&reward_token.address);
+    router.set_pools_plane(&admin, &create_plane_contract(&e).address);
+    router.grant_role(&admin, &Role::RewardsAdmin, &rewards_admin);
+
+    let (pool_index, pool_address) = router.init_standard_pool(&user1, &tokens, &30);
+    let pool = standard_pool::Client::new(&e, &pool_address);
+    assert_eq!(pool.get_voting_escrow(), None);
+
+    router.set_pool_voting_escrow(&rewards_admin, &tokens, &pool_index, &voting_escrow);
+    assert_eq!(
+        vec![&e, e.events().all().last().unwrap()],
+        vec![
+            &e,
+            (
+                router.address.clone(),
+                (
+                    Symbol::new(&e, "set_pool_voting_escrow"),
+                    tokens.clone(),
+                    rewards_admin.clone()
+                )
+                    .into_val(&e),
+                (pool_address.clone(), voting_escrow.clone()).into_val(&e),
+            )
+        ]
+    );
+    assert_eq!(pool.get_voting_escrow(), Some(voting_escrow));
+    assert!(router
+        .try_set_pool_voting_escrow(&user1, &tokens, &pool_index, &admin)
+        .is_err());
+}
+
+#[test]
+fn test_enable_pool_rewards_checkpoint() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.budget().reset_unlimited();
+
+    let mut admin1 = Address::generate(&e);
+    let mut admin2 = Address::generate(&e);
+
+    let mut token1 = create_token_contract(&e, &admin1);
+    let mut token2 = create_token_contract(&e, &admin2);
+    if token2.address < token1.address {
+        std::mem::swap(&mut token1, &mut token2);
+        std::mem::swap(&mut admin1, &mut admin2);
+    }
+    let tokens = Vec::from_array(&e, [token1.address.clone(), token2.address.clone()]);
+
+    let reward_admin = Address::generate(&e);
+    let admin = Address::generate(&e);
+    let rewards_admin = Address::generate(&e);
+    let user1 = Address::generate(&e);
+
+    let reward_token = create_token_contract(&e, &reward_admin);
+
+    let router = create_liqpool_router_contract(&e);
+    router.init_admin(&admin);
+    router.set_pool_hash(&install_liq_pool_hash(&e));
+    router.set_token_hash(&install_token_wasm(&e));
+    router.set_reward_token(&reward_token.address);
+    router.set_pools_plane(&admin, &create_plane_contract(&e).address);
+    router.grant_role(&admin, &Role::RewardsAdmin, &rewards_admin);
+
+    let (pool_index, pool_address) = router.init_standard_pool(&user1, &tokens, &30);
+    let token_share = test_token::Client::new(&e, &router.share_id(&tokens, &pool_index));
+
+    // pool created before share transfers were checkpointed
+    token_share.set_rewards_checkpoint(&false);
+    assert!(!token_share.get_rewards_checkpoint());
+
+    assert!(router
+        .try_enable_pool_rewards_checkpoint(&user1, &tokens, &pool_index)
+        .is_err());
+    router.enable_pool_rewards_checkpoint(&rewards_admin, &tokens, &pool_index);
+    assert_eq!(
+        vec![&e, e.events().all().last().unwrap()],
+        vec![
+            &e,
+            (
+                router.address.clone(),
+                (
+                    Symbol::new(&e, "enable_pool_rewards_checkpoint"),
+                    tokens.clone(),
+                    rewards_admin.clone()
+                )
+                    .into_val(&e),
+                (pool_address,).into_val(&e),
+            )
+        ]
+    );
+    assert!(token_share.get_rewards_checkpoint());
+}
+
+#[test]
+fn test_set_pool_reward_precision() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.budget().reset_unlimited();
+
+    let mut admin1 = Address::generate(&e);
+    let mut admin2 = Address::generate(&e);
+
+    let mut token1 = create_token_contract(&e, &admin1);
+    let mut token2 = create_token_contract(&e, &admin2);
+    if &token2.address < &token1.address {
+        std::mem::swap(&mut token1, &mut token2);
+        std::mem::swap(&mut admin1, &mut admin2);
+    }
+    let tokens = Vec::from_array(&e, [token1.address.clone(), token2.address.clone()]);
+
+    let reward_admin = Address::generate(&e);
+    let admin = Address::generate(&e);
+    let rewards_admin = Address::generate(&e);
+    let user1 = Address::generate(&e);
+
+    let reward_token = create_token_contract(&e, &reward_admin);
+
+    let router = create_liqpool_router_contract(&e);
+    router.init_admin(&admin);
+    router.set_pool_hash(&install_liq_pool_hash(&e));
+    router.set_token_hash(&install_token_wasm(&e));
+    router.set_reward_token(&reward_token.address);
+    router.set_pools_plane(&admin, &create_plane_contract(&e).address);
+    router.grant_role(&admin, &Role::RewardsAdmin, &rewards_admin);
+
+    let (pool_index, pool_address) = router.init_standard_pool(&user1, &tokens, &30);
+    let pool = standard_pool::Client::new(&e, &pool_address);
+    assert_eq!(pool.get_reward_precision(), 1_000_000_000_000);
+
+    router.set_pool_reward_precision(&rewards_admin, &tokens, &pool_index, &1_000_000_000_000_000);
+    assert_eq!(
+        vec![&e, e.events().all().last().unwrap()],
+        vec![
+            &e,
+            (
+                router.address.clone(),
+                (
+                    Symbol::new(&e, "set_pool_reward_precision"),
+                    tokens.clone(),
+                    rewards_admin.clone()
+                )
+                    .into_val(&e),
+                (pool_address.clone(), 1_000_000_000_000_000_u128).into_val(&e),
+            )
+        ]
+    );
+    assert_eq!(pool.get_reward_precision(), 1_000_000_000_000_000);
+    assert!(router
+        .try_set_pool_reward_precision(&user1, &tokens, &pool_index, &1_000_000_000_000_000_000)
+        .is_err());
+}
+
+#[test]
+fn test_incentives() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.budget().reset_unlimited();
+
+    let mut admin1 = Address::generate(&e);
+    let mut admin2 = Address::generate(&e);
+
+    let mut token1 = create_token_contract(&e, &admin1);
+    let mut token2 = create_token_contract(&e, &admin2);
+    if &token2.address < &token1.address {
+        std::mem::swap(&mut token1, &mut token2);
+        std::mem::swap(&mut admin1, &mut admin2);
+    }
+    let tokens = Vec::from_array(&e, [token1.address.clone(), token2.address.clone()]);
+
+    let reward_admin = Address::generate(&e);
+    let admin = Address::generate(&e);
+    let user1 = Address::generate(&e);
+    let depositor = Address::generate(&e);
+
+    let reward_token = create_token_contract(&e, &reward_admin);
+    let incentive_token = create_token_contract(&e, &depositor);
+    incentive_token.mint(&depositor, &1000_0000000);
+
+    let router = create_liqpool_router_contract(&e);
+    router.init_admin(&admin);
+    router.set_pool_hash(&install_liq_pool_hash(&e));
+    router.set_token_hash(&install_token_wasm(&e));
+    router.set_reward_token(&reward_token.address);
+    router.set_pools_plane(&admin, &create_plane_contract(&e).address);
+
+    let (pool_index, pool_address) = router.init_standard_pool(&user1, &tokens, &30);
+    token1.mint(&user1, &1000);
+    token2.mint(&user1, &1000);
+    token1.approve(&user1, &pool_address, &1000, &99999);
+    token2.approve(&user1, &pool_address, &1000, &99999);
+    assert!(router
+        .try_deposit_incentive(
+            &depositor,
+            &tokens,
+            &pool_index,
+            &incentive_token.address,
+            &100_0000000,
+            &100,
+        )
+        .is_err());
+    assert!(router
+        .try_set_pool_incentive_token(
+            &depositor,
+            &tokens,
+            &pool_index,
+            &incentive_token.address,
+            &Some(10_0000000),
+        )
+        .is_err());
+    router.set_pool_incentive_token(
+        &admin,
+        &tokens,
+        &pool_index,
+        &incentive_token.address,
+        &Some(10_0000000),
+    );
+    assert_eq!(
+        vec![&e, e.events().all().last().unwrap()],
+        vec![
+            &e,
+            (
+                router.address.clone(),
+                (
+                    Symbol::new(&e, "set_pool_incentive_token"),
+                    tokens.clone(),
+                    admin.clone()
+                )
+                    .into_val(&e),
+                (
+                    pool_address.clone(),
+                    incentive_token.address.clone(),
+                    Some(10_0000000_u128)
+                )
+                    .into_val(&e),
+            )
+        ]
+    );
+    assert_eq!(
+        router.get_pool_incentive_min_amount(&tokens, &pool_index, &incentive_token.address),
+        Some(10_0000000)
+    );
+    let stream = router.deposit_incentive(
+        &depositor,
+        &tokens,
+        &pool_index,
+        &incentive_token.address,
+        &100_0000000,
+        &100,
+    );
+    assert_eq!(stream, 1);
+    assert_eq!(
+        vec![&e, e.events().all().last().unwrap()],
+        vec![
+            &e,
+            (
+                router.address.clone(),
+                (
+                    Symbol::new(&e, "deposit_incentive"),
+                    tokens.clone(),
+                    depositor.clone()
+                )
+                    .into_val(&e),
+                (
+                    pool_address.clone(),
+                    1_u32,
+                    incentive_token.address.clone(),
+                    100_0000000_u128,
+                    100_u64
+                )
+                    .into_val(&e),
+            )
+        ]
+    );
+    assert_eq!(
+        router
+            .get_incentive(&tokens, &pool_index, &1)
+            .unwrap()
+            .depositor,
+        depositor
+    );
+    router.deposit(
+        &user1,
+        &tokens,
+        &pool_index,
+        &Vec::from_array(&e, [100, 100]),
+    );
+
+    // liquidity leaves the pool halfway
+    jump(&e, 50);
+    let token_share = test_token::Client::new(&e, &router.share_id(&tokens, &pool_index));
+    token_share.approve(&user1, &pool_address, &100, &99999);
+    router.withdraw(
+        &user1,
+        &tokens,
+        &pool_index,
+        &100,
+        &Vec::from_array(&e, [0, 0]),
+    );
+    jump(&e, 50);
+    assert_eq!(
+        router.settle_incentive(&tokens, &pool_index, &1),
+        50_0000000
+    );
+    assert_eq!(
+        vec![&e, e.events().all().last().unwrap()],
+        vec![
+            &e,
+            (
+                router.address.clone(),
+                (
+                    Symbol::new(&e, "settle_incentive"),
+                    tokens.clone(),
+                    depositor.clone()
+                )
+                    .into_val(&e),
+                (pool_address.clone(), 1_u32, 50_0000000_u128).into_val(&e),
+            )
+        ]
+    );
+    assert_eq!(incentive_token.balance(&depositor), 950_0000000);
+    assert_eq!(router.get_incentive(&tokens, &pool_index, &1), None);
+
+    router.claim_all_rewards(&user1, &tokens, &pool_index);
+    assert_eq!(incentive_token.balance(&user1), 50_0000000);
+}
+
+#[test]
+fn test_volume_mining() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.budget().reset_unlimited();
+
+    let mut admin1 = Address::generate(&e);
+    let mut admin2 = Address::generate(&e);
+
+    let mut token1 = create_token_contract(&e, &admin1);
+    let mut token2 = create_token_contract(&e, &admin2);
+    if &token2.address < &token1.address {
+        std::mem::swap(&mut token1, &mut token2);
+        std::mem::swap(&mut admin1, &mut admin2);
+    }
+    let tokens = Vec::from_array(&e, [token1.address.clone(), token2.address.clone()]);
+
+    let reward_admin = Address::generate(&e);
+    let admin = Address::generate(&e);
+    let user1 = Address::generate(&e);
+    let trader = Address::generate(&e);
+
+    let reward_token = create_token_contract(&e, &reward_admin);
+
+    let router = create_liqpool_router_contract(&e);
+    router.init_admin(&admin);
+    router.set_pool_hash(&install_liq_pool_hash(&e));
+    router.set_token_hash(&install_token_wasm(&e));
+    router.set_reward_token(&reward_token.address);
+    router.set_pools_plane(&admin, &create_plane_contract(&e).address);
+
+    let (pool_index, pool_address) = router.init_standard_pool(&user1, &tokens, &30);
+    reward_token.mint(&pool_address, &100_0000000);
+    token1.mint(&user1, &1000_0000000);
+    token2.mint(&user1, &1000_0000000);
+    token1.approve(&user1, &pool_address, &1000_0000000, &99999);
+    token2.approve(&user1, &pool_address, &1000_0000000, &99999);
+    router.deposit(
+        &user1,
+        &tokens,
+        &pool_index,
+        &Vec::from_array(&e, [500_0000000, 500_0000000]),
+    );
+
+    // pool can't pay 200 tokens per epoch
+    assert!(router
+        .try_set_volume_mining_config(&admin, &tokens, &pool_index, &100, &200_0000000)
+        .is_err());
+    router.set_volume_mining_config(&admin, &tokens, &pool_index, &100, &50_0000000);
+    assert_eq!(
+        vec![&e, e.events().all().last().unwrap()],
+        vec![
+            &e,
+            (
+                router.address.clone(),
+                (
+                    Symbol::new(&e, "set_volume_mining_config"),
+                    tokens.clone(),
+                    admin.clone()
+                )
+                    .into_val(&e),
+                (pool_address.clone(), 100_u64, 50_0000000_u128).into_val(&e),
+            )
+        ]
+    );
+    assert_eq!(
+        router.get_volume_mining_config(&tokens, &pool_index),
+        Some(VolumeMiningConfig {
+            epoch_length: 100,
+            budget: 50_0000000,
+        })
+    );
+
+    token1.mint(&trader, &100_0000000);
+    token1.approve(&trader, &pool_address, &100_0000000, &99999);
+    router.swap(
+        &trader,
+        &tokens,
+        &token1.address,
+        &token2.address,
+        &pool_index,
+        &100_0000000,
+        &0,
+    );
+    assert_eq!(router.get_volume_epochs_count(&tokens, &pool_index), 1);
+    assert_eq!(
+        router.get_user_volume_points(&trader, &tokens, &pool_index, &0),
+        3000000
+    );
+    assert_eq!(
+        router
+            .get_volume_epoch(&tokens, &pool_index, &0)
+            .unwrap()
+            .total_points,
+        3000000
+    );
+
+    jump(&e, 100);
+    assert_eq!(
+        router.get_user_volume_reward(&trader, &tokens, &pool_index, &0),
+        50_0000000
+    );
+    assert_eq!(
+        router.claim_volume_reward(&trader, &tokens, &pool_index, &0),
+        50_0000000
+    );
+    assert_eq!(
+        vec![&e, e.events().all().last().unwrap()],
+        vec![
+            &e,
+            (
+                router.address.clone(),
+                (
+                    Symbol::new(&e, "claim_volume_reward"),
+                    tokens.clone(),
+                    trader.clone()
+                )
+                    .into_val(&e),
+                (pool_address.clone(), 0_u32, 50_0000000_u128).into_val(&e),
+            )
+        ]
+    );
+    assert_eq!(reward_token.balance(&trader), 50_0000000);
+
+    // epoch is swept once claim period is over
+    assert!(router
+        .try_sweep_pool_volume_epoch(&tokens, &pool_index, &0)
+        .is_err());
+    jump(&e, 30 * 24 * 60 * 60);
+    assert_eq!(router.sweep_pool_volume_epoch(&tokens, &pool_index, &0), 0);
+    assert_eq!(
+        vec![&e, e.events().all().last().unwrap()],
+        vec![
+            &e,
+            (
+                router.address.clone(),
+                (Symbol::new(&e, "sweep_volume_epoch"), tokens.clone()).into_val(&e),
+                (pool_address.clone(), 0_u32, 0_u128).into_val(&e),
+            )
+        ]
+    );
+}
+
+#[test]
+fn test_claim_all_with_distributions() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.budget().reset_unlimited();
+
+    let admin = Address::generate(&e);
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    let token = create_token_contract(&e, &admin);
+    token.mint(&admin, &1000);
+
+    let router = create_liqpool_router_contract(&e);
+    router.init_admin(&admin);
+
+    let distributor = merkle_distributor::Client::new(
+        &e,
+        &e.register_contract_wasm(None, merkle_distributor::WASM),
+    );
+    distributor.initialize(&admin);
+    let leaves = [(0_u32, &user1, 300_u128), (1, &user2, 700)].map(|leaf| {
+        e.crypto()
+            .sha256(&(leaf.0, leaf.1.clone(), leaf.2).to_xdr(&e))
+    });
+    let (left, right) = match leaves[0] < leaves[1] {
+        true => (&leaves[0], &leaves[1]),
+        false => (&leaves[1], &leaves[0]),
+    };
+    let mut node = Bytes::from(left.clone());
+    node.append(&Bytes::from(right.clone()));
+    let root = e.crypto().sha256(&node);
+    let deadline = e.ledger().timestamp() + 100;
+    distributor.add_distribution(&admin, &token.address, &root, &1000, &deadline);
+
+    let claims = Vec::from_array(
+        &e,
+        [MerkleClaim {
+            epoch: 0,
+            index: 0,
+            amount: 300,
+            proof: Vec::from_array(&e, [leaves[1].clone()]),
+        }],
+    );
+    // distributor should be configured first
+    assert!(router
+        .try_claim_all_with_distributions(&user1, &Vec::new(&e), &claims)
+        .is_err());
+    assert!(router
+        .try_set_merkle_distributor(&user1, &distributor.address)
+        .is_err());
+    router.set_merkle_distributor(&admin, &distributor.address);
+    assert_eq!(
+        vec![&e, e.events().all().last().unwrap()],
+        vec![
+            &e,
+            (
+                router.address.clone(),
+                (Symbol::new(&e, "set_merkle_distributor"),).into_val(&e),
+                (distributor.address.clone(),).into_val(&e),
+            )
+        ]
+    );
+    assert_eq!(
+        router.get_merkle_distributor(),
+        Some(distributor.address.clone())
+    );
+
+    assert_eq!(
+        router.claim_all_with_distributions(&user1, &Vec::new(&e), &claims),
+        (Vec::new(&e), Vec::from_array(&e, [300]))
+    );
+    assert_eq!(token.balance(&user1), 300);
+    assert!(distributor.is_claimed(&0, &0));
+    assert!(router
+        .try_claim_all_with_distributions(&user1, &Vec::new(&e), &claims)
+        .is_err());
+}
+
+#[test]
+fn test_pool_gauge() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.budget().reset_unlimited();
+
+    let mut admin1 = Address::generate(&e);
+    let mut admin2 = Address::generate(&e);
+
+    let mut token1 = create_token_contract(&e, &admin1);
+    let mut token2 = create_token_contract(&e, &admin2);
+    if &token2.address < &token1.address {
+        std::mem::swap(&mut token1, &mut token2);
+        std::mem::swap(&mut admin1, &mut admin2);
+    }
+    let tokens = Vec::from_array(&e, [token1.address.clone(), token2.address.clone()]);
+
+    let reward_admin = Address::generate(&e);
+    let admin = Address::generate(&e);
+    let user1 = Address::generate(&e);
+
+    let reward_token = create_token_contract(&e, &reward_admin);
+
+    let router = create_liqpool_router_contract(&e);
+    router.init_admin(&admin);
+    router.set_pool_hash(&install_liq_pool_hash(&e));
+    router.set_token_hash(&install_token_wasm(&e));
+    router.set_reward_token(&reward_token.address);
+    router.set_pools_plane(&admin, &create_plane_contract(&e).address);
+
+    let (pool_index, pool_address) = router.init_standard_pool(&user1, &tokens, &30);
+    assert_eq!(router.get_pool_gauge(&tokens, &pool_index), None);
+
+    // gauge staking another token can't be linked
+    let wrong_gauge = create_staking_gauge_contract(&e);
+    wrong_gauge.initialize(&admin, &token1.address, &reward_token.address);
+    assert!(router
+        .try_set_pool_gauge(&admin, &tokens, &pool_index, &wrong_gauge.address)
+        .is_err());
+
+    let share_token = router.share_id(&tokens, &pool_index);
+    let gauge = create_staking_gauge_contract(&e);
+    gauge.initialize(&admin, &share_token, &reward_token.address);
+    router.set_pool_gauge(&admin, &tokens, &pool_index, &gauge.address);
+    assert_eq!(
+        vec![&e, e.events().all().last().unwrap()],
+        vec![
+            &e,
+            (
+                router.address.clone(),
+                (
+                    Symbol::new(&e, "set_pool_gauge"),
+                    tokens.clone(),
+                    admin.clone()
+                )
+                    .into_val(&e),
+                (pool_address.clone(), gauge.address.clone()).into_val(&e),
+            )
+        ]
+    );
+    assert_eq!(
+        router.get_pool_gauge(&tokens, &pool_index),
+        Some(gauge.address.clone())
+    );
+
+    // liquidity providers stake pool shares into the gauge
+    token1.mint(&user1, &1000);
+    token2.mint(&user1, &1000);
+    token1.approve(&user1, &pool_address, &1000, &99999);
+    token2.approve(&user1, &pool_address, &1000, &99999);
+    router.deposit(
+        &user1,
+        &tokens,
+        &pool_index,
+        &Vec::from_array(&e, [100, 100]),
+    );
+    reward_token.mint(&gauge.address, &100_0000000);
+    gauge.set_rewards_config(&admin, &(e.ledger().timestamp() + 100), &1_0000000);
+    gauge.deposit(&user1, &100);
+    jump(&e, 100);
+    assert_eq!(gauge.claim(&user1), 100_0000000);
+    assert_eq!(reward_token.balance(&user1), 100_0000000);
+
+    router.remove_pool_gauge(&admin, &tokens, &pool_index);
+    assert_eq!(router.get_pool_gauge(&tokens, &pool_index), None);
+    assert!(router
+        .try_remove_pool_gauge(&admin, &tokens, &pool_index)
+        .is_err());
+}
+
+#[test]
+fn test_pool_gauge_claims_pool_rewards() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.budget().reset_unlimited();
+
+    let mut admin1 = Address::generate(&e);
+    let mut admin2 = Address::generate(&e);
+
+    let mut token1 = create_token_contract(&e, &admin1);
+    let mut token2 = create_token_contract(&e, &admin2);
+    if token2.address < token1.address {
+        std::mem::swap(&mut token1, &mut token2);
+        std::mem::swap(&mut admin1, &mut admin2);
+    }
+    let tokens = Vec::from_array(&e, [token1.address.clone(), token2.address.clone()]);
+
+    let reward_admin = Address::generate(&e);
+    let admin = Address::generate(&e);
+    let user1 = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    let reward_token = create_token_contract(&e, &reward_admin);
+
+    let router = create_liqpool_router_contract(&e);
+    router.init_admin(&admin);
+    router.set_pool_hash(&install_liq_pool_hash(&e));
+    router.set_token_hash(&install_token_wasm(&e));
+    router.set_reward_token(&reward_token.address);
+    router.set_pools_plane(&admin, &create_plane_contract(&e).address);
+
+    let (pool_index, pool_address) = router.init_standard_pool(&user1, &tokens, &30);
+    let (_, other_pool_address) = router.init_standard_pool(&user1, &tokens, &10);
+    let share_token = test_token::Client::new(&e, &router.share_id(&tokens, &pool_index));
+    let gauge = create_staking_gauge_contract(&e);
+    gauge.initialize(&admin, &share_token.address, &reward_token.address);
+    router.set_pool_gauge(&admin, &tokens, &pool_index, &gauge.address);
+
+    reward_token.mint(&pool_address, &1000_0000000);
+    router.set_rewards_config(
+        &admin,
+        &tokens,
+        &pool_index,
+        &e.ledger().timestamp().saturating_add(100),
+        &1_0000000,
+    );
+
+    token1.mint(&user1, &1000);
+    token2.mint(&user1, &1000);
+    token1.approve(&user1, &pool_address, &1000, &99999);
+    token2.approve(&user1, &pool_address, &1000, &99999);
+    router.deposit(
+        &user1,
+        &tokens,
+        &pool_index,
+        &Vec::from_array(&e, [100, 100]),
+    );
+    gauge.deposit(&user1, &100);
+    assert_eq!(share_token.balance(&gauge.address), 100);
+
+    // pool rewards of staked shares are accrued to the gauge
+    jump(&e, 50);
+    let pool = standard_pool::Client::new(&e, &pool_address);
+    assert_eq!(pool.get_user_reward(&user1), 0);
+    assert_eq!(pool.get_user_reward(&gauge.address), 50_0000000);
+
+    assert!(gauge
+        .try_claim_pool_rewards(&user1, &pool_address, &recipient)
+        .is_err());
+    assert!(gauge
+        .try_claim_pool_rewards(&admin, &other_pool_address, &recipient)
+        .is_err());
+    assert_eq!(
+        gauge.claim_pool_rewards(&admin, &pool_address, &recipient),
+        Vec::from_array(&e, [50_0000000])
+    );
+    assert_eq!(
+        vec![&e, e.events().all().last().unwrap()],
+        vec![
+            &e,
+            (
+                gauge.address.clone(),
+                (
+                    Symbol::new(&e, "claim_pool_rewards"),
+                    pool_address.clone(),
+                    recipient.clone()
+                )
+                    .into_val(&e),
+                (Vec::from_array(&e, [50_0000000_u128]),).into_val(&e),
+            )
+        ]
+    );
+    assert_eq!(reward_token.balance(&recipient), 50_0000000);
+    assert_eq!(reward_token.balance(&gauge.address), 0);
+
+    // pool rewards forwarded to the gauge fund its own rewards
+    jump(&e, 50);
+    assert_eq!(
+        gauge.claim_pool_rewards(&admin, &pool_address, &gauge.address),
+        Vec::from_array(&e, [50_0000000])
+    );
+    assert_eq!(reward_token.balance(&gauge.address), 50_0000000);
+    gauge.set_rewards_config(&admin, &(e.ledger().timestamp() + 50), &1_0000000);
+    jump(&e, 50);
+    assert_eq!(gauge.claim(&user1), 50_0000000);
+    assert_eq!(reward_token.balance(&user1), 50_0000000);
+}
+
+#[test]
+fn test_stop_and_reclaim_rewards() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.budget().reset_unlimited();
+
+    let mut admin1 = Address::generate(&e);
+    let mut admin2 = Address::generate(&e);
+
+    let mut token1 = create_token_contract(&e, &admin1);
+    let mut token2 = create_token_contract(&e, &admin2);
+    if &token2.address < &token1.address {
+        std::mem::swap(&mut token1, &mut token2);
+        std::mem::swap(&mut admin1, &mut admin2);
+    }
+    let tokens = Vec::from_array(&e, [token1.address.clone(), token2.address.clone()]);
+
+    let reward_admin = Address::generate(&e);
+    let admin = Address::generate(&e);
+    let user1 = Address::generate(&e);
+    let treasury = Address::generate(&e);
+
+    let reward_token = create_token_contract(&e, &reward_admin);
+
+    let router = create_liqpool_router_contract(&e);
+    router.init_admin(&admin);
+    router.set_pool_hash(&install_liq_pool_hash(&e));
+    router.set_token_hash(&install_token_wasm(&e));
+    router.set_reward_token(&reward_token.address);
+    router.set_pools_plane(&admin, &create_plane_contract(&e).address);
+
+    let (pool_index, pool_address) = router.init_standard_pool(&user1, &tokens, &30);
+    reward_token.mint(&pool_address, &1000_0000000);
+    token1.mint(&user1, &1000);
+    token2.mint(&user1, &1000);
+    token1.approve(&user1, &pool_address, &1000, &99999);
+    token2.approve(&user1, &pool_address, &1000, &99999);
+    router.deposit(
+        &user1,
+        &tokens,
+        &pool_index,
+        &Vec::from_array(&e, [100, 100]),
+    );
+
+    // pool can't pay 2 tokens per second for 10 minutes
+    let expired_at = e.ledger().timestamp() + 600;
+    assert!(router
+        .try_set_rewards_config(&admin, &tokens, &pool_index, &expired_at, &2_0000000)
+        .is_err());
+    router.set_rewards_config(&admin, &tokens, &pool_index, &expired_at, &1_0000000);
+    assert_eq!(
+        router.get_rewards_tps_per_share(&tokens, &pool_index, &0),
+        1_0000000 * 1_000_000_000_000 / 100
+    );
+    assert_eq!(
+        router.get_remaining_emission(&tokens, &pool_index, &0),
+        600_0000000
+    );
+
+    jump(&e, 100);
+    router.stop_rewards(&admin, &tokens, &pool_index, &0);
+    assert_eq!(
+        router.get_rewards_tps_per_share(&tokens, &pool_index, &0),
+        0
+    );
+    assert_eq!(router.get_remaining_emission(&tokens, &pool_index, &0), 0);
+    assert_eq!(
+        router
+            .get_rewards_info(&user1, &tokens, &pool_index)
+            .to_claim,
+        100_0000000
+    );
+    assert_eq!(
+        vec![&e, e.events().all().last().unwrap()],
+        vec![
+            &e,
+            (
+                router.address.clone(),
+                (
+                    Symbol::new(&e, "stop_rewards"),
+                    tokens.clone(),
+                    admin.clone()
+                )
+                    .into_val(&e),
+                (pool_address.clone(), 0_u32).into_val(&e),
+            )
+        ]
+    );
+    assert_eq!(
+        router.get_rewards_funding(&tokens, &pool_index, &0),
+        RewardsFunding {
+            committed: 100_0000000,
+            paid: 0,
+            available: 1000_0000000,
+        }
+    );
+
+    assert_eq!(
+        router.reclaim_unallocated_rewards(&admin, &tokens, &pool_index, &0, &treasury),
+        900_0000000
+    );
+    assert_eq!(
+        vec![&e, e.events().all().last().unwrap()],
+        vec![
+            &e,
+            (
+                router.address.clone(),
+                (
+                    Symbol::new(&e, "reclaim_unallocated_rewards"),
+                    tokens.clone(),
+                    admin.clone()
+                )
+                    .into_val(&e),
+                (
+                    pool_address.clone(),
+                    0_u32,
+                    treasury.clone(),
+                    900_0000000_u128
+                )
+                    .into_val(&e),
+            )
+        ]
+    );
+    assert_eq!(reward_token.balance(&treasury), 900_0000000);
+    assert_eq!(router.claim(&user1, &tokens, &pool_index), 100_0000000);
+}
+
+#[test]
+fn test_scheduled_rewards_config() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.budget().reset_unlimited();
+
+    let mut admin1 = Address::generate(&e);
+    let mut admin2 = Address::generate(&e);
+
+    let mut token1 = create_token_contract(&e, &admin1);
+    let mut token2 = create_token_contract(&e, &admin2);
+    if &token2.address < &token1.address {
+        std::mem::swap(&mut token1, &mut token2);
+        std::mem::swap(&mut admin1, &mut admin2);
+    }
+    let tokens = Vec::from_array(&e, [token1.address.clone(), token2.address.clone()]);
+
+    let reward_admin = Address::generate(&e);
+    let admin = Address::generate(&e);
+    let rewards_admin = Address::generate(&e);
+    let user1 = Address::generate(&e);
+
+    let reward_token = create_token_contract(&e, &reward_admin);
+
+    let router = create_liqpool_router_contract(&e);
+    router.init_admin(&admin);
+    router.set_pool_hash(&install_liq_pool_hash(&e));
+    router.set_token_hash(&install_token_wasm(&e));
+    router.set_reward_token(&reward_token.address);
+    router.set_pools_plane(&admin, &create_plane_contract(&e).address);
+    router.grant_role(&admin, &Role::RewardsAdmin, &rewards_admin);
+
+    let (pool_index, pool_address) = router.init_standard_pool(&user1, &tokens, &30);
+    reward_token.mint(&pool_address, &3600_0000000);
+    let start = e.ledger().timestamp() + 3600;
+    let config = ScheduledRewardConfig {
+        start_at: start,
+        expired_at: start + 3600,
+        tps: 1_0000000,
+    };
+
+    router.schedule_rewards_config(&rewards_admin, &tokens, &pool_index, &0, &config);
+    assert_eq!(
+        vec![&e, e.events().all().last().unwrap()],
+        vec![
+            &e,
+            (
+                router.address.clone(),
+                (
+                    Symbol::new(&e, "schedule_rewards_config"),
+                    tokens.clone(),
+                    rewards_admin.clone()
+                )
+                    .into_val(&e),
+                (
+                    pool_address.clone(),
+                    0_u32,
+                    config.start_at,
+                    config.expired_at,
+                    config.tps
+                )
+                    .into_val(&e),
+            )
+        ]
+    );
+    assert_eq!(
+        router.get_scheduled_rewards_configs(&tokens, &pool_index, &0),
+        Vec::from_array(&e, [config.clone()])
+    );
+    assert!(router
+        .try_schedule_rewards_config(
+            &user1,
+            &tokens,
+            &pool_index,
+            &0,
+            &ScheduledRewardConfig {
+                start_at: start + 3600,
+                expired_at: start + 7200,
+                tps: 1,
+            }
+        )
+        .is_err());
+
+    router.cancel_scheduled_rewards_config(&rewards_admin, &tokens, &pool_index, &0, &start);
+    assert_eq!(
+        router.get_scheduled_rewards_configs(&tokens, &pool_index, &0),
+        Vec::new(&e)
+    );
+}
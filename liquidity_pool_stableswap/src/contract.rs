@@ -9,10 +9,10 @@ use crate::pool_interface::{
 use crate::storage::{
     get_admin_actions_deadline, get_admin_fee, get_fee, get_future_a, get_future_a_time,
     get_future_admin_fee, get_future_fee, get_initial_a, get_initial_a_time, get_is_killed,
-    get_kill_deadline, get_plane, get_reserves, get_tokens, get_transfer_ownership_deadline,
-    has_plane, put_admin_actions_deadline, put_admin_fee, put_fee, put_future_a, put_future_a_time,
-    put_future_admin_fee, put_future_fee, put_initial_a, put_initial_a_time, put_is_killed,
-    put_kill_deadline, put_reserves, put_tokens, put_transfer_ownership_deadline, set_plane,
+    get_kill_deadline, get_plane, get_reserves, get_tokens, has_plane, put_admin_actions_deadline,
+    put_admin_fee, put_fee, put_future_a, put_future_a_time, put_future_admin_fee, put_future_fee,
+    put_initial_a, put_initial_a_time, put_is_killed, put_kill_deadline, put_reserves, put_tokens,
+    set_plane,
 };
 use crate::token::create_contract;
 use token_share::{
@@ -22,10 +22,18 @@ use token_share::{
 
 use crate::plane::update_plane;
 use crate::plane_interface::Plane;
-use crate::rewards::get_rewards_manager;
+use crate::rewards::{
+    claim_reward_to, get_reserved_balance, get_rewards_manager, get_stream_rewards,
+};
 use access_control::access::{AccessControl, AccessControlTrait};
-use cast::i128 as to_i128;
-use rewards::{storage::PoolRewardConfig, storage::RewardsStorageTrait};
+use access_control::interface::{RolesInterface, TransferableContract};
+use access_control::role::Role;
+use rewards::math::saturating_mul_div;
+use rewards::{
+    storage::Incentive, storage::RewardsFunding, storage::RewardsInfo,
+    storage::RewardsStorageTrait, storage::ScheduledRewardConfig, storage::VolumeEpoch,
+    storage::VolumeMiningConfig,
+};
 use soroban_sdk::token::Client as SorobanTokenClient;
 use soroban_sdk::{
     contract, contracterror, contractimpl, contractmeta, panic_with_error, symbol_short, Address,
@@ -135,15 +143,11 @@ impl LiquidityPoolTrait for LiquidityPool {
     ) -> u128 {
         user.require_auth();
 
-        // Before actual changes were made to the pool, update total rewards data and refresh user reward
+        // Before actual changes were made to the pool, update total rewards data and refresh user reward of every stream
         let rewards = get_rewards_manager(&e);
         let total_shares = get_total_shares(&e);
-        let pool_data = rewards.manager().update_rewards_data(total_shares);
         let user_shares = get_user_balance_shares(&e, &user);
-        rewards
-            .manager()
-            .update_user_reward(&pool_data, &user, user_shares);
-        rewards.storage().bump_user_reward_data(&user);
+        rewards.checkpoint_user(&user, total_shares, user_shares);
 
         if get_is_killed(&e) {
             panic!("is killed")
@@ -206,6 +210,11 @@ impl LiquidityPoolTrait for LiquidityPool {
             &(token_amount as i128),
         );
         burn_shares(&e, token_amount as i128);
+        rewards.update_working_balance(
+            &user,
+            get_total_shares(&e),
+            get_user_balance_shares(&e, &user),
+        );
 
         for i in 0..N_COINS as u32 {
             if amounts.get(i).unwrap() != 0 {
@@ -232,15 +241,11 @@ impl LiquidityPoolTrait for LiquidityPool {
     fn withdraw_one_coin(e: Env, user: Address, token_amount: u128, i: u32, min_amount: u128) {
         user.require_auth();
 
-        // Before actual changes were made to the pool, update total rewards data and refresh user reward
+        // Before actual changes were made to the pool, update total rewards data and refresh user reward of every stream
         let rewards = get_rewards_manager(&e);
         let total_shares = get_total_shares(&e);
-        let pool_data = rewards.manager().update_rewards_data(total_shares);
         let user_shares = get_user_balance_shares(&e, &user);
-        rewards
-            .manager()
-            .update_user_reward(&pool_data, &user, user_shares);
-        rewards.storage().bump_user_reward_data(&user);
+        rewards.checkpoint_user(&user, total_shares, user_shares);
 
         if get_is_killed(&e) {
             panic!("is killed")
@@ -268,6 +273,11 @@ impl LiquidityPoolTrait for LiquidityPool {
             &(token_amount as i128),
         );
         burn_shares(&e, token_amount as i128);
+        rewards.update_working_balance(
+            &user,
+            get_total_shares(&e),
+            get_user_balance_shares(&e, &user),
+        );
 
         let coins = get_tokens(&e);
         let token_client = SorobanTokenClient::new(&e, &coins.get(i).unwrap());
@@ -496,7 +506,7 @@ impl AdminInterfaceTrait for LiquidityPool {
     fn ramp_a(e: Env, admin: Address, future_a: u128, future_time: u64) {
         admin.require_auth();
         let access_control = AccessControl::new(&e);
-        access_control.check_admin(&admin);
+        access_control.check_role(Role::OperationsAdmin, &admin);
         if e.ledger().timestamp() < get_initial_a_time(&e) + MIN_RAMP_TIME {
             panic!("ramp time is less than minimal")
         };
@@ -525,7 +535,7 @@ impl AdminInterfaceTrait for LiquidityPool {
     fn stop_ramp_a(e: Env, admin: Address) {
         admin.require_auth();
         let access_control = AccessControl::new(&e);
-        access_control.check_admin(&admin);
+        access_control.check_role(Role::OperationsAdmin, &admin);
 
         let current_a = Self::a(e.clone());
         put_initial_a(&e, &current_a);
@@ -542,7 +552,7 @@ impl AdminInterfaceTrait for LiquidityPool {
     fn commit_new_fee(e: Env, admin: Address, new_fee: u32, new_admin_fee: u32) {
         admin.require_auth();
         let access_control = AccessControl::new(&e);
-        access_control.check_admin(&admin);
+        access_control.check_role(Role::OperationsAdmin, &admin);
 
         if get_admin_actions_deadline(&e) != 0 {
             panic!("active action")
@@ -563,7 +573,7 @@ impl AdminInterfaceTrait for LiquidityPool {
     fn apply_new_fee(e: Env, admin: Address) {
         admin.require_auth();
         let access_control = AccessControl::new(&e);
-        access_control.check_admin(&admin);
+        access_control.check_role(Role::OperationsAdmin, &admin);
 
         if e.ledger().timestamp() < get_admin_actions_deadline(&e) {
             panic!("insufficient time")
@@ -585,52 +595,11 @@ impl AdminInterfaceTrait for LiquidityPool {
     fn revert_new_parameters(e: Env, admin: Address) {
         admin.require_auth();
         let access_control = AccessControl::new(&e);
-        access_control.check_admin(&admin);
+        access_control.check_role(Role::OperationsAdmin, &admin);
 
         put_admin_actions_deadline(&e, &0);
     }
 
-    fn commit_transfer_ownership(e: Env, admin: Address, new_admin: Address) {
-        admin.require_auth();
-        let access_control = AccessControl::new(&e);
-        access_control.check_admin(&admin);
-
-        if get_transfer_ownership_deadline(&e) != 0 {
-            panic!("active transfer");
-        }
-
-        let deadline = e.ledger().timestamp() + ADMIN_ACTIONS_DELAY;
-        put_transfer_ownership_deadline(&e, &deadline);
-        access_control.set_future_admin(&new_admin);
-    }
-
-    fn apply_transfer_ownership(e: Env, admin: Address) {
-        admin.require_auth();
-        let access_control = AccessControl::new(&e);
-        access_control.check_admin(&admin);
-
-        if e.ledger().timestamp() < get_transfer_ownership_deadline(&e) {
-            panic!("insufficient time")
-        }
-        if get_transfer_ownership_deadline(&e) == 0 {
-            panic!("no active transfer")
-        }
-
-        put_transfer_ownership_deadline(&e, &0);
-        let future_admin = access_control
-            .get_future_admin()
-            .expect("Try get future admin");
-        access_control.set_admin(&future_admin);
-    }
-
-    fn revert_transfer_ownership(e: Env, admin: Address) {
-        admin.require_auth();
-        let access_control = AccessControl::new(&e);
-        access_control.check_admin(&admin);
-
-        put_transfer_ownership_deadline(&e, &0);
-    }
-
     fn admin_balances(e: Env, i: u32) -> u128 {
         let coins = get_tokens(&e);
         let token_client = SorobanTokenClient::new(&e, &coins.get(i).unwrap());
@@ -681,7 +650,7 @@ impl AdminInterfaceTrait for LiquidityPool {
     fn kill_me(e: Env, admin: Address) {
         admin.require_auth();
         let access_control = AccessControl::new(&e);
-        access_control.check_admin(&admin);
+        access_control.check_role(Role::EmergencyPauseAdmin, &admin);
 
         if get_kill_deadline(&e) <= e.ledger().timestamp() {
             panic!("deadline has passed")
@@ -692,7 +661,7 @@ impl AdminInterfaceTrait for LiquidityPool {
     fn unkill_me(e: Env, admin: Address) {
         admin.require_auth();
         let access_control = AccessControl::new(&e);
-        access_control.check_admin(&admin);
+        access_control.check_role(Role::EmergencyPauseAdmin, &admin);
 
         put_is_killed(&e, &false);
     }
@@ -708,6 +677,7 @@ impl ManagedLiquidityPool for LiquidityPool {
         a: u128,
         fee: u32,
         admin_fee: u32,
+        reward_precision: u128,
         reward_token: Address,
         reward_storage: Address,
         plane: Address,
@@ -716,7 +686,7 @@ impl ManagedLiquidityPool for LiquidityPool {
         // https://github.com/stellar/rs-soroban-env/issues/827
         Self::set_pools_plane(e.clone(), plane);
         Self::initialize(e.clone(), admin, token_wasm_hash, coins, a, fee, admin_fee);
-        Self::initialize_rewards_config(e.clone(), reward_token, reward_storage);
+        Self::initialize_rewards_config(e.clone(), reward_token, reward_storage, reward_precision);
     }
 }
 
@@ -754,6 +724,8 @@ impl LiquidityPoolInterfaceTrait for LiquidityPool {
             &"Pool Share Token".into_val(&e),
             &"POOL".into_val(&e),
         );
+        // checkpoint rewards on share transfers
+        LPToken::new(&e, &share_contract).set_rewards_checkpoint(&true);
         put_token_share(&e, share_contract);
         let initial_reserves = Vec::from_array(&e, [0_u128; N_COINS]);
         put_reserves(&e, &initial_reserves);
@@ -766,7 +738,6 @@ impl LiquidityPoolInterfaceTrait for LiquidityPool {
         put_fee(&e, &fee);
         put_kill_deadline(&e, &(e.ledger().timestamp() + KILL_DEADLINE_DT));
         put_admin_actions_deadline(&e, &0);
-        put_transfer_ownership_deadline(&e, &0);
         put_is_killed(&e, &false);
 
         let rewards = get_rewards_manager(&e);
@@ -807,15 +778,11 @@ impl LiquidityPoolInterfaceTrait for LiquidityPool {
             panic!("is killed")
         }
 
-        // Before actual changes were made to the pool, update total rewards data and refresh/initialize user reward
+        // Before actual changes were made to the pool, update total rewards data and refresh/initialize user reward of every stream
         let rewards = get_rewards_manager(&e);
         let total_shares = get_total_shares(&e);
-        let pool_data = rewards.manager().update_rewards_data(total_shares);
         let user_shares = get_user_balance_shares(&e, &user);
-        rewards
-            .manager()
-            .update_user_reward(&pool_data, &user, user_shares);
-        rewards.storage().bump_user_reward_data(&user);
+        rewards.checkpoint_user(&user, total_shares, user_shares);
 
         let mut fees: Vec<u128> = Vec::from_array(&e, [0; N_COINS]);
         let fee = get_fee(&e) as u128 * N_COINS as u128 / (4 * (N_COINS as u128 - 1));
@@ -900,7 +867,12 @@ impl LiquidityPoolInterfaceTrait for LiquidityPool {
         // }
 
         // Mint pool tokens
-        mint_shares(&e, user, mint_amount as i128);
+        mint_shares(&e, user.clone(), mint_amount as i128);
+        rewards.update_working_balance(
+            &user,
+            get_total_shares(&e),
+            get_user_balance_shares(&e, &user),
+        );
 
         // update plane data for every pool update
         update_plane(&e);
@@ -966,6 +938,14 @@ impl LiquidityPoolInterfaceTrait for LiquidityPool {
         let token_client = SorobanTokenClient::new(&e, &coins.get(out_idx).unwrap());
         token_client.transfer(&e.current_contract_address(), &user, &(dy as i128));
 
+        // volume mining points are the swap fee. coins are pegged, so fee in any of them counts the same
+        let rewards = get_rewards_manager(&e);
+        rewards.add_volume_points(
+            &user,
+            saturating_mul_div(&e, dy_fee, PRECISION, rates[out_idx as usize]),
+            get_reserved_balance(&e, &rewards),
+        );
+
         // update plane data for every pool update
         update_plane(&e);
 
@@ -983,15 +963,11 @@ impl LiquidityPoolInterfaceTrait for LiquidityPool {
             panic!("wrong min_amounts vector size")
         }
 
-        // Before actual changes were made to the pool, update total rewards data and refresh user reward
+        // Before actual changes were made to the pool, update total rewards data and refresh user reward of every stream
         let rewards = get_rewards_manager(&e);
         let total_shares = get_total_shares(&e);
-        let pool_data = rewards.manager().update_rewards_data(total_shares);
         let user_shares = get_user_balance_shares(&e, &user);
-        rewards
-            .manager()
-            .update_user_reward(&pool_data, &user, user_shares);
-        rewards.storage().bump_user_reward_data(&user);
+        rewards.checkpoint_user(&user, total_shares, user_shares);
 
         let total_supply = get_total_shares(&e);
         let mut amounts = Vec::from_array(&e, [0; N_COINS]);
@@ -1020,6 +996,11 @@ impl LiquidityPoolInterfaceTrait for LiquidityPool {
             &(share_amount as i128),
         );
         burn_shares(&e, share_amount as i128);
+        rewards.update_working_balance(
+            &user,
+            get_total_shares(&e),
+            get_user_balance_shares(&e, &user),
+        );
 
         // update plane data for every pool update
         update_plane(&e);
@@ -1040,6 +1021,10 @@ impl LiquidityPoolInterfaceTrait for LiquidityPool {
     }
 }
 
+access_control::generate_transferable_contract!(LiquidityPool);
+
+access_control::generate_roles_interface!(LiquidityPool);
+
 #[contractimpl]
 impl UpgradeableContractTrait for LiquidityPool {
     fn version() -> u32 {
@@ -1055,13 +1040,13 @@ impl UpgradeableContractTrait for LiquidityPool {
 
 #[contractimpl]
 impl RewardsTrait for LiquidityPool {
-    fn initialize_rewards_config(e: Env, reward_token: Address, reward_storage: Address) {
-        let rewards = get_rewards_manager(&e);
-        if rewards.storage().has_reward_token() {
-            panic!("rewards config already initialized")
-        }
-        rewards.storage().put_reward_token(reward_token);
-        rewards.storage().put_reward_storage(reward_storage);
+    fn initialize_rewards_config(
+        e: Env,
+        reward_token: Address,
+        reward_storage: Address,
+        reward_precision: u128,
+    ) {
+        get_rewards_manager(&e).initialize_config(reward_token, reward_storage, reward_precision);
     }
 
     fn set_rewards_config(
@@ -1070,71 +1055,351 @@ impl RewardsTrait for LiquidityPool {
         expired_at: u64, // timestamp
         tps: u128,       // value with 7 decimal places. example: 600_0000000
     ) {
+        Self::set_stream_rewards_config(e, admin, 0, expired_at, tps);
+    }
+
+    fn get_rewards_info(e: Env, user: Address) -> RewardsInfo {
+        get_rewards_manager(&e).get_rewards_info(
+            &user,
+            get_total_shares(&e),
+            get_user_balance_shares(&e, &user),
+        )
+    }
+
+    fn get_user_reward(e: Env, user: Address) -> u128 {
+        get_rewards_manager(&e).get_user_reward(
+            &user,
+            get_total_shares(&e),
+            get_user_balance_shares(&e, &user),
+        )
+    }
+
+    fn claim(e: Env, user: Address) -> u128 {
+        claim_reward_to(&e, &user, &user)
+    }
+
+    fn claim_to(e: Env, user: Address, recipient: Address) -> u128 {
+        user.require_auth();
+        claim_reward_to(&e, &user, &recipient)
+    }
+
+    fn claim_as_operator(e: Env, operator: Address, user: Address, recipient: Address) -> u128 {
+        // pool admin (router) checks operator approval on its own side
+        if AccessControl::new(&e).get_admin() == Some(operator.clone()) {
+            operator.require_auth();
+        } else {
+            get_rewards_manager(&e).require_claim_allowed(&operator, &user);
+        }
+        claim_reward_to(&e, &user, &recipient)
+    }
+
+    fn set_claim_operator(e: Env, user: Address, operator: Address, approved: bool) {
+        user.require_auth();
+        get_rewards_manager(&e).set_claim_operator(&user, &operator, approved);
+    }
+
+    fn is_claim_operator(e: Env, user: Address, operator: Address) -> bool {
+        get_rewards_manager(&e).is_claim_operator(&user, &operator)
+    }
+
+    fn get_reward_token(e: Env) -> Address {
+        let rewards = get_rewards_manager(&e);
+        rewards.storage().get_reward_token()
+    }
+
+    fn get_reward_storage(e: Env) -> Address {
+        let rewards = get_rewards_manager(&e);
+        rewards.storage().get_reward_storage()
+    }
+
+    fn collect_reward(e: Env, user: Address) -> u128 {
+        let rewards = get_rewards_manager(&e);
+        let reward_storage = rewards.storage().get_reward_storage();
+        reward_storage.require_auth();
+        // reward storage should be the router trusted by the pool, not an arbitrary account
+        AccessControl::new(&e).check_role(Role::RewardsAdmin, &reward_storage);
+
+        rewards.take_reward(
+            &user,
+            get_total_shares(&e),
+            get_user_balance_shares(&e, &user),
+        )
+    }
+
+    fn add_reward_stream(
+        e: Env,
+        admin: Address,
+        reward_token: Address,
+        reward_storage: Address,
+    ) -> u32 {
         admin.require_auth();
-        let access_control = AccessControl::new(&e);
-        access_control.check_admin(&admin);
+        AccessControl::new(&e).check_role(Role::RewardsAdmin, &admin);
+
+        let rewards = get_rewards_manager(&e);
+        bump_instance(&e);
+        rewards.add_stream(reward_token, reward_storage)
+    }
+
+    fn set_stream_rewards_config(e: Env, admin: Address, stream: u32, expired_at: u64, tps: u128) {
+        admin.require_auth();
+        AccessControl::new(&e).check_role(Role::RewardsAdmin, &admin);
 
         if expired_at < e.ledger().timestamp() {
             panic!("cannot set expiration time to the past");
         }
 
+        let rewards = get_stream_rewards(&e, stream);
+        bump_instance(&e);
+        rewards.set_config(
+            expired_at,
+            tps,
+            get_total_shares(&e),
+            get_reserved_balance(&e, &rewards),
+        );
+    }
+
+    fn get_all_rewards_info(e: Env, user: Address) -> Vec<(Address, RewardsInfo)> {
+        get_rewards_manager(&e).get_all_rewards_info(
+            &user,
+            get_total_shares(&e),
+            get_user_balance_shares(&e, &user),
+        )
+    }
+
+    fn get_all_user_rewards(e: Env, user: Address) -> Vec<u128> {
+        get_rewards_manager(&e).get_all_user_rewards(
+            &user,
+            get_total_shares(&e),
+            get_user_balance_shares(&e, &user),
+        )
+    }
+
+    fn claim_all_rewards(e: Env, user: Address) -> Vec<u128> {
+        get_rewards_manager(&e).claim_all(
+            &user,
+            get_total_shares(&e),
+            get_user_balance_shares(&e, &user),
+        )
+    }
+
+    fn set_voting_escrow(e: Env, admin: Address, voting_escrow: Address) {
+        admin.require_auth();
+        AccessControl::new(&e).check_role(Role::RewardsAdmin, &admin);
+
         let rewards = get_rewards_manager(&e);
-        let total_shares = get_total_shares(&e);
-        rewards.manager().update_rewards_data(total_shares);
+        bump_instance(&e);
+        rewards.set_voting_escrow(voting_escrow, get_total_shares(&e));
+    }
+
+    fn get_voting_escrow(e: Env) -> Option<Address> {
+        get_rewards_manager(&e).get_voting_escrow()
+    }
+
+    fn set_reward_precision(e: Env, admin: Address, precision: u128) {
+        admin.require_auth();
+        AccessControl::new(&e).check_role(Role::RewardsAdmin, &admin);
 
-        let config = PoolRewardConfig { tps, expired_at };
+        let rewards = get_rewards_manager(&e);
         bump_instance(&e);
-        rewards.storage().set_pool_reward_config(&config);
+        rewards.set_precision(precision, get_total_shares(&e));
+    }
+
+    fn get_reward_precision(e: Env) -> u128 {
+        get_rewards_manager(&e).get_precision()
+    }
+
+    fn kick(e: Env, user: Address) {
+        get_rewards_manager(&e).kick(
+            &user,
+            get_total_shares(&e),
+            get_user_balance_shares(&e, &user),
+        );
     }
 
-    fn get_rewards_info(e: Env, user: Address) -> Map<Symbol, i128> {
+    fn get_working_balance(e: Env, user: Address) -> u128 {
+        get_rewards_manager(&e).get_working_balance(
+            &user,
+            get_total_shares(&e),
+            get_user_balance_shares(&e, &user),
+        )
+    }
+
+    fn get_working_supply(e: Env) -> u128 {
+        get_rewards_manager(&e).working_supply(get_total_shares(&e))
+    }
+
+    fn enable_rewards_checkpoint(e: Env, admin: Address) {
+        admin.require_auth();
+        AccessControl::new(&e).check_role(Role::RewardsAdmin, &admin);
+
+        bump_instance(&e);
+        LPToken::new(&e, &get_token_share(&e)).set_rewards_checkpoint(&true);
+    }
+
+    fn checkpoint_reward(e: Env, user: Address, shares_before: u128, shares_after: u128) {
+        get_token_share(&e).require_auth();
+
         let rewards = get_rewards_manager(&e);
-        let config = rewards.storage().get_pool_reward_config();
         let total_shares = get_total_shares(&e);
-        let pool_data = rewards.manager().update_rewards_data(total_shares);
-        let user_shares = get_user_balance_shares(&e, &user);
-        let user_data = rewards
-            .manager()
-            .update_user_reward(&pool_data, &user, user_shares);
-        let mut result = Map::new(&e);
-        result.set(symbol_short!("tps"), to_i128(config.tps).unwrap());
-        result.set(symbol_short!("exp_at"), to_i128(config.expired_at));
-        result.set(
-            symbol_short!("acc"),
-            to_i128(pool_data.accumulated).unwrap(),
-        );
-        result.set(symbol_short!("last_time"), to_i128(pool_data.last_time));
-        result.set(
-            symbol_short!("pool_acc"),
-            to_i128(user_data.pool_accumulated).unwrap(),
-        );
-        result.set(symbol_short!("block"), to_i128(pool_data.block));
-        result.set(symbol_short!("usr_block"), to_i128(user_data.last_block));
-        result.set(
-            symbol_short!("to_claim"),
-            to_i128(user_data.to_claim).unwrap(),
+        rewards.checkpoint_user(&user, total_shares, shares_before);
+        rewards.update_working_balance(&user, total_shares, shares_after);
+    }
+
+    fn schedule_rewards_config(
+        e: Env,
+        admin: Address,
+        stream: u32,
+        start_at: u64,
+        expired_at: u64,
+        tps: u128,
+    ) {
+        admin.require_auth();
+        AccessControl::new(&e).check_role(Role::RewardsAdmin, &admin);
+
+        let rewards = get_stream_rewards(&e, stream);
+        bump_instance(&e);
+        rewards.schedule_config(
+            start_at,
+            expired_at,
+            tps,
+            get_reserved_balance(&e, &rewards),
         );
-        result
     }
 
-    fn get_user_reward(e: Env, user: Address) -> u128 {
+    fn cancel_scheduled_rewards_config(e: Env, admin: Address, stream: u32, start_at: u64) {
+        admin.require_auth();
+        AccessControl::new(&e).check_role(Role::RewardsAdmin, &admin);
+
+        let rewards = get_stream_rewards(&e, stream);
+        bump_instance(&e);
+        rewards.manager().cancel_scheduled_rewards_config(start_at);
+    }
+
+    fn stop_rewards(e: Env, admin: Address, stream: u32) {
+        admin.require_auth();
+        AccessControl::new(&e).check_role(Role::RewardsAdmin, &admin);
+
+        let rewards = get_stream_rewards(&e, stream);
+        bump_instance(&e);
+        rewards.stop(get_total_shares(&e));
+    }
+
+    fn get_rewards_funding(e: Env, stream: u32) -> RewardsFunding {
+        let rewards = get_stream_rewards(&e, stream);
+        rewards.get_funding(get_reserved_balance(&e, &rewards))
+    }
+
+    fn get_rewards_tps_per_share(e: Env, stream: u32) -> u128 {
+        get_stream_rewards(&e, stream).get_tps_per_share(get_total_shares(&e))
+    }
+
+    fn get_remaining_emission(e: Env, stream: u32) -> u128 {
+        get_stream_rewards(&e, stream).get_remaining_emission(get_total_shares(&e))
+    }
+
+    fn deposit_incentive(
+        e: Env,
+        depositor: Address,
+        token: Address,
+        amount: u128,
+        duration: u64,
+    ) -> u32 {
+        depositor.require_auth();
+        if get_tokens(&e).contains(&token) {
+            panic!("pool tokens can't be used as incentive")
+        }
+
+        SorobanTokenClient::new(&e, &token).transfer(
+            &depositor,
+            &e.current_contract_address(),
+            &(amount as i128),
+        );
         let rewards = get_rewards_manager(&e);
-        let total_shares = get_total_shares(&e);
-        let user_shares = get_user_balance_shares(&e, &user);
-        rewards
-            .manager()
-            .get_amount_to_claim(&user, total_shares, user_shares)
+        bump_instance(&e);
+        rewards.add_incentive(&depositor, token, amount, duration, get_total_shares(&e))
     }
 
-    fn claim(e: Env, user: Address) -> u128 {
+    fn set_incentive_token(e: Env, admin: Address, token: Address, min_amount: Option<u128>) {
+        admin.require_auth();
+        AccessControl::new(&e).check_role(Role::RewardsAdmin, &admin);
+
+        bump_instance(&e);
+        get_rewards_manager(&e).set_incentive_token(&token, min_amount);
+    }
+
+    fn get_incentive_min_amount(e: Env, token: Address) -> Option<u128> {
+        get_rewards_manager(&e)
+            .storage()
+            .get_incentive_min_amount(&token)
+    }
+
+    fn settle_incentive(e: Env, stream: u32) -> u128 {
+        let (_, leftover) = get_stream_rewards(&e, stream).settle_incentive(get_total_shares(&e));
+        leftover
+    }
+
+    fn get_incentive(e: Env, stream: u32) -> Option<Incentive> {
+        get_stream_rewards(&e, stream).storage().get_incentive()
+    }
+
+    fn reclaim_unallocated(e: Env, admin: Address, stream: u32, to: Address) -> u128 {
+        admin.require_auth();
+        AccessControl::new(&e).check_role(Role::RewardsAdmin, &admin);
+
+        let rewards = get_stream_rewards(&e, stream);
+        rewards.reclaim_unallocated(
+            &to,
+            get_total_shares(&e),
+            get_reserved_balance(&e, &rewards),
+        )
+    }
+
+    fn get_scheduled_rewards_configs(e: Env, stream: u32) -> Vec<ScheduledRewardConfig> {
+        get_stream_rewards(&e, stream).get_scheduled_configs(get_total_shares(&e))
+    }
+
+    fn set_volume_mining_config(e: Env, admin: Address, epoch_length: u64, budget: u128) {
+        admin.require_auth();
+        AccessControl::new(&e).check_role(Role::RewardsAdmin, &admin);
+
         let rewards = get_rewards_manager(&e);
-        let total_shares = get_total_shares(&e);
-        let user_shares = get_user_balance_shares(&e, &user);
-        let reward = rewards
-            .manager()
-            .claim_reward(&user, total_shares, user_shares);
-        rewards.storage().bump_user_reward_data(&user);
-        reward
+        bump_instance(&e);
+        rewards.set_volume_mining_config(epoch_length, budget, get_reserved_balance(&e, &rewards));
+    }
+
+    fn get_volume_mining_config(e: Env) -> Option<VolumeMiningConfig> {
+        get_rewards_manager(&e).volume_mining().get_config()
+    }
+
+    fn get_volume_epochs_count(e: Env) -> u32 {
+        get_rewards_manager(&e).volume_mining().epochs_count()
+    }
+
+    fn get_volume_epoch(e: Env, epoch: u32) -> Option<VolumeEpoch> {
+        get_rewards_manager(&e).volume_mining().get_epoch(epoch)
+    }
+
+    fn get_user_volume_points(e: Env, user: Address, epoch: u32) -> u128 {
+        get_rewards_manager(&e)
+            .volume_mining()
+            .get_user_points(&user, epoch)
+    }
+
+    fn get_user_volume_reward(e: Env, user: Address, epoch: u32) -> u128 {
+        get_rewards_manager(&e)
+            .volume_mining()
+            .get_user_reward(&user, epoch)
+    }
+
+    fn claim_volume_reward(e: Env, user: Address, epoch: u32) -> u128 {
+        user.require_auth();
+        bump_instance(&e);
+        get_rewards_manager(&e).volume_mining().claim(&user, epoch)
+    }
+
+    fn sweep_volume_epoch(e: Env, epoch: u32) -> u128 {
+        bump_instance(&e);
+        get_rewards_manager(&e).volume_mining().sweep(epoch)
     }
 }
 
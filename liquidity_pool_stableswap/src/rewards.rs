@@ -1,5 +1,8 @@
+use crate::storage::{get_reserves, get_tokens};
+use rewards::storage::RewardsStorageTrait;
 use rewards::Rewards;
-use soroban_sdk::Env;
+use soroban_sdk::{Address, Env};
+use token_share::{get_total_shares, get_user_balance_shares};
 
 #[cfg(not(test))]
 pub(crate) const PAGE_SIZE: u64 = 1000;
@@ -10,3 +13,34 @@ pub(crate) const PAGE_SIZE: u64 = 5;
 pub(crate) fn get_rewards_manager(e: &Env) -> Rewards {
     Rewards::new(e, PAGE_SIZE)
 }
+
+// rewards of existing stream
+pub(crate) fn get_stream_rewards(e: &Env, stream: u32) -> Rewards {
+    let rewards = get_rewards_manager(e);
+    if stream >= rewards.streams_count() {
+        panic!("reward stream doesn't exist")
+    }
+    rewards.stream(stream)
+}
+
+// amount of reward token held by the pool as liquidity reserves
+pub(crate) fn get_reserved_balance(e: &Env, rewards: &Rewards) -> u128 {
+    let reward_token = rewards.storage().get_reward_token();
+    let reserves = get_reserves(e);
+    for (i, token) in get_tokens(e).iter().enumerate() {
+        if token == reward_token {
+            return reserves.get(i as u32).unwrap();
+        }
+    }
+    0
+}
+
+// claim primary stream reward of the user, transferring it to recipient
+pub(crate) fn claim_reward_to(e: &Env, user: &Address, recipient: &Address) -> u128 {
+    get_rewards_manager(e).claim_reward_to(
+        user,
+        recipient,
+        get_total_shares(e),
+        get_user_balance_shares(e, user),
+    )
+}
@@ -16,7 +16,6 @@ enum DataKey {
     AdminFee,
     FutureAdminFee,
     AdminActionsDeadline,
-    TransferOwnershipDeadline,
     KillDeadline,
     IsKilled,
     Plane,
@@ -182,22 +181,6 @@ pub fn put_admin_actions_deadline(e: &Env, value: &u64) {
         .set(&DataKey::AdminActionsDeadline, value);
 }
 
-// transfer_ownership_deadline
-pub fn get_transfer_ownership_deadline(e: &Env) -> u64 {
-    bump_instance(e);
-    e.storage()
-        .instance()
-        .get(&DataKey::TransferOwnershipDeadline)
-        .expect("Please initialize transfer_ownership_deadline")
-}
-
-pub fn put_transfer_ownership_deadline(e: &Env, value: &u64) {
-    bump_instance(e);
-    e.storage()
-        .instance()
-        .set(&DataKey::TransferOwnershipDeadline, value);
-}
-
 // kill_deadline
 pub fn get_kill_deadline(e: &Env) -> u64 {
     bump_instance(e);
@@ -2,6 +2,7 @@
 extern crate std;
 
 use crate::LiquidityPoolClient;
+use access_control::role::Role;
 
 use crate::plane::{pool_plane, PoolPlaneClient};
 use crate::pool_constants::{ADMIN_ACTIONS_DELAY, MIN_RAMP_TIME};
@@ -40,6 +41,7 @@ fn create_liqpool_contract<'a>(
         &a,
         &fee,
         &admin_fee,
+        &1_000_000_000_000,
         token_reward,
         &liqpool.address,
         plane,
@@ -256,6 +258,81 @@ fn test_kill() {
     );
 }
 
+#[cfg(feature = "tokens_2")]
+#[test]
+fn test_kill_by_emergency_pause_admin() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.budget().reset_unlimited();
+
+    let admin1 = Address::generate(&e);
+    let admin2 = Address::generate(&e);
+
+    let token1 = create_token_contract(&e, &admin1);
+    let token2 = create_token_contract(&e, &admin2);
+    let token_reward = create_token_contract(&e, &admin1);
+    let user1 = Address::generate(&e);
+    let pause_admin = Address::generate(&e);
+    let plane = create_plane_contract(&e);
+    let liqpool = create_liqpool_contract(
+        &e,
+        &user1,
+        &install_token_wasm(&e),
+        &Vec::from_array(&e, [token1.address.clone(), token2.address.clone()]),
+        10,
+        0,
+        0,
+        &token_reward.address,
+        &plane.address,
+    );
+
+    assert!(liqpool.has_role(&Role::EmergencyPauseAdmin, &user1));
+    assert!(!liqpool.has_role(&Role::EmergencyPauseAdmin, &pause_admin));
+    liqpool.grant_role(&user1, &Role::EmergencyPauseAdmin, &pause_admin);
+    assert!(liqpool.has_role(&Role::EmergencyPauseAdmin, &pause_admin));
+    assert!(!liqpool.has_role(&Role::OperationsAdmin, &pause_admin));
+
+    liqpool.kill_me(&pause_admin);
+    liqpool.unkill_me(&pause_admin);
+
+    liqpool.renounce_role(&pause_admin, &Role::EmergencyPauseAdmin);
+    assert!(!liqpool.has_role(&Role::EmergencyPauseAdmin, &pause_admin));
+}
+
+#[cfg(feature = "tokens_2")]
+#[test]
+#[should_panic(expected = "Error(Contract, #103)")]
+fn test_ramp_a_by_emergency_pause_admin() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.budget().reset_unlimited();
+
+    let admin1 = Address::generate(&e);
+    let admin2 = Address::generate(&e);
+
+    let token1 = create_token_contract(&e, &admin1);
+    let token2 = create_token_contract(&e, &admin2);
+    let token_reward = create_token_contract(&e, &admin1);
+    let user1 = Address::generate(&e);
+    let pause_admin = Address::generate(&e);
+    let plane = create_plane_contract(&e);
+    let liqpool = create_liqpool_contract(
+        &e,
+        &user1,
+        &install_token_wasm(&e),
+        &Vec::from_array(&e, [token1.address.clone(), token2.address.clone()]),
+        10,
+        0,
+        0,
+        &token_reward.address,
+        &plane.address,
+    );
+
+    liqpool.grant_role(&user1, &Role::EmergencyPauseAdmin, &pause_admin);
+    jump(&e, MIN_RAMP_TIME);
+    liqpool.ramp_a(&pause_admin, &20, &(e.ledger().timestamp() + MIN_RAMP_TIME));
+}
+
 #[cfg(feature = "tokens_2")]
 #[test]
 #[should_panic(expected = "initial deposit requires all coins")]
@@ -1623,3 +1700,159 @@ fn test_ramp_a() {
     jump(&e, MIN_RAMP_TIME);
     assert_eq!(liqpool.a(), 99);
 }
+
+#[test]
+#[cfg(feature = "tokens_2")]
+fn test_multiple_reward_streams() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.budget().reset_unlimited();
+
+    let admin1 = Address::generate(&e);
+    let admin2 = Address::generate(&e);
+
+    let token1 = create_token_contract(&e, &admin1);
+    let token2 = create_token_contract(&e, &admin2);
+    let token1_admin_client = get_token_admin_client(&e, &token1.address);
+    let token2_admin_client = get_token_admin_client(&e, &token2.address);
+    let token_reward = create_token_contract(&e, &admin1);
+    let token_reward_admin_client = get_token_admin_client(&e, &token_reward.address);
+    let token_partner = create_token_contract(&e, &admin2);
+    let token_partner_admin_client = get_token_admin_client(&e, &token_partner.address);
+
+    let user1 = Address::generate(&e);
+    let partner = Address::generate(&e);
+    let plane = create_plane_contract(&e);
+    let liqpool = create_liqpool_contract(
+        &e,
+        &user1,
+        &install_token_wasm(&e),
+        &Vec::from_array(&e, [token1.address.clone(), token2.address.clone()]),
+        10,
+        0,
+        0,
+        &token_reward.address,
+        &plane.address,
+    );
+
+    token_reward_admin_client.mint(&liqpool.address, &1_000_000_0000000);
+    token_reward.approve(
+        &liqpool.address,
+        &liqpool.address,
+        &1_000_000_0000000,
+        &99999,
+    );
+    let reward_1_tps = 10_5000000_u128;
+    let total_reward_1 = reward_1_tps * 60;
+    liqpool.set_rewards_config(
+        &user1,
+        &e.ledger().timestamp().saturating_add(60),
+        &reward_1_tps,
+    );
+
+    // partner stream is paid from partner's account
+    let partner_tps = 3_0000000_u128;
+    let total_partner_reward = partner_tps * 60;
+    token_partner_admin_client.mint(&partner, &(total_partner_reward as i128));
+    token_partner.approve(
+        &partner,
+        &liqpool.address,
+        &(total_partner_reward as i128),
+        &99999,
+    );
+    let stream = liqpool.add_reward_stream(&user1, &token_partner.address, &partner);
+    assert_eq!(stream, 1);
+    liqpool.set_stream_rewards_config(
+        &user1,
+        &stream,
+        &e.ledger().timestamp().saturating_add(60),
+        &partner_tps,
+    );
+
+    token1_admin_client.mint(&user1, &1000);
+    token2_admin_client.mint(&user1, &1000);
+    token1.approve(&user1, &liqpool.address, &1000, &99999);
+    token2.approve(&user1, &liqpool.address, &1000, &99999);
+
+    // 10 seconds passed since config, user depositing
+    jump(&e, 10);
+    liqpool.deposit(&user1, &Vec::from_array(&e, [100, 100]));
+
+    // 30 seconds passed, half of every stream reward is available for the user
+    jump(&e, 30);
+    assert_eq!(
+        liqpool.claim_all_rewards(&user1),
+        Vec::from_array(&e, [total_reward_1 / 2, total_partner_reward / 2])
+    );
+    assert_eq!(token_reward.balance(&user1) as u128, total_reward_1 / 2);
+    assert_eq!(
+        token_partner.balance(&user1) as u128,
+        total_partner_reward / 2
+    );
+    assert_eq!(
+        token_partner.balance(&partner) as u128,
+        total_partner_reward / 2
+    );
+}
+
+#[test]
+#[cfg(feature = "tokens_2")]
+fn test_share_transfer_rewards_checkpoint() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.budget().reset_unlimited();
+
+    let admin1 = Address::generate(&e);
+    let admin2 = Address::generate(&e);
+
+    let token1 = create_token_contract(&e, &admin1);
+    let token2 = create_token_contract(&e, &admin2);
+    let token1_admin_client = get_token_admin_client(&e, &token1.address);
+    let token2_admin_client = get_token_admin_client(&e, &token2.address);
+    let token_reward = create_token_contract(&e, &admin1);
+    let token_reward_admin_client = get_token_admin_client(&e, &token_reward.address);
+
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    let plane = create_plane_contract(&e);
+    let liqpool = create_liqpool_contract(
+        &e,
+        &user1,
+        &install_token_wasm(&e),
+        &Vec::from_array(&e, [token1.address.clone(), token2.address.clone()]),
+        10,
+        0,
+        0,
+        &token_reward.address,
+        &plane.address,
+    );
+    let token_share = SorobanTokenClient::new(&e, &liqpool.share_id());
+
+    token_reward_admin_client.mint(&liqpool.address, &1_000_000_0000000);
+    let reward_1_tps = 10_5000000_u128;
+    let total_reward_1 = reward_1_tps * 60;
+    liqpool.set_rewards_config(
+        &user1,
+        &e.ledger().timestamp().saturating_add(60),
+        &reward_1_tps,
+    );
+
+    token1_admin_client.mint(&user1, &1000);
+    token2_admin_client.mint(&user1, &1000);
+    token1.approve(&user1, &liqpool.address, &1000, &99999);
+    token2.approve(&user1, &liqpool.address, &1000, &99999);
+    liqpool.deposit(&user1, &Vec::from_array(&e, [100, 100]));
+
+    // half of shares transferred in the middle of distribution
+    jump(&e, 30);
+    let shares = token_share.balance(&user1);
+    token_share.transfer(&user1, &user2, &(shares / 2));
+    jump(&e, 30);
+
+    assert_approx_eq_abs(
+        liqpool.claim(&user1),
+        total_reward_1 / 2 + total_reward_1 / 4,
+        1000,
+    );
+    assert_approx_eq_abs(liqpool.claim(&user2), total_reward_1 / 4, 1000);
+}
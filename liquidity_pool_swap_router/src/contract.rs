@@ -3,6 +3,7 @@ use crate::plane::{parse_stableswap_data, parse_standard_data, PoolPlaneClient};
 use crate::storage::{get_plane, set_plane};
 use crate::{stableswap_pool, standard_pool};
 use access_control::access::{AccessControl, AccessControlTrait};
+use access_control::interface::TransferableContract;
 use soroban_sdk::{contract, contractimpl, symbol_short, Address, BytesN, Env, Symbol, Vec};
 
 #[contract]
@@ -109,3 +110,5 @@ impl UpgradeableContract for LiquidityPoolSwapRouter {
         e.deployer().update_current_contract_wasm(new_wasm_hash);
     }
 }
+
+access_control::generate_transferable_contract!(LiquidityPoolSwapRouter);
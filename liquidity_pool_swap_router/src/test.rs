@@ -2,7 +2,7 @@
 extern crate std;
 
 use crate::{contract::LiquidityPoolSwapRouter, LiquidityPoolSwapRouterClient};
-use soroban_sdk::testutils::Address as _;
+use soroban_sdk::testutils::{Address as _, Ledger};
 use soroban_sdk::{symbol_short, Address, Env, Vec};
 
 fn create_contract<'a>(e: &Env) -> LiquidityPoolSwapRouterClient<'a> {
@@ -178,3 +178,34 @@ fn test_bad_address() {
     assert_eq!(best_pool, address1);
     assert_eq!(best_result, 0);
 }
+
+#[test]
+fn test_transfer_ownership() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().with_mut(|li| li.timestamp = 1_700_000_000);
+
+    let admin_original = Address::generate(&e);
+    let admin_new = Address::generate(&e);
+    let router = create_contract(&e);
+    router.init_admin(&admin_original);
+
+    router.commit_transfer_ownership(&admin_original, &admin_new);
+    assert_eq!(router.get_future_admin(), Some(admin_new.clone()));
+    router.revert_transfer_ownership(&admin_original);
+    assert_eq!(router.get_future_admin(), None);
+    assert_eq!(router.get_transfer_ownership_deadline(), 0);
+
+    assert!(router
+        .try_revert_transfer_ownership(&admin_original)
+        .is_err());
+    // delay can't be lower than one day
+    assert!(router.try_set_transfer_delay(&admin_original, &0).is_err());
+
+    router.commit_transfer_ownership(&admin_original, &admin_new);
+    e.ledger().with_mut(|li| li.timestamp += 3 * 86400);
+    router.apply_transfer_ownership(&admin_original);
+
+    // new admin is able to call protected methods
+    router.set_pools_plane(&admin_new, &create_plane_contract(&e).address);
+}
@@ -0,0 +1,149 @@
+use crate::events::{Events, MerkleDistributorEvents};
+use crate::interface::MerkleDistributorInterface;
+use crate::merkle::{leaf_hash, verify_proof};
+use crate::storage::{
+    get_distribution, get_epochs_count, is_claimed, set_claimed, set_distribution,
+    set_epochs_count, Distribution,
+};
+use access_control::access::{AccessControl, AccessControlTrait};
+use access_control::interface::{RolesInterface, TransferableContract};
+use access_control::role::Role;
+use soroban_sdk::token::TokenClient;
+use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, Vec};
+use utils::bump::bump_instance;
+
+#[contract]
+pub struct MerkleDistributor;
+
+#[contractimpl]
+impl MerkleDistributorInterface for MerkleDistributor {
+    fn initialize(e: Env, admin: Address) {
+        let access_control = AccessControl::new(&e);
+        if access_control.has_admin() {
+            panic!("already initialized")
+        }
+        access_control.set_admin(&admin);
+    }
+
+    fn add_distribution(
+        e: Env,
+        admin: Address,
+        token: Address,
+        root: BytesN<32>,
+        amount: u128,
+        deadline: u64,
+    ) -> u32 {
+        let access_control = AccessControl::new(&e);
+        access_control.require_role(Role::RewardsAdmin, &admin);
+        if deadline <= e.ledger().timestamp() {
+            panic!("deadline should be in the future")
+        }
+
+        TokenClient::new(&e, &token).transfer(
+            &admin,
+            &e.current_contract_address(),
+            &(amount as i128),
+        );
+        let epoch = get_epochs_count(&e);
+        set_distribution(
+            &e,
+            epoch,
+            &Distribution {
+                token: token.clone(),
+                root: root.clone(),
+                amount,
+                claimed: 0,
+                deadline,
+                swept: false,
+            },
+        );
+        set_epochs_count(&e, &(epoch + 1));
+
+        Events::new(&e).add_distribution(epoch, token, root, amount, deadline);
+        epoch
+    }
+
+    fn claim(
+        e: Env,
+        epoch: u32,
+        index: u32,
+        account: Address,
+        amount: u128,
+        proof: Vec<BytesN<32>>,
+    ) -> u128 {
+        let mut distribution = get_distribution(&e, epoch).expect("distribution doesn't exist");
+        if e.ledger().timestamp() > distribution.deadline {
+            panic!("claim deadline passed")
+        }
+        if is_claimed(&e, epoch, index) {
+            panic!("already claimed")
+        }
+        if !verify_proof(
+            &e,
+            &distribution.root,
+            leaf_hash(&e, index, &account, amount),
+            &proof,
+        ) {
+            panic!("invalid proof")
+        }
+        if distribution.claimed + amount > distribution.amount {
+            panic!("distribution is underfunded")
+        }
+
+        bump_instance(&e);
+        set_claimed(&e, epoch, index);
+        distribution.claimed += amount;
+        set_distribution(&e, epoch, &distribution);
+        TokenClient::new(&e, &distribution.token).transfer(
+            &e.current_contract_address(),
+            &account,
+            &(amount as i128),
+        );
+
+        Events::new(&e).claim(account, epoch, index, amount);
+        amount
+    }
+
+    fn is_claimed(e: Env, epoch: u32, index: u32) -> bool {
+        is_claimed(&e, epoch, index)
+    }
+
+    fn sweep(e: Env, admin: Address, epoch: u32, to: Address) -> u128 {
+        let access_control = AccessControl::new(&e);
+        access_control.require_role(Role::RewardsAdmin, &admin);
+
+        let mut distribution = get_distribution(&e, epoch).expect("distribution doesn't exist");
+        if e.ledger().timestamp() <= distribution.deadline {
+            panic!("claim deadline not passed yet")
+        }
+        if distribution.swept {
+            panic!("distribution already swept")
+        }
+
+        let amount = distribution.amount - distribution.claimed;
+        distribution.swept = true;
+        set_distribution(&e, epoch, &distribution);
+        if amount > 0 {
+            TokenClient::new(&e, &distribution.token).transfer(
+                &e.current_contract_address(),
+                &to,
+                &(amount as i128),
+            );
+        }
+
+        Events::new(&e).sweep(epoch, to, amount);
+        amount
+    }
+
+    fn get_distribution(e: Env, epoch: u32) -> Option<Distribution> {
+        get_distribution(&e, epoch)
+    }
+
+    fn get_epochs_count(e: Env) -> u32 {
+        get_epochs_count(&e)
+    }
+}
+
+access_control::generate_transferable_contract!(MerkleDistributor);
+
+access_control::generate_roles_interface!(MerkleDistributor);
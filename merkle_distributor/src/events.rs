@@ -0,0 +1,60 @@
+use soroban_sdk::{Address, BytesN, Env, Symbol};
+
+#[derive(Clone)]
+pub(crate) struct Events(Env);
+
+impl Events {
+    #[inline(always)]
+    pub(crate) fn env(&self) -> &Env {
+        &self.0
+    }
+
+    #[inline(always)]
+    pub(crate) fn new(env: &Env) -> Events {
+        Events(env.clone())
+    }
+}
+
+pub(crate) trait MerkleDistributorEvents {
+    fn add_distribution(
+        &self,
+        epoch: u32,
+        token: Address,
+        root: BytesN<32>,
+        amount: u128,
+        deadline: u64,
+    );
+
+    fn claim(&self, account: Address, epoch: u32, index: u32, amount: u128);
+
+    fn sweep(&self, epoch: u32, to: Address, amount: u128);
+}
+
+impl MerkleDistributorEvents for Events {
+    fn add_distribution(
+        &self,
+        epoch: u32,
+        token: Address,
+        root: BytesN<32>,
+        amount: u128,
+        deadline: u64,
+    ) {
+        self.env().events().publish(
+            (Symbol::new(self.env(), "add_distribution"), token),
+            (epoch, root, amount, deadline),
+        );
+    }
+
+    fn claim(&self, account: Address, epoch: u32, index: u32, amount: u128) {
+        self.env().events().publish(
+            (Symbol::new(self.env(), "claim"), account),
+            (epoch, index, amount),
+        );
+    }
+
+    fn sweep(&self, epoch: u32, to: Address, amount: u128) {
+        self.env()
+            .events()
+            .publish((Symbol::new(self.env(), "sweep"), to), (epoch, amount));
+    }
+}
@@ -0,0 +1,44 @@
+use crate::storage::Distribution;
+use soroban_sdk::{Address, BytesN, Env, Vec};
+
+pub trait MerkleDistributorInterface {
+    // Initialize distributor. Will panic if called twice
+    fn initialize(e: Env, admin: Address);
+
+    // Post merkle root of distribution computed off-chain and transfer its funding from admin.
+    // Leaves are sha256 of (index, account, amount) serialized to xdr, nodes are
+    // sha256 of sorted pair. Claims are accepted until deadline. Only rewards admin.
+    // Returns epoch number of the distribution
+    fn add_distribution(
+        e: Env,
+        admin: Address,
+        token: Address,
+        root: BytesN<32>,
+        amount: u128,
+        deadline: u64,
+    ) -> u32;
+
+    // Transfer amount of distribution to the account if proof is valid.
+    // Every leaf can be claimed only once. Callable by anyone, returns amount transferred
+    fn claim(
+        e: Env,
+        epoch: u32,
+        index: u32,
+        account: Address,
+        amount: u128,
+        proof: Vec<BytesN<32>>,
+    ) -> u128;
+
+    // Check if leaf of the distribution is claimed already
+    fn is_claimed(e: Env, epoch: u32, index: u32) -> bool;
+
+    // Transfer unclaimed funds of the distribution once deadline passed. Only rewards admin.
+    // Returns amount transferred
+    fn sweep(e: Env, admin: Address, epoch: u32, to: Address) -> u128;
+
+    // Get distribution of the epoch
+    fn get_distribution(e: Env, epoch: u32) -> Option<Distribution>;
+
+    // Get amount of distributions posted
+    fn get_epochs_count(e: Env) -> u32;
+}
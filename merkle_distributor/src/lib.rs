@@ -0,0 +1,10 @@
+#![no_std]
+
+mod contract;
+mod events;
+mod interface;
+mod merkle;
+mod storage;
+mod test;
+
+pub use crate::contract::{MerkleDistributor, MerkleDistributorClient};
@@ -0,0 +1,28 @@
+use soroban_sdk::xdr::ToXdr;
+use soroban_sdk::{Address, Bytes, BytesN, Env, Vec};
+
+// leaf is sha256 of (index, account, amount) tuple serialized to xdr
+pub(crate) fn leaf_hash(e: &Env, index: u32, account: &Address, amount: u128) -> BytesN<32> {
+    e.crypto()
+        .sha256(&(index, account.clone(), amount).to_xdr(e))
+}
+
+// nodes are hashed as sorted pairs, so proof doesn't need to carry leaf position
+pub(crate) fn verify_proof(
+    e: &Env,
+    root: &BytesN<32>,
+    leaf: BytesN<32>,
+    proof: &Vec<BytesN<32>>,
+) -> bool {
+    let mut computed = leaf;
+    for node in proof.iter() {
+        let (left, right) = match computed < node {
+            true => (computed, node),
+            false => (node, computed),
+        };
+        let mut data = Bytes::from(left);
+        data.append(&Bytes::from(right));
+        computed = e.crypto().sha256(&data);
+    }
+    computed == *root
+}
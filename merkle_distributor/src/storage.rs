@@ -0,0 +1,69 @@
+use paste::paste;
+use soroban_sdk::{contracttype, Address, BytesN, Env};
+use utils::bump::{bump_instance, bump_persistent};
+use utils::{
+    generate_instance_storage_getter_and_setter_with_default,
+    generate_instance_storage_getter_with_default, generate_instance_storage_setter,
+};
+
+// Distribution computed off-chain. amount is funded on creation, claims are accepted
+// until deadline and the rest can be swept afterwards
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Distribution {
+    pub token: Address,
+    pub root: BytesN<32>,
+    pub amount: u128,
+    pub claimed: u128,
+    pub deadline: u64,
+    pub swept: bool,
+}
+
+#[derive(Clone)]
+#[contracttype]
+enum DataKey {
+    EpochsCount,
+    Distribution(u32),
+    // bitmap of claimed leaves, 128 leaves per word
+    ClaimedWord(u32, u32),
+}
+
+generate_instance_storage_getter_and_setter_with_default!(
+    epochs_count,
+    DataKey::EpochsCount,
+    u32,
+    0
+);
+
+pub fn get_distribution(e: &Env, epoch: u32) -> Option<Distribution> {
+    let key = DataKey::Distribution(epoch);
+    let value = e.storage().persistent().get(&key);
+    if value.is_some() {
+        bump_persistent(e, &key);
+    }
+    value
+}
+
+pub fn set_distribution(e: &Env, epoch: u32, distribution: &Distribution) {
+    let key = DataKey::Distribution(epoch);
+    e.storage().persistent().set(&key, distribution);
+    bump_persistent(e, &key);
+}
+
+pub fn is_claimed(e: &Env, epoch: u32, index: u32) -> bool {
+    let word: u128 = e
+        .storage()
+        .persistent()
+        .get(&DataKey::ClaimedWord(epoch, index / 128))
+        .unwrap_or(0);
+    word & (1 << (index % 128)) != 0
+}
+
+pub fn set_claimed(e: &Env, epoch: u32, index: u32) {
+    let key = DataKey::ClaimedWord(epoch, index / 128);
+    let word: u128 = e.storage().persistent().get(&key).unwrap_or(0);
+    e.storage()
+        .persistent()
+        .set(&key, &(word | (1 << (index % 128))));
+    bump_persistent(e, &key);
+}
@@ -0,0 +1,237 @@
+#![cfg(test)]
+extern crate std;
+
+use crate::merkle::leaf_hash;
+use crate::MerkleDistributorClient;
+use soroban_sdk::testutils::{Address as _, Events, Ledger, LedgerInfo};
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{vec, Address, Bytes, BytesN, Env, IntoVal, Symbol, Vec};
+
+fn jump(e: &Env, time: u64) {
+    e.ledger().set(LedgerInfo {
+        timestamp: e.ledger().timestamp().saturating_add(time),
+        protocol_version: 20,
+        sequence_number: e.ledger().sequence(),
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: 999999,
+        min_persistent_entry_ttl: 999999,
+        max_entry_ttl: u32::MAX,
+    });
+}
+
+fn hash_pair(e: &Env, a: &BytesN<32>, b: &BytesN<32>) -> BytesN<32> {
+    let (left, right) = if a < b { (a, b) } else { (b, a) };
+    let mut data = Bytes::from(left.clone());
+    data.append(&Bytes::from(right.clone()));
+    e.crypto().sha256(&data)
+}
+
+struct Setup<'a> {
+    env: Env,
+    admin: Address,
+    users: [Address; 4],
+    amounts: [u128; 4],
+    token: TokenClient<'a>,
+    distributor: MerkleDistributorClient<'a>,
+    root: BytesN<32>,
+    leaves: [BytesN<32>; 4],
+}
+
+impl Default for Setup<'_> {
+    fn default() -> Self {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let admin = Address::generate(&env);
+        let users = [
+            Address::generate(&env),
+            Address::generate(&env),
+            Address::generate(&env),
+            Address::generate(&env),
+        ];
+        let amounts = [100, 200, 300, 400];
+        let token_address = env.register_stellar_asset_contract(Address::generate(&env));
+        StellarAssetClient::new(&env, &token_address).mint(&admin, &10_000);
+
+        let distributor = MerkleDistributorClient::new(
+            &env,
+            &env.register_contract(None, crate::MerkleDistributor),
+        );
+        distributor.initialize(&admin);
+
+        let leaves = [0, 1, 2, 3].map(|i| leaf_hash(&env, i as u32, &users[i], amounts[i]));
+        let root = hash_pair(
+            &env,
+            &hash_pair(&env, &leaves[0], &leaves[1]),
+            &hash_pair(&env, &leaves[2], &leaves[3]),
+        );
+
+        Setup {
+            token: TokenClient::new(&env, &token_address),
+            env,
+            admin,
+            users,
+            amounts,
+            distributor,
+            root,
+            leaves,
+        }
+    }
+}
+
+impl Setup<'_> {
+    fn proof(&self, index: usize) -> Vec<BytesN<32>> {
+        let e = &self.env;
+        let sibling = self.leaves[index ^ 1].clone();
+        let other = match index < 2 {
+            true => hash_pair(e, &self.leaves[2], &self.leaves[3]),
+            false => hash_pair(e, &self.leaves[0], &self.leaves[1]),
+        };
+        Vec::from_array(e, [sibling, other])
+    }
+
+    fn add_distribution(&self, amount: u128) -> u32 {
+        self.distributor.add_distribution(
+            &self.admin,
+            &self.token.address,
+            &self.root,
+            &amount,
+            &(self.env.ledger().timestamp() + 100),
+        )
+    }
+}
+
+#[test]
+fn test_claim() {
+    let setup = Setup::default();
+    let e = &setup.env;
+    let distributor = &setup.distributor;
+
+    assert_eq!(setup.add_distribution(1000), 0);
+    assert_eq!(setup.token.balance(&distributor.address), 1000);
+    assert_eq!(distributor.get_epochs_count(), 1);
+
+    assert_eq!(
+        distributor.claim(&0, &1, &setup.users[1], &200, &setup.proof(1)),
+        200
+    );
+    assert_eq!(
+        vec![e, e.events().all().last().unwrap()],
+        vec![
+            e,
+            (
+                distributor.address.clone(),
+                (Symbol::new(e, "claim"), setup.users[1].clone()).into_val(e),
+                (0_u32, 1_u32, 200_u128).into_val(e),
+            )
+        ]
+    );
+    assert_eq!(setup.token.balance(&setup.users[1]), 200);
+    assert!(distributor.is_claimed(&0, &1));
+    assert!(!distributor.is_claimed(&0, &2));
+    assert_eq!(distributor.get_distribution(&0).unwrap().claimed, 200);
+
+    // every leaf is claimed only once
+    assert!(distributor
+        .try_claim(&0, &1, &setup.users[1], &200, &setup.proof(1))
+        .is_err());
+    // leaf data should match the proof
+    assert!(distributor
+        .try_claim(&0, &2, &setup.users[2], &400, &setup.proof(2))
+        .is_err());
+    assert!(distributor
+        .try_claim(&0, &2, &setup.users[3], &300, &setup.proof(2))
+        .is_err());
+    assert!(distributor
+        .try_claim(&0, &2, &setup.users[2], &300, &setup.proof(3))
+        .is_err());
+
+    for i in [0, 2, 3] {
+        distributor.claim(
+            &0,
+            &(i as u32),
+            &setup.users[i],
+            &setup.amounts[i],
+            &setup.proof(i),
+        );
+        assert_eq!(
+            setup.token.balance(&setup.users[i]),
+            setup.amounts[i] as i128
+        );
+    }
+}
+
+#[test]
+fn test_sweep_after_deadline() {
+    let setup = Setup::default();
+    let e = &setup.env;
+    let distributor = &setup.distributor;
+    let treasury = Address::generate(e);
+
+    setup.add_distribution(1000);
+    distributor.claim(&0, &0, &setup.users[0], &100, &setup.proof(0));
+    assert!(distributor.try_sweep(&setup.admin, &0, &treasury).is_err());
+
+    jump(e, 101);
+    assert!(distributor
+        .try_claim(&0, &1, &setup.users[1], &200, &setup.proof(1))
+        .is_err());
+    assert!(distributor
+        .try_sweep(&setup.users[0], &0, &treasury)
+        .is_err());
+    assert_eq!(distributor.sweep(&setup.admin, &0, &treasury), 900);
+    assert_eq!(setup.token.balance(&treasury), 900);
+    assert!(distributor.try_sweep(&setup.admin, &0, &treasury).is_err());
+}
+
+#[test]
+fn test_underfunded_distribution() {
+    let setup = Setup::default();
+    let distributor = &setup.distributor;
+
+    // claims can't take funds of other distributions
+    setup.add_distribution(500);
+    setup.add_distribution(1000);
+    distributor.claim(&0, &3, &setup.users[3], &400, &setup.proof(3));
+    assert!(distributor
+        .try_claim(&0, &2, &setup.users[2], &300, &setup.proof(2))
+        .is_err());
+    distributor.claim(&1, &2, &setup.users[2], &300, &setup.proof(2));
+}
+
+#[test]
+fn test_add_distribution_requires_role() {
+    let setup = Setup::default();
+    let e = &setup.env;
+    let deadline = e.ledger().timestamp() + 100;
+
+    assert!(setup
+        .distributor
+        .try_add_distribution(
+            &setup.users[0],
+            &setup.token.address,
+            &setup.root,
+            &0,
+            &deadline
+        )
+        .is_err());
+    // deadline in the past
+    assert!(setup
+        .distributor
+        .try_add_distribution(
+            &setup.admin,
+            &setup.token.address,
+            &setup.root,
+            &0,
+            &e.ledger().timestamp()
+        )
+        .is_err());
+}
+
+#[test]
+#[should_panic(expected = "already initialized")]
+fn test_initialize_twice() {
+    let setup = Setup::default();
+    setup.distributor.initialize(&setup.admin);
+}
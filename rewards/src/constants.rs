@@ -1 +1,16 @@
-pub(crate) const REWARD_PRECISION: u128 = 1_000;
+// precision of pools created before it became configurable. also used for legacy rewards history
+pub(crate) const LEGACY_REWARD_PRECISION: u128 = 1_000;
+// precision of reward per share for new pools
+pub const DEFAULT_REWARD_PRECISION: u128 = 1_000_000_000_000;
+pub const MAX_REWARD_PRECISION: u128 = 1_000_000_000_000_000_000;
+// reward amount per single share reward per share should be able to accumulate on top of
+// the current one without overflow. max precision multiplied by it fits into u128
+pub(crate) const REWARD_PER_SHARE_HEADROOM: u128 = 100_000_000_000_000_000_000;
+// maximum amount of reward streams per pool, including primary one
+pub(crate) const MAX_REWARD_STREAMS: u32 = 5;
+// maximum amount of reward configs queued per stream
+pub(crate) const MAX_SCHEDULED_REWARDS: u32 = 10;
+// period after volume epoch end during which its budget can be claimed, seconds
+pub(crate) const VOLUME_CLAIM_PERIOD: u64 = 30 * 24 * 60 * 60;
+// share of liquidity counted without any boost, percents
+pub(crate) const TOKENLESS_PRODUCTION: u128 = 40;
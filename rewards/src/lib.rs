@@ -1,14 +1,26 @@
 #![no_std]
 
-use soroban_sdk::Env;
+use crate::constants::{MAX_REWARD_STREAMS, REWARD_PER_SHARE_HEADROOM, TOKENLESS_PRODUCTION};
+use crate::math::mul_div;
+use crate::storage::{
+    Incentive, PoolRewardConfig, RewardsFunding, RewardsInfo, RewardsStorageTrait,
+    ScheduledRewardConfig,
+};
+use soroban_sdk::{token::TokenClient as Client, Address, Env, Vec};
 
 mod constants;
 pub mod manager;
+pub mod math;
 pub mod storage;
+pub mod volume;
+pub mod voting_escrow;
 
+pub use constants::{DEFAULT_REWARD_PRECISION, MAX_REWARD_PRECISION};
 pub use manager::Manager;
 pub use storage::Storage;
 pub use utils;
+pub use volume::VolumeMining;
+pub use voting_escrow::VotingEscrowClient;
 
 #[derive(Clone)]
 pub struct RewardsConfig {
@@ -19,6 +31,7 @@ pub struct RewardsConfig {
 pub struct Rewards {
     env: Env,
     config: RewardsConfig,
+    stream: u32,
 }
 
 impl Rewards {
@@ -27,14 +40,648 @@ impl Rewards {
         Rewards {
             env: env.clone(),
             config: RewardsConfig { page_size },
+            stream: 0,
+        }
+    }
+
+    // rewards of specific stream. stream 0 is the primary one
+    pub fn stream(&self, stream: u32) -> Rewards {
+        Rewards {
+            env: self.env.clone(),
+            config: self.config.clone(),
+            stream,
         }
     }
 
     pub fn storage(&self) -> Storage {
-        Storage::new(&self.env)
+        Storage::new_for_stream(&self.env, self.stream)
     }
 
     pub fn manager(&self) -> Manager {
         Manager::new(&self.env, self.storage(), &self.config)
     }
+
+    pub fn volume_mining(&self) -> VolumeMining {
+        VolumeMining::new(&self.env)
+    }
+
+    pub fn streams_count(&self) -> u32 {
+        self.storage().get_reward_streams_count()
+    }
+
+    // register additional reward stream. returns stream number
+    pub fn add_stream(&self, reward_token: Address, reward_storage: Address) -> u32 {
+        let count = self.streams_count();
+        if count >= MAX_REWARD_STREAMS {
+            panic!("too many reward streams")
+        }
+
+        let rewards = self.stream(count);
+        rewards.storage().put_reward_token(reward_token);
+        rewards.storage().put_reward_storage(reward_storage);
+        rewards.manager().initialize();
+        self.storage().put_reward_streams_count(count + 1);
+        count
+    }
+
+    // update every stream rewards data and refresh user reward.
+    // should be called before user shares change
+    pub fn checkpoint_user(&self, user: &Address, total_shares: u128, user_shares: u128) {
+        let (working_supply, working_balance) =
+            self.get_working_balances(user, total_shares, user_shares);
+        for stream in 0..self.streams_count() {
+            let rewards = self.stream(stream);
+            let pool_data = rewards.manager().update_rewards_data(working_supply);
+            rewards
+                .manager()
+                .update_user_reward(&pool_data, user, working_balance);
+            rewards.storage().bump_user_reward_data(user);
+        }
+
+        // fix user working balance before shares change so it can be recalculated later
+        if self.get_voting_escrow().is_some() && self.storage().get_working_balance(user).is_none()
+        {
+            self.storage().put_working_balance(user, working_balance);
+        }
+    }
+
+    pub fn get_voting_escrow(&self) -> Option<Address> {
+        self.storage().get_voting_escrow()
+    }
+
+    // enable rewards boost. until then working balances are equal to user shares
+    pub fn set_voting_escrow(&self, voting_escrow: Address, total_shares: u128) {
+        if self.get_voting_escrow().is_none() {
+            // checkpoint distribution made with raw shares before switching to working supply
+            for stream in 0..self.streams_count() {
+                self.stream(stream)
+                    .manager()
+                    .update_rewards_data(total_shares);
+            }
+            self.storage().put_working_supply(total_shares);
+        }
+        self.storage().put_voting_escrow(voting_escrow);
+    }
+
+    // total shares used for rewards distribution
+    pub fn working_supply(&self, total_shares: u128) -> u128 {
+        match self.get_voting_escrow() {
+            Some(_) => self.storage().get_working_supply(),
+            None => total_shares,
+        }
+    }
+
+    // returns (working supply, user working balance) used for rewards distribution.
+    // user without stored working balance is counted with his current shares
+    pub fn get_working_balances(
+        &self,
+        user: &Address,
+        total_shares: u128,
+        user_shares: u128,
+    ) -> (u128, u128) {
+        match self.get_voting_escrow() {
+            Some(_) => (
+                self.storage().get_working_supply(),
+                self.storage()
+                    .get_working_balance(user)
+                    .unwrap_or(user_shares),
+            ),
+            None => (total_shares, user_shares),
+        }
+    }
+
+    // recalculate user working balance according to his vote-escrow lock.
+    // every stream is checkpointed first since working supply is shared across them
+    pub fn update_working_balance(&self, user: &Address, total_shares: u128, user_shares: u128) {
+        let voting_escrow = match self.get_voting_escrow() {
+            Some(address) => address,
+            None => return,
+        };
+        self.checkpoint_user(user, total_shares, user_shares);
+
+        let ve_client = VotingEscrowClient::new(&self.env, &voting_escrow);
+        let lock = ve_client.balance(user);
+        let total_lock = ve_client.total_supply();
+
+        let e = &self.env;
+        let mut new_balance = mul_div(e, user_shares, TOKENLESS_PRODUCTION, 100);
+        if total_lock > 0 {
+            let locked_shares = mul_div(e, total_shares, lock, total_lock);
+            new_balance += mul_div(e, locked_shares, 100 - TOKENLESS_PRODUCTION, 100);
+        }
+        if new_balance > user_shares {
+            new_balance = user_shares;
+        }
+
+        let storage = self.storage();
+        let old_balance = storage.get_working_balance(user).unwrap_or(user_shares);
+        storage.put_working_supply(storage.get_working_supply() + new_balance - old_balance);
+        storage.put_working_balance(user, new_balance);
+    }
+
+    // claim reward of every stream. additional stream failing to pay keeps user reward,
+    // so broken incentive token doesn't block the others. returns amounts transferred
+    pub fn claim_all(&self, user: &Address, total_shares: u128, user_shares: u128) -> Vec<u128> {
+        let (working_supply, working_balance) =
+            self.get_working_balances(user, total_shares, user_shares);
+        let mut result = Vec::new(&self.env);
+        for stream in 0..self.streams_count() {
+            let rewards = self.stream(stream);
+            let reward = match stream {
+                0 => rewards
+                    .manager()
+                    .claim_reward(user, working_supply, working_balance),
+                _ => rewards.manager().try_claim_reward_to(
+                    user,
+                    user,
+                    working_supply,
+                    working_balance,
+                ),
+            };
+            rewards.storage().bump_user_reward_data(user);
+            result.push_back(reward);
+        }
+        self.update_working_balance(user, total_shares, user_shares);
+        result
+    }
+
+    // set token and storage of the primary stream and reward precision once
+    pub fn initialize_config(
+        &self,
+        reward_token: Address,
+        reward_storage: Address,
+        precision: u128,
+    ) {
+        if self.storage().has_reward_token() {
+            panic!("rewards config already initialized")
+        }
+
+        self.storage().put_reward_token(reward_token);
+        self.storage().put_reward_storage(reward_storage);
+        self.initialize_precision(precision);
+    }
+
+    // reward available for the user in the stream. doesn't write anything
+    pub fn get_user_reward(&self, user: &Address, total_shares: u128, user_shares: u128) -> u128 {
+        let (working_supply, working_balance) =
+            self.get_working_balances(user, total_shares, user_shares);
+        self.manager()
+            .get_amount_to_claim(user, working_supply, working_balance)
+    }
+
+    // reward available for the user in every stream
+    pub fn get_all_user_rewards(
+        &self,
+        user: &Address,
+        total_shares: u128,
+        user_shares: u128,
+    ) -> Vec<u128> {
+        let mut result = Vec::new(&self.env);
+        for stream in 0..self.streams_count() {
+            result.push_back(
+                self.stream(stream)
+                    .get_user_reward(user, total_shares, user_shares),
+            );
+        }
+        result
+    }
+
+    // rewards info of every stream paired with its reward token
+    pub fn get_all_rewards_info(
+        &self,
+        user: &Address,
+        total_shares: u128,
+        user_shares: u128,
+    ) -> Vec<(Address, RewardsInfo)> {
+        let mut result = Vec::new(&self.env);
+        for stream in 0..self.streams_count() {
+            let rewards = self.stream(stream);
+            result.push_back((
+                rewards.storage().get_reward_token(),
+                rewards.get_rewards_info(user, total_shares, user_shares),
+            ));
+        }
+        result
+    }
+
+    // claim stream reward of the user, transferring it to recipient
+    pub fn claim_reward_to(
+        &self,
+        user: &Address,
+        recipient: &Address,
+        total_shares: u128,
+        user_shares: u128,
+    ) -> u128 {
+        let (working_supply, working_balance) =
+            self.get_working_balances(user, total_shares, user_shares);
+        let reward =
+            self.manager()
+                .claim_reward_to(user, recipient, working_supply, working_balance);
+        self.storage().bump_user_reward_data(user);
+        self.update_working_balance(user, total_shares, user_shares);
+        reward
+    }
+
+    // reset stream reward of the user without transferring it. returns amount taken
+    pub fn take_reward(&self, user: &Address, total_shares: u128, user_shares: u128) -> u128 {
+        let (working_supply, working_balance) =
+            self.get_working_balances(user, total_shares, user_shares);
+        let reward = self
+            .manager()
+            .take_reward(user, working_supply, working_balance);
+        self.storage().bump_user_reward_data(user);
+        self.update_working_balance(user, total_shares, user_shares);
+        reward
+    }
+
+    // replace stream config right away, checkpointing rewards generated by the previous one
+    pub fn set_config(&self, expired_at: u64, tps: u128, total_shares: u128, reserved: u128) {
+        self.require_no_incentive();
+        self.manager()
+            .update_rewards_data(self.working_supply(total_shares));
+        self.storage()
+            .set_pool_reward_config(&PoolRewardConfig { tps, expired_at });
+        if tps > 0 {
+            self.check_funding(reserved);
+        }
+    }
+
+    // add stream config to be activated at start_at
+    pub fn schedule_config(&self, start_at: u64, expired_at: u64, tps: u128, reserved: u128) {
+        self.require_no_incentive();
+        self.manager()
+            .schedule_rewards_config(start_at, expired_at, tps);
+        if tps > 0 {
+            self.check_funding(reserved);
+        }
+    }
+
+    // end reward period of the stream now. scheduled configs are kept
+    pub fn stop(&self, total_shares: u128) {
+        self.require_no_incentive();
+        self.manager()
+            .stop_rewards(self.working_supply(total_shares));
+    }
+
+    // incentive stream config is driven by the incentive until it's settled
+    fn require_no_incentive(&self) {
+        if self.storage().get_incentive().is_some() {
+            panic!("stream is used by incentive")
+        }
+    }
+
+    // pending stream configs. started ones are activated first
+    pub fn get_scheduled_configs(&self, total_shares: u128) -> Vec<ScheduledRewardConfig> {
+        self.manager()
+            .update_rewards_data(self.working_supply(total_shares));
+        self.manager().get_scheduled_rewards_configs()
+    }
+
+    // refresh working balance of the user whose vote-escrow lock has changed
+    pub fn kick(&self, user: &Address, total_shares: u128, user_shares: u128) {
+        if self.get_voting_escrow().is_none() {
+            panic!("boost is not enabled")
+        }
+
+        self.update_working_balance(user, total_shares, user_shares);
+    }
+
+    pub fn get_working_balance(
+        &self,
+        user: &Address,
+        total_shares: u128,
+        user_shares: u128,
+    ) -> u128 {
+        let (_, working_balance) = self.get_working_balances(user, total_shares, user_shares);
+        working_balance
+    }
+
+    // configure volume mining paid from the primary stream storage
+    pub fn set_volume_mining_config(&self, epoch_length: u64, budget: u128, reserved: u128) {
+        self.volume_mining().set_config(epoch_length, budget);
+        if budget > 0 {
+            self.check_volume_mining_funding(reserved, budget);
+        }
+    }
+
+    // allow token for incentives with minimum amount or disallow it when none given
+    pub fn set_incentive_token(&self, token: &Address, min_amount: Option<u128>) {
+        match min_amount {
+            Some(value) => self.storage().set_incentive_min_amount(token, value),
+            None => self.storage().remove_incentive_min_amount(token),
+        }
+    }
+
+    // incentive stream of the token which is free to be reused, if any.
+    // streams are never reused for another token since users may have rewards left in them
+    fn find_free_incentive_stream(&self, reward_token: &Address) -> Option<u32> {
+        let now = self.env.ledger().timestamp();
+        for stream in self.storage().get_incentive_streams(reward_token) {
+            let storage = self.stream(stream).storage();
+            let incentive_finished = match storage.get_incentive() {
+                Some(incentive) => incentive.expired_at <= now,
+                None => true,
+            };
+            if incentive_finished
+                && storage.get_pool_reward_config().expired_at <= now
+                && storage.get_reward_schedule().is_empty()
+            {
+                return Some(stream);
+            }
+        }
+        None
+    }
+
+    // stream third-party incentive to liquidity providers during duration seconds.
+    // amount should be already transferred to the contract. token should be allowed for
+    // incentives. free stream of the token is reused, finished incentive in it is settled
+    // first, otherwise new stream is registered. returns stream number
+    pub fn add_incentive(
+        &self,
+        depositor: &Address,
+        reward_token: Address,
+        amount: u128,
+        duration: u64,
+        total_shares: u128,
+    ) -> u32 {
+        if duration == 0 {
+            panic!("incentive duration should be positive")
+        }
+        let min_amount = match self.storage().get_incentive_min_amount(&reward_token) {
+            Some(value) => value,
+            None => panic!("token is not allowed for incentives"),
+        };
+        let tps = amount / duration as u128;
+        if tps == 0 || amount < min_amount {
+            panic!("incentive amount is too small")
+        }
+
+        let stream = match self.find_free_incentive_stream(&reward_token) {
+            Some(stream) => stream,
+            None => {
+                let stream =
+                    self.add_stream(reward_token.clone(), self.env.current_contract_address());
+                let mut streams = self.storage().get_incentive_streams(&reward_token);
+                streams.push_back(stream);
+                self.storage()
+                    .set_incentive_streams(&reward_token, &streams);
+                stream
+            }
+        };
+        let rewards = self.stream(stream);
+        let now = self.env.ledger().timestamp();
+        if rewards.storage().get_incentive().is_some() {
+            rewards.settle_incentive(total_shares);
+        }
+        let data = rewards
+            .manager()
+            .update_rewards_data(self.working_supply(total_shares));
+        let storage = rewards.storage();
+
+        let expired_at = now + duration;
+        storage.set_pool_reward_config(&PoolRewardConfig { tps, expired_at });
+        storage.set_incentive(&Incentive {
+            depositor: depositor.clone(),
+            amount,
+            expired_at,
+            accumulated_before: data.accumulated,
+        });
+        stream
+    }
+
+    // finish incentive of the stream returning undistributed leftover to depositor.
+    // returns settled incentive and leftover amount
+    pub fn settle_incentive(&self, total_shares: u128) -> (Incentive, u128) {
+        let storage = self.storage();
+        let incentive = storage.get_incentive().expect("no incentive in the stream");
+        if incentive.expired_at > self.env.ledger().timestamp() {
+            panic!("incentive is not finished yet")
+        }
+
+        let data = self
+            .manager()
+            .update_rewards_data(self.working_supply(total_shares));
+        // distributed amount may exceed incentive amount by rounding, nothing is left then
+        let leftover = data
+            .accumulated
+            .checked_sub(incentive.accumulated_before)
+            .and_then(|distributed| incentive.amount.checked_sub(distributed))
+            .unwrap_or(0);
+        storage.remove_incentive();
+        if leftover > 0 {
+            Client::new(&self.env, &storage.get_reward_token()).transfer(
+                &self.env.current_contract_address(),
+                &incentive.depositor,
+                &(leftover as i128),
+            );
+        }
+        (incentive, leftover)
+    }
+
+    // stream rewards state at current time, including amount available for the user.
+    // simulated without writing anything, so can be used as a query
+    pub fn get_rewards_info(
+        &self,
+        user: &Address,
+        total_shares: u128,
+        user_shares: u128,
+    ) -> RewardsInfo {
+        let (working_supply, working_balance) =
+            self.get_working_balances(user, total_shares, user_shares);
+        let manager = self.manager();
+        let (pool_data, config) = manager.get_rewards_data(working_supply);
+        let user_data = manager.get_user_reward(&pool_data, user, working_balance);
+        RewardsInfo {
+            tps: config.tps,
+            expired_at: config.expired_at,
+            accumulated: pool_data.accumulated,
+            last_time: pool_data.last_time,
+            reward_per_share: pool_data.reward_per_share,
+            precision: self.get_precision(),
+            user_reward_per_share: user_data.reward_per_share,
+            to_claim: user_data.to_claim,
+        }
+    }
+
+    // reward per second for single working share, multiplied by precision
+    pub fn get_tps_per_share(&self, total_shares: u128) -> u128 {
+        self.manager()
+            .get_tps_per_share(self.working_supply(total_shares))
+    }
+
+    // rewards to be generated from now on by active and scheduled configs
+    pub fn get_remaining_emission(&self, total_shares: u128) -> u128 {
+        self.manager()
+            .get_remaining_emission(self.working_supply(total_shares))
+    }
+
+    // allow or disallow operator to claim rewards on behalf of user
+    pub fn set_claim_operator(&self, user: &Address, operator: &Address, approved: bool) {
+        self.storage().set_claim_operator(user, operator, approved);
+    }
+
+    pub fn is_claim_operator(&self, user: &Address, operator: &Address) -> bool {
+        self.storage().get_claim_operator(user, operator)
+    }
+
+    // require caller to be the user himself or operator approved by him
+    pub fn require_claim_allowed(&self, caller: &Address, user: &Address) {
+        caller.require_auth();
+        if caller != user && !self.is_claim_operator(user, caller) {
+            panic!("caller is not allowed to claim")
+        }
+    }
+
+    // reward per share precision, shared by all streams
+    pub fn get_precision(&self) -> u128 {
+        self.storage().get_reward_precision()
+    }
+
+    // set precision of newly initialized pool
+    pub fn initialize_precision(&self, precision: u128) {
+        if precision == 0 || precision > MAX_REWARD_PRECISION {
+            panic!("reward precision is out of allowed range")
+        }
+        self.storage().put_reward_precision(precision);
+    }
+
+    // increase reward per share precision. every stream is checkpointed with current precision
+    // and rescaled, users are rescaled lazily on their next update.
+    // new precision should be a multiple of current one so no accumulated reward is lost
+    pub fn set_precision(&self, precision: u128, total_shares: u128) {
+        let current = self.get_precision();
+        if precision > MAX_REWARD_PRECISION {
+            panic!("reward precision is too high")
+        }
+        if precision < current || precision % current != 0 {
+            panic!("reward precision should be a multiple of current one")
+        }
+
+        let working_supply = self.working_supply(total_shares);
+        for stream in 0..self.streams_count() {
+            let mut manager = self.stream(stream).manager();
+            let data = manager.update_rewards_data(working_supply);
+            // rescaled reward per share should be able to grow further without overflow
+            let reward_per_share = data
+                .reward_per_share
+                .checked_mul(precision / current)
+                .expect("reward precision is too high for accumulated rewards");
+            if u128::MAX - reward_per_share < precision * REWARD_PER_SHARE_HEADROOM {
+                panic!("reward precision is too high for accumulated rewards")
+            }
+            manager.scale_reward_per_share(precision / current);
+        }
+        self.storage().put_reward_precision(precision);
+    }
+
+    // outstanding rewards of every stream paying the same token from the same storage
+    fn get_shared_outstanding_rewards(&self) -> u128 {
+        let storage = self.storage();
+        let reward_token = storage.get_reward_token();
+        let reward_storage = storage.get_reward_storage();
+        let mut outstanding = 0;
+        for stream in 0..self.streams_count() {
+            let stream_storage = self.stream(stream).storage();
+            if stream_storage.get_reward_token() == reward_token
+                && stream_storage.get_reward_storage() == reward_storage
+            {
+                outstanding += self.stream(stream).manager().get_outstanding_rewards();
+            }
+        }
+        // volume mining budget is paid by the primary stream reward storage
+        let primary_storage = self.stream(0).storage();
+        if primary_storage.get_reward_token() == reward_token
+            && primary_storage.get_reward_storage() == reward_storage
+        {
+            outstanding += self.volume_mining().get_outstanding();
+        }
+        outstanding
+    }
+
+    // funds available for rewards. reserved: amount of reward token held by the contract
+    // for other purposes, like pool reserves
+    fn get_available_funds(&self, reserved: u128) -> u128 {
+        let available = self.manager().get_available_funds();
+        if self.storage().get_reward_storage() == self.env.current_contract_address() {
+            available.saturating_sub(reserved)
+        } else {
+            available
+        }
+    }
+
+    pub fn get_funding(&self, reserved: u128) -> RewardsFunding {
+        let manager = self.manager();
+        RewardsFunding {
+            committed: manager.get_committed_rewards(),
+            paid: self.storage().get_reward_paid(),
+            available: self.get_available_funds(reserved),
+        }
+    }
+
+    // ensure reward storage is able to pay all the committed rewards
+    pub fn check_funding(&self, reserved: u128) {
+        if self.get_available_funds(reserved) < self.get_shared_outstanding_rewards() {
+            panic!("not enough funds for rewards")
+        }
+    }
+
+    // whether primary reward storage is able to pay one more volume mining epoch budget
+    fn is_volume_mining_funded(&self, reserved: u128, budget: u128) -> bool {
+        let primary = self.stream(0);
+        primary.get_available_funds(reserved)
+            >= primary
+                .get_shared_outstanding_rewards()
+                .saturating_add(budget)
+    }
+
+    // ensure primary reward storage is able to pay one more volume mining epoch budget
+    pub fn check_volume_mining_funding(&self, reserved: u128, budget: u128) {
+        if !self.is_volume_mining_funded(reserved, budget) {
+            panic!("not enough funds for rewards")
+        }
+    }
+
+    // credit user with volume mining points for the swap.
+    // new epoch is started with actual config once previous one is finished, unless its
+    // budget isn't funded. never panics on funding, so swap isn't blocked by volume mining
+    pub fn add_volume_points(&self, user: &Address, points: u128, reserved: u128) {
+        if points == 0 {
+            return;
+        }
+
+        let volume_mining = self.volume_mining();
+        let epoch = match volume_mining.get_open_epoch() {
+            Some(epoch) => epoch,
+            None => match volume_mining.get_config() {
+                Some(config) if self.is_volume_mining_funded(reserved, config.budget) => {
+                    volume_mining.open_epoch(&config)
+                }
+                _ => return,
+            },
+        };
+        volume_mining.add_points(user, epoch, points);
+    }
+
+    // transfer reward tokens not committed to any stream out of the contract.
+    // possible only if contract is the reward storage itself. returns amount transferred
+    pub fn reclaim_unallocated(&self, to: &Address, total_shares: u128, reserved: u128) -> u128 {
+        self.manager()
+            .update_rewards_data(self.working_supply(total_shares));
+        let storage = self.storage();
+        if storage.get_reward_storage() != self.env.current_contract_address() {
+            panic!("rewards are not stored in the contract")
+        }
+
+        let amount = self
+            .get_available_funds(reserved)
+            .saturating_sub(self.get_shared_outstanding_rewards());
+        if amount > 0 {
+            Client::new(&self.env, &storage.get_reward_token()).transfer(
+                &self.env.current_contract_address(),
+                to,
+                &(amount as i128),
+            );
+        }
+        amount
+    }
 }
@@ -1,10 +1,12 @@
-use crate::constants::REWARD_PRECISION;
+use crate::constants::{LEGACY_REWARD_PRECISION, MAX_SCHEDULED_REWARDS};
+use crate::math::mul_div;
 use crate::storage::{
-    PoolRewardConfig, PoolRewardData, RewardsStorageTrait, Storage, UserRewardData,
+    LegacyUserRewardData, PoolRewardConfig, PoolRewardData, RewardsStorageTrait,
+    ScheduledRewardConfig, Storage, UserRewardData,
 };
 use crate::RewardsConfig;
 use cast::u128 as to_u128;
-use soroban_sdk::{token::TokenClient as Client, Address, Env, Map};
+use soroban_sdk::{token::TokenClient as Client, Address, Env, Vec};
 
 pub struct Manager {
     env: Env,
@@ -22,10 +24,9 @@ impl Manager {
     }
 
     pub fn initialize(&mut self) {
-        self.add_reward_inv(0, 0);
         self.storage.set_pool_reward_data(&PoolRewardData {
-            block: 0,
             accumulated: 0,
+            reward_per_share: 0,
             last_time: 0,
         });
         self.storage.set_pool_reward_config(&PoolRewardConfig {
@@ -35,41 +36,160 @@ impl Manager {
     }
 
     pub fn update_rewards_data(&mut self, total_shares: u128) -> PoolRewardData {
-        let config = self.storage.get_pool_reward_config();
-        let data = self.storage.get_pool_reward_data();
+        self.migrate_pool_data();
+        let (data, config, activated) = self.calculate_rewards_data(total_shares);
+        if activated > 0 {
+            // scheduled configs which have already started replace active one
+            self.storage.set_pool_reward_config(&config);
+            let schedule = self.storage.get_reward_schedule();
+            self.storage
+                .set_reward_schedule(&schedule.slice(activated..));
+        }
+        self.storage.set_pool_reward_data(&data);
+        data
+    }
+
+    // pool rewards state and active config at current time. nothing is written
+    pub fn get_rewards_data(&self, total_shares: u128) -> (PoolRewardData, PoolRewardConfig) {
+        let (data, config, _) = self.calculate_rewards_data(total_shares);
+        (data, config)
+    }
+
+    // reward per second for single working share, multiplied by precision.
+    // zero if rewards are not being distributed at the moment
+    pub fn get_tps_per_share(&self, total_shares: u128) -> u128 {
+        let (data, config) = self.get_rewards_data(total_shares);
+        if total_shares == 0 || config.expired_at <= data.last_time {
+            return 0;
+        }
+        mul_div(
+            &self.env,
+            config.tps,
+            self.storage.get_reward_precision(),
+            total_shares,
+        )
+    }
+
+    // rewards to be generated from now on by active and scheduled configs
+    pub fn get_remaining_emission(&self, total_shares: u128) -> u128 {
+        let (data, config, activated) = self.calculate_rewards_data(total_shares);
+        let mut remaining = 0;
+        if config.expired_at > data.last_time {
+            remaining += to_u128(config.expired_at - data.last_time) * config.tps;
+        }
+        for scheduled in self.storage.get_reward_schedule().slice(activated..) {
+            remaining += to_u128(scheduled.expired_at - scheduled.start_at) * scheduled.tps;
+        }
+        remaining
+    }
+
+    // queue reward period. periods should not overlap and go in chronological order
+    pub fn schedule_rewards_config(&mut self, start_at: u64, expired_at: u64, tps: u128) {
+        if start_at < self.env.ledger().timestamp() {
+            panic!("cannot schedule rewards in the past")
+        }
+        if expired_at <= start_at {
+            panic!("reward period should end after start")
+        }
+
+        let mut schedule = self.storage.get_reward_schedule();
+        if schedule.len() >= MAX_SCHEDULED_REWARDS {
+            panic!("too many scheduled rewards")
+        }
+        if let Some(last) = schedule.last() {
+            if start_at < last.expired_at {
+                panic!("scheduled rewards overlap")
+            }
+        }
+
+        schedule.push_back(ScheduledRewardConfig {
+            start_at,
+            expired_at,
+            tps,
+        });
+        self.storage.set_reward_schedule(&schedule);
+    }
+
+    // remove not started reward period from the queue
+    pub fn cancel_scheduled_rewards_config(&mut self, start_at: u64) {
+        let mut schedule = self.storage.get_reward_schedule();
+        for (index, config) in schedule.iter().enumerate() {
+            if config.start_at == start_at {
+                schedule.remove(index as u32);
+                self.storage.set_reward_schedule(&schedule);
+                return;
+            }
+        }
+        panic!("scheduled rewards config not found")
+    }
+
+    pub fn get_scheduled_rewards_configs(&self) -> Vec<ScheduledRewardConfig> {
+        self.storage.get_reward_schedule()
+    }
+
+    // pool rewards state at current time, active config and number of scheduled configs
+    // activated since last update
+    fn calculate_rewards_data(
+        &self,
+        total_shares: u128,
+    ) -> (PoolRewardData, PoolRewardConfig, u32) {
         let now = self.env.ledger().timestamp();
+        let mut data = self.get_pool_reward_data();
+        let mut config = self.storage.get_pool_reward_config();
+        let mut activated = 0;
+        for next in self.storage.get_reward_schedule() {
+            if next.start_at > now {
+                break;
+            }
+            data = self.accumulate_rewards(&data, &config, next.start_at, total_shares);
+            config = PoolRewardConfig {
+                tps: next.tps,
+                expired_at: next.expired_at,
+            };
+            activated += 1;
+        }
+        (
+            self.accumulate_rewards(&data, &config, now, total_shares),
+            config,
+            activated,
+        )
+    }
 
-        // 1. config not expired - snapshot reward
-        // 2. config expired
-        //  2.a data before config expiration - snapshot reward for now, increase block and generate inv
-        //  2.b data after config expiration - snapshot reward for config end, increase block, snapshot reward for now, don't increase block
-
-        if now < config.expired_at {
-            self.update_rewards_data_snapshot(now, &config, &data, total_shares)
-        } else if data.last_time > config.expired_at {
-            // todo: try to avoid unneeded block increments
-            self.create_new_rewards_data(
-                0,
+    fn accumulate_rewards(
+        &self,
+        data: &PoolRewardData,
+        config: &PoolRewardConfig,
+        now: u64,
+        total_shares: u128,
+    ) -> PoolRewardData {
+        // rewards are generated until config expiration
+        let reward_until = now.min(config.expired_at);
+        let generated_tokens = if reward_until > data.last_time {
+            to_u128(reward_until - data.last_time) * config.tps
+        } else {
+            0
+        };
+        let reward_per_share = if total_shares > 0 {
+            mul_div(
+                &self.env,
+                self.storage.get_reward_precision(),
+                generated_tokens,
                 total_shares,
-                PoolRewardData {
-                    block: data.block + 1,
-                    accumulated: data.accumulated,
-                    last_time: now,
-                },
             )
         } else {
-            self.update_rewards_data_catchup(now, &config, &data, total_shares)
-        }
-    }
+            0
+        };
 
-    fn calculate_user_reward(
-        &mut self,
-        start_block: u64,
-        end_block: u64,
-        user_share: u128,
-    ) -> u128 {
-        let result = self.calculate_reward(start_block, end_block, true);
-        (result) * user_share / REWARD_PRECISION
+        // rewards generated while there are no shares are not distributed to anyone
+        let distributed_tokens = match reward_per_share {
+            0 => 0,
+            _ => generated_tokens,
+        };
+        PoolRewardData {
+            accumulated: data.accumulated + distributed_tokens,
+            reward_per_share: data.reward_per_share + reward_per_share,
+            last_time: now,
+        }
     }
 
     pub fn update_user_reward(
@@ -78,39 +198,51 @@ impl Manager {
         user: &Address,
         user_balance_shares: u128,
     ) -> UserRewardData {
-        match self.storage.get_user_reward_data(user) {
-            Some(user_data) => {
-                if user_data.pool_accumulated == pool_data.accumulated {
-                    // nothing accumulated since last update
-                    return user_data;
-                }
-
-                if user_balance_shares == 0 {
-                    // zero balance, no new reward
-                    return self.create_new_user_data(user, pool_data, user_data.to_claim);
-                }
-
-                let reward = self.calculate_user_reward(
-                    user_data.last_block + 1,
-                    pool_data.block,
-                    user_balance_shares,
-                );
-                // let new_reward =
-                //     (pool_data.accumulated - user_data.pool_accumulated) * user_shares / total_shares;
-                self.create_new_user_data(user, pool_data, user_data.to_claim + reward)
-            }
-            None => self.create_new_user_data(user, pool_data, 0),
+        let stored_data = self.storage.get_user_reward_data(user);
+        let legacy_data = match stored_data {
+            Some(_) => None,
+            None => self.storage.get_legacy_user_reward_data(user),
+        };
+        let has_legacy_data = legacy_data.is_some();
+        let user_data = self.calculate_user_reward(
+            pool_data,
+            stored_data.clone(),
+            legacy_data,
+            user_balance_shares,
+        );
+        if has_legacy_data {
+            self.storage.remove_legacy_user_reward_data(user);
+        }
+        if stored_data.as_ref() != Some(&user_data) {
+            self.storage.set_user_reward_data(user, &user_data);
         }
+        user_data
     }
 
+    // user rewards state for given pool state. nothing is written
+    pub fn get_user_reward(
+        &self,
+        pool_data: &PoolRewardData,
+        user: &Address,
+        user_balance_shares: u128,
+    ) -> UserRewardData {
+        let stored_data = self.storage.get_user_reward_data(user);
+        let legacy_data = match stored_data {
+            Some(_) => None,
+            None => self.storage.get_legacy_user_reward_data(user),
+        };
+        self.calculate_user_reward(pool_data, stored_data, legacy_data, user_balance_shares)
+    }
+
+    // reward available for the user at current time. nothing is written
     pub fn get_amount_to_claim(
-        &mut self,
+        &self,
         user: &Address,
         total_shares: u128,
         user_balance_shares: u128,
     ) -> u128 {
-        // update pool data & calculate reward
-        self.user_reward_data(user, total_shares, user_balance_shares)
+        let (pool_data, _) = self.get_rewards_data(total_shares);
+        self.get_user_reward(&pool_data, user, user_balance_shares)
             .to_claim
     }
 
@@ -120,12 +252,19 @@ impl Manager {
         total_shares: u128,
         user_balance_shares: u128,
     ) -> u128 {
-        // update pool data & calculate reward
-        let UserRewardData {
-            last_block,
-            pool_accumulated,
-            to_claim: reward_amount,
-        } = self.user_reward_data(user, total_shares, user_balance_shares);
+        self.claim_reward_to(user, user, total_shares, user_balance_shares)
+    }
+
+    // claim user reward, transferring it to recipient
+    pub fn claim_reward_to(
+        &mut self,
+        user: &Address,
+        recipient: &Address,
+        total_shares: u128,
+        user_balance_shares: u128,
+    ) -> u128 {
+        // update pool data, calculate reward & reset it
+        let reward_amount = self.take_reward(user, total_shares, user_balance_shares);
 
         // transfer reward
         let reward_token = self.storage.get_reward_token();
@@ -133,44 +272,267 @@ impl Manager {
         if rewards_storage == self.env.current_contract_address() {
             Client::new(&self.env, &reward_token).transfer(
                 &rewards_storage,
-                user,
+                recipient,
                 &(reward_amount as i128),
             );
         } else {
             Client::new(&self.env, &reward_token).transfer_from(
                 &self.env.current_contract_address(),
                 &rewards_storage,
-                user,
+                recipient,
                 &(reward_amount as i128),
             );
         };
+        reward_amount
+    }
+
+    // claim user reward like claim_reward_to, but reward is kept for the user
+    // if its transfer fails. returns amount transferred
+    pub fn try_claim_reward_to(
+        &mut self,
+        user: &Address,
+        recipient: &Address,
+        total_shares: u128,
+        user_balance_shares: u128,
+    ) -> u128 {
+        let user_data = self.user_reward_data(user, total_shares, user_balance_shares);
+        if user_data.to_claim == 0 {
+            return 0;
+        }
+
+        let reward_token = Client::new(&self.env, &self.storage.get_reward_token());
+        let rewards_storage = self.storage.get_reward_storage();
+        let amount = user_data.to_claim as i128;
+        let transferred = if rewards_storage == self.env.current_contract_address() {
+            reward_token
+                .try_transfer(&rewards_storage, recipient, &amount)
+                .is_ok()
+        } else {
+            reward_token
+                .try_transfer_from(
+                    &self.env.current_contract_address(),
+                    &rewards_storage,
+                    recipient,
+                    &amount,
+                )
+                .is_ok()
+        };
+        if !transferred {
+            return 0;
+        }
+
+        self.storage.set_user_reward_data(
+            user,
+            &UserRewardData {
+                to_claim: 0,
+                ..user_data
+            },
+        );
+        self.storage
+            .put_reward_paid(self.storage.get_reward_paid() + user_data.to_claim);
+        user_data.to_claim
+    }
+
+    // mark user reward as claimed without actual transfer.
+    // caller is responsible for paying the reward out
+    pub fn take_reward(
+        &mut self,
+        user: &Address,
+        total_shares: u128,
+        user_balance_shares: u128,
+    ) -> u128 {
+        // update pool data & calculate reward
+        let UserRewardData {
+            reward_per_share,
+            to_claim: reward_amount,
+            precision,
+        } = self.user_reward_data(user, total_shares, user_balance_shares);
 
         // set available reward to zero
         let new_data = UserRewardData {
-            last_block,
-            pool_accumulated,
+            reward_per_share,
             to_claim: 0,
+            precision,
         };
         self.storage.set_user_reward_data(user, &new_data);
+        self.storage
+            .put_reward_paid(self.storage.get_reward_paid() + reward_amount);
         reward_amount
     }
 
+    // end reward period now. scheduled configs are kept
+    pub fn stop_rewards(&mut self, total_shares: u128) {
+        let data = self.update_rewards_data(total_shares);
+        let config = self.storage.get_pool_reward_config();
+        if config.expired_at > data.last_time {
+            self.storage.set_pool_reward_config(&PoolRewardConfig {
+                tps: config.tps,
+                expired_at: data.last_time,
+            });
+        }
+    }
+
+    // rewards distributed so far plus future emission of active and scheduled configs
+    pub fn get_committed_rewards(&self) -> u128 {
+        let data = self.storage.get_pool_reward_data();
+        let config = self.storage.get_pool_reward_config();
+        let mut committed = data.accumulated;
+        if config.expired_at > data.last_time {
+            committed += to_u128(config.expired_at - data.last_time) * config.tps;
+        }
+        for scheduled in self.storage.get_reward_schedule() {
+            committed += to_u128(scheduled.expired_at - scheduled.start_at) * scheduled.tps;
+        }
+        // whole incentive is reserved until its leftover is returned to depositor
+        if let Some(incentive) = self.storage.get_incentive() {
+            committed = committed.max(incentive.accumulated_before + incentive.amount);
+        }
+        committed
+    }
+
+    // committed rewards not paid yet
+    pub fn get_outstanding_rewards(&self) -> u128 {
+        self.get_committed_rewards()
+            .saturating_sub(self.storage.get_reward_paid())
+    }
+
+    // reward token amount reward storage holds. allowance isn't taken into account
+    // since external storage may pay rewards itself collecting them from the contract
+    pub fn get_available_funds(&self) -> u128 {
+        Client::new(&self.env, &self.storage.get_reward_token())
+            .balance(&self.storage.get_reward_storage()) as u128
+    }
+
+    // multiply reward per share precision by its factor. every stream should be updated before
+    pub fn scale_reward_per_share(&mut self, factor: u128) {
+        let data = self.storage.get_pool_reward_data();
+        self.storage.set_pool_reward_data(&PoolRewardData {
+            reward_per_share: data.reward_per_share * factor,
+            ..data
+        });
+    }
+
     // private functions
 
-    fn write_reward_inv_to_page(&mut self, pow: u32, start_block: u64, value: u128) {
-        let page_number = start_block / self.config.page_size.pow(pow + 1);
-        let mut page = match start_block % self.config.page_size.pow(pow + 1) {
-            0 => Map::new(&self.env),
-            _ => self.storage.get_reward_inv_data(pow, page_number),
+    // pool rewards state. data of not migrated pool is converted without writing it
+    fn get_pool_reward_data(&self) -> PoolRewardData {
+        if self.storage.has_pool_reward_data() {
+            return self.storage.get_pool_reward_data();
+        }
+        let legacy_data = self
+            .storage
+            .get_legacy_pool_reward_data()
+            .expect("Please, initialize pool reward data");
+        PoolRewardData {
+            accumulated: legacy_data.accumulated,
+            reward_per_share: 0,
+            last_time: legacy_data.last_time,
+        }
+    }
+
+    // move pool from block based rewards history to reward per share accumulator.
+    // legacy history is frozen at the last block and used only to migrate users
+    fn migrate_pool_data(&mut self) {
+        if self.storage.has_pool_reward_data() {
+            return;
+        }
+        let legacy_data = self
+            .storage
+            .get_legacy_pool_reward_data()
+            .expect("Please, initialize pool reward data");
+        self.storage.set_legacy_reward_block(legacy_data.block);
+        self.storage.remove_legacy_pool_reward_data();
+        // payouts weren't tracked before, legacy history is considered as paid
+        self.storage.put_reward_paid(legacy_data.accumulated);
+        self.storage.set_pool_reward_data(&PoolRewardData {
+            accumulated: legacy_data.accumulated,
+            reward_per_share: 0,
+            last_time: legacy_data.last_time,
+        });
+    }
+
+    // user reward accumulated in legacy history up to the migration point
+    fn calculate_legacy_reward(
+        &self,
+        legacy_data: &LegacyUserRewardData,
+        user_balance_shares: u128,
+    ) -> u128 {
+        let legacy_block = match self.storage.get_legacy_reward_block() {
+            Some(block) => Some(block),
+            // pool isn't migrated yet, history is going to be frozen at its last block
+            None => self
+                .storage
+                .get_legacy_pool_reward_data()
+                .map(|data| data.block),
         };
-        page.set(start_block, value);
-        self.storage.set_reward_inv_data(pow, page_number, page);
+        let mut reward = legacy_data.to_claim;
+        if let Some(legacy_block) = legacy_block {
+            if user_balance_shares > 0 && legacy_data.last_block < legacy_block {
+                reward += self.calculate_reward(legacy_data.last_block + 1, legacy_block, true)
+                    * user_balance_shares
+                    / LEGACY_REWARD_PRECISION;
+            }
+        }
+        reward
+    }
+
+    fn calculate_user_reward(
+        &self,
+        pool_data: &PoolRewardData,
+        user_data: Option<UserRewardData>,
+        legacy_data: Option<LegacyUserRewardData>,
+        user_balance_shares: u128,
+    ) -> UserRewardData {
+        let precision = self.storage.get_reward_precision();
+        let user_data = match (user_data, legacy_data) {
+            (Some(user_data), _) => user_data,
+            // accumulator starts from zero at migration point, so user starts from zero as well
+            (None, Some(legacy_data)) => UserRewardData {
+                reward_per_share: 0,
+                to_claim: self.calculate_legacy_reward(&legacy_data, user_balance_shares),
+                precision,
+            },
+            (None, None) => UserRewardData {
+                reward_per_share: pool_data.reward_per_share,
+                to_claim: 0,
+                precision,
+            },
+        };
+
+        if user_data.reward_per_share == pool_data.reward_per_share
+            && user_data.precision == precision
+        {
+            // nothing accumulated since last update
+            return user_data;
+        }
+
+        // precision may have been increased since last user update
+        let user_reward_per_share = user_data.reward_per_share * (precision / user_data.precision);
+        // difference of rounded totals so rounding error doesn't add up over user updates
+        let reward = mul_div(
+            &self.env,
+            pool_data.reward_per_share,
+            user_balance_shares,
+            precision,
+        ) - mul_div(
+            &self.env,
+            user_reward_per_share,
+            user_balance_shares,
+            precision,
+        );
+        UserRewardData {
+            reward_per_share: pool_data.reward_per_share,
+            to_claim: user_data.to_claim + reward,
+            precision,
+        }
     }
 
-    fn calculate_reward(&mut self, start_block: u64, end_block: u64, use_max_pow: bool) -> u128 {
+    fn calculate_reward(&self, start_block: u64, end_block: u64, use_max_pow: bool) -> u128 {
         // calculate result from start_block to end_block [...]
         // use_max_pow disabled during aggregation process
         //  since we don't have such information and can be enabled after
+        // pages are cached in local copy of the storage
+        let mut storage = self.storage.clone();
         let mut result = 0;
         let mut block = start_block;
 
@@ -205,7 +567,7 @@ impl Manager {
                     }
 
                     let page_number = block / self.config.page_size.pow(l_pow + 1);
-                    let page = self.storage.get_reward_inv_data(l_pow, page_number);
+                    let page = storage.get_reward_inv_data(l_pow, page_number);
                     result += page.get(block).expect("unknown block");
                     block = next_block;
                     block_increased = true;
@@ -213,16 +575,12 @@ impl Manager {
                 }
                 if !block_increased {
                     // couldn't find shortcut, looks like we're close to the tail. go one by one
-                    let page = self
-                        .storage
-                        .get_reward_inv_data(0, block / self.config.page_size);
+                    let page = storage.get_reward_inv_data(0, block / self.config.page_size);
                     result += page.get(block).expect("unknown block");
                     block += 1;
                 }
             } else {
-                let page = self
-                    .storage
-                    .get_reward_inv_data(0, block / self.config.page_size);
+                let page = storage.get_reward_inv_data(0, block / self.config.page_size);
                 result += page.get(block).expect("unknown block");
                 block += 1;
             }
@@ -230,110 +588,6 @@ impl Manager {
         result
     }
 
-    fn add_reward_inv(&mut self, block: u64, value: u128) {
-        // write zero level page first
-        self.write_reward_inv_to_page(0, block, value);
-
-        if (block + 1) % self.config.page_size == 0 {
-            // page end, at least one aggregation should be applicable
-            for pow in 1..255 {
-                let aggregation_size = self.config.page_size.pow(pow);
-                if (block + 1) % aggregation_size != 0 {
-                    // aggregation level not applicable
-                    break;
-                }
-                let agg_page_start = block - block % aggregation_size;
-                let aggregation = self.calculate_reward(agg_page_start, block, false);
-                self.write_reward_inv_to_page(pow, agg_page_start, aggregation);
-            }
-        }
-    }
-
-    fn update_reward_inv(&mut self, accumulated: u128, total_shares: u128) {
-        let reward_per_share = if total_shares > 0 {
-            REWARD_PRECISION * accumulated / total_shares
-        } else {
-            0
-        };
-
-        let data = self.storage.get_pool_reward_data();
-        self.add_reward_inv(data.block, reward_per_share);
-    }
-
-    fn update_rewards_data_snapshot(
-        &mut self,
-        now: u64,
-        config: &PoolRewardConfig,
-        data: &PoolRewardData,
-        total_shares: u128,
-    ) -> PoolRewardData {
-        let reward_timestamp = now;
-        let generated_tokens = to_u128(reward_timestamp - data.last_time) * to_u128(config.tps);
-        self.create_new_rewards_data(
-            generated_tokens,
-            total_shares,
-            PoolRewardData {
-                block: data.block + 1,
-                accumulated: data.accumulated + generated_tokens,
-                last_time: now,
-            },
-        )
-    }
-
-    fn create_new_rewards_data(
-        &mut self,
-        generated_tokens: u128,
-        total_shares: u128,
-        new_data: PoolRewardData,
-    ) -> PoolRewardData {
-        self.storage.set_pool_reward_data(&new_data);
-        self.update_reward_inv(generated_tokens, total_shares);
-        new_data
-    }
-
-    fn update_rewards_data_catchup(
-        &mut self,
-        now: u64,
-        config: &PoolRewardConfig,
-        data: &PoolRewardData,
-        total_shares: u128,
-    ) -> PoolRewardData {
-        let reward_timestamp = config.expired_at;
-
-        let generated_tokens = to_u128(reward_timestamp - data.last_time) * to_u128(config.tps);
-        let catchup_data = PoolRewardData {
-            block: data.block + 1,
-            accumulated: data.accumulated + generated_tokens,
-            last_time: config.expired_at,
-        };
-        self.create_new_rewards_data(generated_tokens, total_shares, catchup_data.clone());
-        // todo: don't increase block when config not enabled thus keeping invariants list small
-        self.create_new_rewards_data(
-            0,
-            total_shares,
-            PoolRewardData {
-                block: catchup_data.block + 1,
-                accumulated: catchup_data.accumulated,
-                last_time: now,
-            },
-        )
-    }
-
-    fn create_new_user_data(
-        &self,
-        user: &Address,
-        pool_data: &PoolRewardData,
-        to_claim: u128,
-    ) -> UserRewardData {
-        let new_data = UserRewardData {
-            last_block: pool_data.block,
-            pool_accumulated: pool_data.accumulated,
-            to_claim,
-        };
-        self.storage.set_user_reward_data(user, &new_data);
-        new_data
-    }
-
     fn user_reward_data(
         &mut self,
         user: &Address,
@@ -0,0 +1,22 @@
+use soroban_sdk::{Env, U256};
+
+// a * b / c without intermediate overflow
+pub(crate) fn mul_div(e: &Env, a: u128, b: u128, c: u128) -> u128 {
+    U256::from_u128(e, a)
+        .mul(&U256::from_u128(e, b))
+        .div(&U256::from_u128(e, c))
+        .to_u128()
+        .expect("reward math overflow")
+}
+
+// a * b / c capped at u128::MAX. zero divisor gives zero
+pub fn saturating_mul_div(e: &Env, a: u128, b: u128, c: u128) -> u128 {
+    if c == 0 {
+        return 0;
+    }
+    U256::from_u128(e, a)
+        .mul(&U256::from_u128(e, b))
+        .div(&U256::from_u128(e, c))
+        .to_u128()
+        .unwrap_or(u128::MAX)
+}
@@ -1,4 +1,5 @@
-use soroban_sdk::{contracttype, Address, Env, Map};
+use crate::constants::LEGACY_REWARD_PRECISION;
+use soroban_sdk::{contracttype, Address, Env, Map, Vec};
 use utils::bump::bump_persistent;
 
 // Rewards configuration for specific pool
@@ -9,9 +10,100 @@ pub struct PoolRewardConfig {
     pub expired_at: u64,
 }
 
+// Reward period planned in advance. Replaces active config once started
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct ScheduledRewardConfig {
+    pub start_at: u64,
+    pub expired_at: u64,
+    pub tps: u128,
+}
+
+// Reward token obligations of the stream.
+// committed: rewards distributed so far plus future emission of active and scheduled configs
+// paid: rewards claimed by users
+// available: reward token amount reward storage holds
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct RewardsFunding {
+    pub committed: u128,
+    pub paid: u128,
+    pub available: u128,
+}
+
+// Third-party incentive streamed to liquidity providers through reward stream.
+// accumulated_before: stream rewards distributed before incentive started,
+// used to find out undistributed leftover returned to depositor
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Incentive {
+    pub depositor: Address,
+    pub amount: u128,
+    pub expired_at: u64,
+    pub accumulated_before: u128,
+}
+
+// Trading volume mining setup. Every epoch of epoch_length seconds budget of primary
+// reward token is split between traders proportionally to the swap fees they paid
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct VolumeMiningConfig {
+    pub epoch_length: u64,
+    pub budget: u128,
+}
+
+// Volume mining epoch. Budget is fixed once epoch is started by the first swap.
+// swept: unclaimed budget is released once claim period is over
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct VolumeEpoch {
+    pub budget: u128,
+    pub total_points: u128,
+    pub end_at: u64,
+    pub claimed: u128,
+    pub swept: bool,
+}
+
+// Stream rewards state at current time with reward available for the user.
+// tps and expired_at describe active config, reward_per_share values are
+// multiplied by precision
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct RewardsInfo {
+    pub tps: u128,
+    pub expired_at: u64,
+    pub accumulated: u128,
+    pub last_time: u64,
+    pub reward_per_share: u128,
+    pub precision: u128,
+    pub user_reward_per_share: u128,
+    pub to_claim: u128,
+}
+
+// Pool rewards state. reward_per_share is cumulative amount of reward per working share,
+// multiplied by reward precision
 #[derive(Clone)]
 #[contracttype]
 pub struct PoolRewardData {
+    pub accumulated: u128,
+    pub reward_per_share: u128,
+    pub last_time: u64,
+}
+
+// User rewards state. reward_per_share is stored with the precision pool had on last update
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct UserRewardData {
+    pub reward_per_share: u128,
+    pub to_claim: u128,
+    pub precision: u128,
+}
+
+// Block based rewards state written before reward per share accumulator was introduced.
+// Kept only to migrate existing data
+#[derive(Clone)]
+#[contracttype]
+pub struct LegacyPoolRewardData {
     pub block: u64,
     pub accumulated: u128,
     pub last_time: u64,
@@ -19,7 +111,7 @@ pub struct PoolRewardData {
 
 #[derive(Clone)]
 #[contracttype]
-pub struct UserRewardData {
+pub struct LegacyUserRewardData {
     pub pool_accumulated: u128,
     pub to_claim: u128,
     pub last_block: u64,
@@ -29,33 +121,137 @@ pub struct UserRewardData {
 #[contracttype]
 enum DataKey {
     PoolRewardConfig,
+    // legacy block based data, pool reward data is being migrated on first update
     PoolRewardData,
     UserRewardData(Address),
     RewardInvData(u32, u64),
+    LegacyRewardBlock,
+    RewardPaid,
+    PoolRewardDataV2,
+    UserRewardDataV2(Address),
     RewardStorage,
     RewardToken,
+    RewardStreamsCount,
+    RewardSchedule,
+    // additional reward streams data. primary stream uses keys above
+    StreamRewardConfig(u32),
+    StreamRewardStorage(u32),
+    StreamRewardToken(u32),
+    StreamRewardSchedule(u32),
+    StreamRewardPaid(u32),
+    StreamRewardDataV2(u32),
+    StreamUserRewardDataV2(u32, Address),
+    StreamIncentive(u32),
+    // boost data, shared by all reward streams
+    VotingEscrow,
+    WorkingSupply,
+    WorkingBalance(Address),
+    // operators allowed to claim on behalf of user, shared by all reward streams
+    ClaimOperator(Address, Address),
+    // tokens allowed for third-party incentives and streams registered for them
+    IncentiveMinAmount(Address),
+    IncentiveStreams(Address),
+    // reward per share precision, shared by all reward streams
+    RewardPrecision,
+    // trading volume mining, paid in primary stream reward token
+    VolumeMiningConfig,
+    VolumeEpochsCount,
+    VolumeEpoch(u32),
+    VolumeUserPoints(u32, Address),
+    VolumeOutstanding,
 }
 
+#[derive(Clone)]
 pub struct Storage {
     env: Env,
+    stream: u32,
     inv_cache: Map<DataKey, Map<u64, u128>>,
 }
 
 impl Storage {
     pub fn new(e: &Env) -> Storage {
+        Self::new_for_stream(e, 0)
+    }
+
+    pub fn new_for_stream(e: &Env, stream: u32) -> Storage {
         Storage {
             env: e.clone(),
+            stream,
             inv_cache: Map::new(e),
         }
     }
+
+    fn pool_reward_config_key(&self) -> DataKey {
+        match self.stream {
+            0 => DataKey::PoolRewardConfig,
+            stream => DataKey::StreamRewardConfig(stream),
+        }
+    }
+
+    fn pool_reward_data_key(&self) -> DataKey {
+        match self.stream {
+            0 => DataKey::PoolRewardDataV2,
+            stream => DataKey::StreamRewardDataV2(stream),
+        }
+    }
+
+    fn user_reward_data_key(&self, user: &Address) -> DataKey {
+        match self.stream {
+            0 => DataKey::UserRewardDataV2(user.clone()),
+            stream => DataKey::StreamUserRewardDataV2(stream, user.clone()),
+        }
+    }
+
+    // legacy block based history exists in the primary stream only,
+    // streams are added after the migration and start with current data format
+    fn has_legacy_data(&self) -> bool {
+        self.stream == 0
+    }
+
+    fn reward_storage_key(&self) -> DataKey {
+        match self.stream {
+            0 => DataKey::RewardStorage,
+            stream => DataKey::StreamRewardStorage(stream),
+        }
+    }
+
+    fn reward_schedule_key(&self) -> DataKey {
+        match self.stream {
+            0 => DataKey::RewardSchedule,
+            stream => DataKey::StreamRewardSchedule(stream),
+        }
+    }
+
+    fn reward_paid_key(&self) -> DataKey {
+        match self.stream {
+            0 => DataKey::RewardPaid,
+            stream => DataKey::StreamRewardPaid(stream),
+        }
+    }
+
+    fn reward_token_key(&self) -> DataKey {
+        match self.stream {
+            0 => DataKey::RewardToken,
+            stream => DataKey::StreamRewardToken(stream),
+        }
+    }
+
+    // primary stream is configured by admin only, so incentives exist in additional streams
+    fn incentive_key(&self) -> DataKey {
+        DataKey::StreamIncentive(self.stream)
+    }
 }
 
 pub trait RewardsStorageTrait {
     fn get_pool_reward_config(&self) -> PoolRewardConfig;
     fn set_pool_reward_config(&self, config: &PoolRewardConfig);
 
+    fn get_reward_schedule(&self) -> Vec<ScheduledRewardConfig>;
+    fn set_reward_schedule(&self, schedule: &Vec<ScheduledRewardConfig>);
+
     fn get_pool_reward_data(&self) -> PoolRewardData;
     fn set_pool_reward_data(&self, data: &PoolRewardData);
+    fn has_pool_reward_data(&self) -> bool;
 
     fn get_user_reward_data(&self, user: &Address) -> Option<UserRewardData>;
     fn set_user_reward_data(&self, user: &Address, config: &UserRewardData);
@@ -65,12 +261,70 @@ pub trait RewardsStorageTrait {
     fn set_reward_inv_data(&mut self, pow: u32, page_number: u64, value: Map<u64, u128>);
     fn bump_reward_inv_data(&self, pow: u32, page_number: u64);
 
+    fn get_legacy_pool_reward_data(&self) -> Option<LegacyPoolRewardData>;
+    fn remove_legacy_pool_reward_data(&self);
+
+    fn get_legacy_user_reward_data(&self, user: &Address) -> Option<LegacyUserRewardData>;
+    fn remove_legacy_user_reward_data(&self, user: &Address);
+
+    fn get_legacy_reward_block(&self) -> Option<u64>;
+    fn set_legacy_reward_block(&self, block: u64);
+
     fn get_reward_storage(&self) -> Address;
     fn put_reward_storage(&self, contract: Address);
 
     fn get_reward_token(&self) -> Address;
     fn put_reward_token(&self, contract: Address);
     fn has_reward_token(&self) -> bool;
+
+    fn get_reward_paid(&self) -> u128;
+    fn put_reward_paid(&self, value: u128);
+
+    fn get_incentive(&self) -> Option<Incentive>;
+    fn set_incentive(&self, incentive: &Incentive);
+    fn remove_incentive(&self);
+
+    fn get_reward_streams_count(&self) -> u32;
+    fn put_reward_streams_count(&self, value: u32);
+
+    fn get_incentive_min_amount(&self, token: &Address) -> Option<u128>;
+    fn set_incentive_min_amount(&self, token: &Address, value: u128);
+    fn remove_incentive_min_amount(&self, token: &Address);
+
+    fn get_incentive_streams(&self, token: &Address) -> Vec<u32>;
+    fn set_incentive_streams(&self, token: &Address, streams: &Vec<u32>);
+
+    fn get_voting_escrow(&self) -> Option<Address>;
+    fn put_voting_escrow(&self, contract: Address);
+
+    fn get_working_supply(&self) -> u128;
+    fn put_working_supply(&self, value: u128);
+
+    fn get_working_balance(&self, user: &Address) -> Option<u128>;
+    fn put_working_balance(&self, user: &Address, value: u128);
+
+    fn get_claim_operator(&self, user: &Address, operator: &Address) -> bool;
+    fn set_claim_operator(&self, user: &Address, operator: &Address, approved: bool);
+
+    fn get_reward_precision(&self) -> u128;
+    fn put_reward_precision(&self, value: u128);
+
+    fn get_volume_mining_config(&self) -> Option<VolumeMiningConfig>;
+    fn set_volume_mining_config(&self, config: &VolumeMiningConfig);
+    fn remove_volume_mining_config(&self);
+
+    fn get_volume_epochs_count(&self) -> u32;
+    fn put_volume_epochs_count(&self, value: u32);
+
+    fn get_volume_epoch(&self, epoch: u32) -> Option<VolumeEpoch>;
+    fn set_volume_epoch(&self, epoch: u32, data: &VolumeEpoch);
+
+    fn get_volume_user_points(&self, epoch: u32, user: &Address) -> u128;
+    fn set_volume_user_points(&self, epoch: u32, user: &Address, value: u128);
+    fn remove_volume_user_points(&self, epoch: u32, user: &Address);
+
+    fn get_volume_outstanding(&self) -> u128;
+    fn put_volume_outstanding(&self, value: u128);
 }
 
 impl RewardsStorageTrait for Storage {
@@ -78,7 +332,7 @@ impl RewardsStorageTrait for Storage {
         self.env
             .storage()
             .instance()
-            .get(&DataKey::PoolRewardConfig)
+            .get(&self.pool_reward_config_key())
             .expect("Please, initialize pool reward config")
     }
 
@@ -86,14 +340,30 @@ impl RewardsStorageTrait for Storage {
         self.env
             .storage()
             .instance()
-            .set(&DataKey::PoolRewardConfig, config);
+            .set(&self.pool_reward_config_key(), config);
+    }
+
+    // reward configs waiting for activation, ordered by start time
+    fn get_reward_schedule(&self) -> Vec<ScheduledRewardConfig> {
+        self.env
+            .storage()
+            .instance()
+            .get(&self.reward_schedule_key())
+            .unwrap_or(Vec::new(&self.env))
+    }
+
+    fn set_reward_schedule(&self, schedule: &Vec<ScheduledRewardConfig>) {
+        self.env
+            .storage()
+            .instance()
+            .set(&self.reward_schedule_key(), schedule);
     }
 
     fn get_pool_reward_data(&self) -> PoolRewardData {
         self.env
             .storage()
             .instance()
-            .get(&DataKey::PoolRewardData)
+            .get(&self.pool_reward_data_key())
             .expect("Please, initialize pool reward data")
     }
 
@@ -101,7 +371,14 @@ impl RewardsStorageTrait for Storage {
         self.env
             .storage()
             .instance()
-            .set(&DataKey::PoolRewardData, data);
+            .set(&self.pool_reward_data_key(), data);
+    }
+
+    fn has_pool_reward_data(&self) -> bool {
+        self.env
+            .storage()
+            .instance()
+            .has(&self.pool_reward_data_key())
     }
 
     fn get_user_reward_data(&self, user: &Address) -> Option<UserRewardData> {
@@ -109,7 +386,7 @@ impl RewardsStorageTrait for Storage {
             .env
             .storage()
             .persistent()
-            .get(&DataKey::UserRewardData(user.clone()))
+            .get(&self.user_reward_data_key(user))
         {
             Some(data) => data,
             None => None,
@@ -120,11 +397,11 @@ impl RewardsStorageTrait for Storage {
         self.env
             .storage()
             .persistent()
-            .set(&DataKey::UserRewardData(user.clone()), config);
+            .set(&self.user_reward_data_key(user), config);
     }
 
     fn bump_user_reward_data(&self, user: &Address) {
-        bump_persistent(&self.env, &DataKey::UserRewardData(user.clone()))
+        bump_persistent(&self.env, &self.user_reward_data_key(user))
     }
 
     fn get_reward_inv_data(&mut self, pow: u32, page_number: u64) -> Map<u64, u128> {
@@ -156,11 +433,60 @@ impl RewardsStorageTrait for Storage {
         bump_persistent(&self.env, &DataKey::RewardInvData(pow, page_number))
     }
 
+    fn get_legacy_pool_reward_data(&self) -> Option<LegacyPoolRewardData> {
+        if !self.has_legacy_data() {
+            return None;
+        }
+        self.env.storage().instance().get(&DataKey::PoolRewardData)
+    }
+
+    fn remove_legacy_pool_reward_data(&self) {
+        self.env
+            .storage()
+            .instance()
+            .remove(&DataKey::PoolRewardData)
+    }
+
+    fn get_legacy_user_reward_data(&self, user: &Address) -> Option<LegacyUserRewardData> {
+        if !self.has_legacy_data() {
+            return None;
+        }
+        self.env
+            .storage()
+            .persistent()
+            .get(&DataKey::UserRewardData(user.clone()))
+    }
+
+    fn remove_legacy_user_reward_data(&self, user: &Address) {
+        self.env
+            .storage()
+            .persistent()
+            .remove(&DataKey::UserRewardData(user.clone()))
+    }
+
+    // last block of legacy rewards history, legacy user rewards are calculated up to it
+    fn get_legacy_reward_block(&self) -> Option<u64> {
+        if !self.has_legacy_data() {
+            return None;
+        }
+        self.env
+            .storage()
+            .instance()
+            .get(&DataKey::LegacyRewardBlock)
+    }
+
+    fn set_legacy_reward_block(&self, block: u64) {
+        self.env
+            .storage()
+            .instance()
+            .set(&DataKey::LegacyRewardBlock, &block)
+    }
+
     fn get_reward_storage(&self) -> Address {
         self.env
             .storage()
             .instance()
-            .get(&DataKey::RewardStorage)
+            .get(&self.reward_storage_key())
             .expect("Trying to get reward storage")
     }
 
@@ -168,7 +494,7 @@ impl RewardsStorageTrait for Storage {
         self.env
             .storage()
             .instance()
-            .get(&DataKey::RewardToken)
+            .get(&self.reward_token_key())
             .expect("Trying to get reward token")
     }
 
@@ -176,17 +502,300 @@ impl RewardsStorageTrait for Storage {
         self.env
             .storage()
             .instance()
-            .set(&DataKey::RewardToken, &contract);
+            .set(&self.reward_token_key(), &contract);
     }
 
     fn has_reward_token(&self) -> bool {
-        self.env.storage().instance().has(&DataKey::RewardToken)
+        self.env.storage().instance().has(&self.reward_token_key())
     }
 
     fn put_reward_storage(&self, contract: Address) {
         self.env
             .storage()
             .instance()
-            .set(&DataKey::RewardStorage, &contract)
+            .set(&self.reward_storage_key(), &contract)
+    }
+
+    // total amount of rewards claimed by users
+    fn get_reward_paid(&self) -> u128 {
+        self.env
+            .storage()
+            .instance()
+            .get(&self.reward_paid_key())
+            .unwrap_or(0)
+    }
+
+    fn put_reward_paid(&self, value: u128) {
+        self.env
+            .storage()
+            .instance()
+            .set(&self.reward_paid_key(), &value)
+    }
+
+    fn get_incentive(&self) -> Option<Incentive> {
+        self.env.storage().instance().get(&self.incentive_key())
+    }
+
+    fn set_incentive(&self, incentive: &Incentive) {
+        self.env
+            .storage()
+            .instance()
+            .set(&self.incentive_key(), incentive)
+    }
+
+    fn remove_incentive(&self) {
+        self.env.storage().instance().remove(&self.incentive_key())
+    }
+
+    // amount of reward streams including primary one
+    fn get_reward_streams_count(&self) -> u32 {
+        self.env
+            .storage()
+            .instance()
+            .get(&DataKey::RewardStreamsCount)
+            .unwrap_or(1)
+    }
+
+    fn put_reward_streams_count(&self, value: u32) {
+        self.env
+            .storage()
+            .instance()
+            .set(&DataKey::RewardStreamsCount, &value)
+    }
+
+    // minimum incentive amount of allowed token. none if token is not allowed
+    fn get_incentive_min_amount(&self, token: &Address) -> Option<u128> {
+        self.env
+            .storage()
+            .instance()
+            .get(&DataKey::IncentiveMinAmount(token.clone()))
+    }
+
+    fn set_incentive_min_amount(&self, token: &Address, value: u128) {
+        self.env
+            .storage()
+            .instance()
+            .set(&DataKey::IncentiveMinAmount(token.clone()), &value)
+    }
+
+    fn remove_incentive_min_amount(&self, token: &Address) {
+        self.env
+            .storage()
+            .instance()
+            .remove(&DataKey::IncentiveMinAmount(token.clone()))
+    }
+
+    // additional streams created for incentives in the token
+    fn get_incentive_streams(&self, token: &Address) -> Vec<u32> {
+        self.env
+            .storage()
+            .instance()
+            .get(&DataKey::IncentiveStreams(token.clone()))
+            .unwrap_or(Vec::new(&self.env))
+    }
+
+    fn set_incentive_streams(&self, token: &Address, streams: &Vec<u32>) {
+        self.env
+            .storage()
+            .instance()
+            .set(&DataKey::IncentiveStreams(token.clone()), streams)
+    }
+
+    fn get_voting_escrow(&self) -> Option<Address> {
+        self.env.storage().instance().get(&DataKey::VotingEscrow)
+    }
+
+    fn put_voting_escrow(&self, contract: Address) {
+        self.env
+            .storage()
+            .instance()
+            .set(&DataKey::VotingEscrow, &contract)
+    }
+
+    // sum of working balances of all users
+    fn get_working_supply(&self) -> u128 {
+        self.env
+            .storage()
+            .instance()
+            .get(&DataKey::WorkingSupply)
+            .unwrap_or(0)
+    }
+
+    fn put_working_supply(&self, value: u128) {
+        self.env
+            .storage()
+            .instance()
+            .set(&DataKey::WorkingSupply, &value)
+    }
+
+    fn get_working_balance(&self, user: &Address) -> Option<u128> {
+        let key = DataKey::WorkingBalance(user.clone());
+        let value = self.env.storage().persistent().get(&key);
+        if value.is_some() {
+            bump_persistent(&self.env, &key);
+        }
+        value
+    }
+
+    fn put_working_balance(&self, user: &Address, value: u128) {
+        let key = DataKey::WorkingBalance(user.clone());
+        self.env.storage().persistent().set(&key, &value);
+        bump_persistent(&self.env, &key);
+    }
+
+    fn get_claim_operator(&self, user: &Address, operator: &Address) -> bool {
+        let key = DataKey::ClaimOperator(user.clone(), operator.clone());
+        let value = self.env.storage().persistent().get(&key);
+        if value.is_some() {
+            bump_persistent(&self.env, &key);
+        }
+        value.unwrap_or(false)
+    }
+
+    fn set_claim_operator(&self, user: &Address, operator: &Address, approved: bool) {
+        let key = DataKey::ClaimOperator(user.clone(), operator.clone());
+        if approved {
+            self.env.storage().persistent().set(&key, &approved);
+            bump_persistent(&self.env, &key);
+        } else {
+            self.env.storage().persistent().remove(&key);
+        }
+    }
+
+    // pools initialized before precision became configurable use legacy one
+    fn get_reward_precision(&self) -> u128 {
+        self.env
+            .storage()
+            .instance()
+            .get(&DataKey::RewardPrecision)
+            .unwrap_or(LEGACY_REWARD_PRECISION)
+    }
+
+    fn put_reward_precision(&self, value: u128) {
+        self.env
+            .storage()
+            .instance()
+            .set(&DataKey::RewardPrecision, &value)
+    }
+    fn get_volume_mining_config(&self) -> Option<VolumeMiningConfig> {
+        self.env
+            .storage()
+            .instance()
+            .get(&DataKey::VolumeMiningConfig)
+    }
+
+    fn set_volume_mining_config(&self, config: &VolumeMiningConfig) {
+        self.env
+            .storage()
+            .instance()
+            .set(&DataKey::VolumeMiningConfig, config)
+    }
+
+    fn remove_volume_mining_config(&self) {
+        self.env
+            .storage()
+            .instance()
+            .remove(&DataKey::VolumeMiningConfig)
+    }
+
+    // amount of volume mining epochs started so far. the last one may be still in progress
+    fn get_volume_epochs_count(&self) -> u32 {
+        self.env
+            .storage()
+            .instance()
+            .get(&DataKey::VolumeEpochsCount)
+            .unwrap_or(0)
+    }
+
+    fn put_volume_epochs_count(&self, value: u32) {
+        self.env
+            .storage()
+            .instance()
+            .set(&DataKey::VolumeEpochsCount, &value)
+    }
+
+    fn get_volume_epoch(&self, epoch: u32) -> Option<VolumeEpoch> {
+        let key = DataKey::VolumeEpoch(epoch);
+        let value = self.env.storage().persistent().get(&key);
+        if value.is_some() {
+            bump_persistent(&self.env, &key);
+        }
+        value
+    }
+
+    fn set_volume_epoch(&self, epoch: u32, data: &VolumeEpoch) {
+        let key = DataKey::VolumeEpoch(epoch);
+        self.env.storage().persistent().set(&key, data);
+        bump_persistent(&self.env, &key);
+    }
+
+    fn get_volume_user_points(&self, epoch: u32, user: &Address) -> u128 {
+        let key = DataKey::VolumeUserPoints(epoch, user.clone());
+        let value = self.env.storage().persistent().get(&key);
+        if value.is_some() {
+            bump_persistent(&self.env, &key);
+        }
+        value.unwrap_or(0)
+    }
+
+    fn set_volume_user_points(&self, epoch: u32, user: &Address, value: u128) {
+        let key = DataKey::VolumeUserPoints(epoch, user.clone());
+        self.env.storage().persistent().set(&key, &value);
+        bump_persistent(&self.env, &key);
+    }
+
+    fn remove_volume_user_points(&self, epoch: u32, user: &Address) {
+        self.env
+            .storage()
+            .persistent()
+            .remove(&DataKey::VolumeUserPoints(epoch, user.clone()))
+    }
+
+    // budget of started volume mining epochs not claimed yet
+    fn get_volume_outstanding(&self) -> u128 {
+        self.env
+            .storage()
+            .instance()
+            .get(&DataKey::VolumeOutstanding)
+            .unwrap_or(0)
+    }
+
+    fn put_volume_outstanding(&self, value: u128) {
+        self.env
+            .storage()
+            .instance()
+            .set(&DataKey::VolumeOutstanding, &value)
+    }
+}
+
+// write rewards data in legacy format to test migration
+#[cfg(any(test, feature = "testutils"))]
+impl Storage {
+    pub fn set_legacy_pool_reward_data(&self, data: &LegacyPoolRewardData) {
+        self.env
+            .storage()
+            .instance()
+            .set(&DataKey::PoolRewardData, data);
+    }
+
+    pub fn set_legacy_user_reward_data(&self, user: &Address, data: &LegacyUserRewardData) {
+        self.env
+            .storage()
+            .persistent()
+            .set(&DataKey::UserRewardData(user.clone()), data);
+    }
+
+    pub fn remove_user_reward_data(&self, user: &Address) {
+        self.env
+            .storage()
+            .persistent()
+            .remove(&self.user_reward_data_key(user));
+    }
+
+    pub fn remove_pool_reward_data(&self) {
+        self.env
+            .storage()
+            .instance()
+            .remove(&self.pool_reward_data_key());
     }
 }
@@ -0,0 +1,183 @@
+use crate::constants::VOLUME_CLAIM_PERIOD;
+use crate::math::mul_div;
+use crate::storage::{RewardsStorageTrait, Storage, VolumeEpoch, VolumeMiningConfig};
+use soroban_sdk::{token::TokenClient as Client, Address, Env};
+
+// Trading volume mining. Traders collect points for swap fees paid within the epoch
+// and share epoch budget once it's finished. Budget is paid from primary stream reward storage
+pub struct VolumeMining {
+    env: Env,
+    storage: Storage,
+}
+
+impl VolumeMining {
+    pub fn new(e: &Env) -> VolumeMining {
+        VolumeMining {
+            env: e.clone(),
+            storage: Storage::new(e),
+        }
+    }
+
+    pub fn get_config(&self) -> Option<VolumeMiningConfig> {
+        self.storage.get_volume_mining_config()
+    }
+
+    // zero budget disables volume mining. epoch in progress is finished with its own budget
+    pub fn set_config(&self, epoch_length: u64, budget: u128) {
+        if budget == 0 {
+            self.storage.remove_volume_mining_config();
+            return;
+        }
+        if epoch_length == 0 {
+            panic!("volume epoch length should be positive")
+        }
+        self.storage.set_volume_mining_config(&VolumeMiningConfig {
+            epoch_length,
+            budget,
+        });
+    }
+
+    pub fn epochs_count(&self) -> u32 {
+        self.storage.get_volume_epochs_count()
+    }
+
+    pub fn get_epoch(&self, epoch: u32) -> Option<VolumeEpoch> {
+        self.storage.get_volume_epoch(epoch)
+    }
+
+    pub fn get_outstanding(&self) -> u128 {
+        self.storage.get_volume_outstanding()
+    }
+
+    // epoch collecting points at the moment, if any
+    pub fn get_open_epoch(&self) -> Option<u32> {
+        let count = self.epochs_count();
+        if count == 0 {
+            return None;
+        }
+        self.storage
+            .get_volume_epoch(count - 1)
+            .filter(|data| data.end_at > self.env.ledger().timestamp())
+            .map(|_| count - 1)
+    }
+
+    // start new epoch with actual config. returns epoch number
+    pub fn open_epoch(&self, config: &VolumeMiningConfig) -> u32 {
+        let epoch = self.epochs_count();
+        self.storage.put_volume_epochs_count(epoch + 1);
+        self.storage
+            .put_volume_outstanding(self.get_outstanding() + config.budget);
+        self.storage.set_volume_epoch(
+            epoch,
+            &VolumeEpoch {
+                budget: config.budget,
+                total_points: 0,
+                end_at: self.env.ledger().timestamp() + config.epoch_length,
+                claimed: 0,
+                swept: false,
+            },
+        );
+        epoch
+    }
+
+    // credit user with points in the epoch. points saturate so swap is never blocked
+    pub fn add_points(&self, user: &Address, epoch: u32, points: u128) {
+        let mut data = self
+            .storage
+            .get_volume_epoch(epoch)
+            .expect("volume epoch doesn't exist");
+        data.total_points = data.total_points.saturating_add(points);
+        self.storage.set_volume_epoch(epoch, &data);
+        let user_points = self.storage.get_volume_user_points(epoch, user);
+        self.storage
+            .set_volume_user_points(epoch, user, user_points.saturating_add(points));
+    }
+
+    pub fn get_user_points(&self, user: &Address, epoch: u32) -> u128 {
+        self.storage.get_volume_user_points(epoch, user)
+    }
+
+    // user share of finished epoch budget.
+    // zero while epoch is in progress and once its claim period is over
+    pub fn get_user_reward(&self, user: &Address, epoch: u32) -> u128 {
+        let now = self.env.ledger().timestamp();
+        match self.storage.get_volume_epoch(epoch) {
+            Some(data) if data.end_at <= now && now < data.end_at + VOLUME_CLAIM_PERIOD => {
+                self.calculate_reward(&data, self.get_user_points(user, epoch))
+            }
+            _ => 0,
+        }
+    }
+
+    // transfer user share of finished epoch budget. returns amount transferred
+    pub fn claim(&self, user: &Address, epoch: u32) -> u128 {
+        let mut data = self
+            .storage
+            .get_volume_epoch(epoch)
+            .expect("volume epoch doesn't exist");
+        let now = self.env.ledger().timestamp();
+        if data.end_at > now {
+            panic!("volume epoch is not finished yet")
+        }
+        if now >= data.end_at + VOLUME_CLAIM_PERIOD {
+            panic!("volume epoch claim period is over")
+        }
+
+        let reward = self.calculate_reward(&data, self.get_user_points(user, epoch));
+        self.storage.remove_volume_user_points(epoch, user);
+        if reward == 0 {
+            return 0;
+        }
+
+        data.claimed += reward;
+        self.storage.set_volume_epoch(epoch, &data);
+        self.storage
+            .put_volume_outstanding(self.get_outstanding() - reward);
+        let reward_token = self.storage.get_reward_token();
+        let rewards_storage = self.storage.get_reward_storage();
+        if rewards_storage == self.env.current_contract_address() {
+            Client::new(&self.env, &reward_token).transfer(
+                &rewards_storage,
+                user,
+                &(reward as i128),
+            );
+        } else {
+            Client::new(&self.env, &reward_token).transfer_from(
+                &self.env.current_contract_address(),
+                &rewards_storage,
+                user,
+                &(reward as i128),
+            );
+        }
+        reward
+    }
+
+    // release budget left unclaimed once epoch claim period is over, including rounding dust.
+    // returns amount released
+    pub fn sweep(&self, epoch: u32) -> u128 {
+        let mut data = self
+            .storage
+            .get_volume_epoch(epoch)
+            .expect("volume epoch doesn't exist");
+        if self.env.ledger().timestamp() < data.end_at + VOLUME_CLAIM_PERIOD {
+            panic!("volume epoch claim period is not over yet")
+        }
+        if data.swept {
+            panic!("volume epoch is already swept")
+        }
+
+        let leftover = data.budget - data.claimed;
+        data.swept = true;
+        self.storage.set_volume_epoch(epoch, &data);
+        self.storage
+            .put_volume_outstanding(self.get_outstanding() - leftover);
+        leftover
+    }
+
+    fn calculate_reward(&self, data: &VolumeEpoch, user_points: u128) -> u128 {
+        if data.total_points == 0 {
+            return 0;
+        }
+        mul_div(&self.env, data.budget, user_points, data.total_points)
+    }
+}
@@ -0,0 +1,12 @@
+use soroban_sdk::{contractclient, Address, Env};
+
+// Interface of vote-escrow contract used to boost user rewards
+#[allow(dead_code)]
+#[contractclient(name = "VotingEscrowClient")]
+pub trait VotingEscrowInterface {
+    // Get current voting power of the user
+    fn balance(e: Env, user: Address) -> u128;
+
+    // Get current total voting power
+    fn total_supply(e: Env) -> u128;
+}
@@ -0,0 +1,2 @@
+// rewards history page size. gauge has no block based history, so it's never used
+pub(crate) const PAGE_SIZE: u64 = 1000;
@@ -0,0 +1,182 @@
+use crate::events::{Events, StakingGaugeEvents};
+use crate::interface::{RewardsInterface, StakingGaugeInterface};
+use crate::pool::LiquidityPoolClient;
+use crate::rewards::{get_reserved_balance, get_rewards_manager};
+use crate::storage::{
+    get_lp_token, get_total_staked, get_user_staked, has_lp_token, set_lp_token, set_total_staked,
+    set_user_staked,
+};
+use access_control::access::{AccessControl, AccessControlTrait};
+use access_control::interface::{RolesInterface, TransferableContract};
+use access_control::role::Role;
+use rewards::storage::{PoolRewardConfig, RewardsFunding, RewardsInfo, RewardsStorageTrait};
+use rewards::DEFAULT_REWARD_PRECISION;
+use soroban_sdk::token::TokenClient;
+use soroban_sdk::{contract, contractimpl, Address, Env, Vec};
+use utils::bump::bump_instance;
+
+#[contract]
+pub struct StakingGauge;
+
+#[contractimpl]
+impl StakingGaugeInterface for StakingGauge {
+    fn initialize(e: Env, admin: Address, lp_token: Address, reward_token: Address) {
+        if has_lp_token(&e) {
+            panic!("already initialized")
+        }
+
+        let access_control = AccessControl::new(&e);
+        access_control.set_admin(&admin);
+        set_lp_token(&e, &lp_token);
+
+        let rewards = get_rewards_manager(&e);
+        rewards.storage().put_reward_token(reward_token);
+        rewards
+            .storage()
+            .put_reward_storage(e.current_contract_address());
+        rewards.manager().initialize();
+        rewards.initialize_precision(DEFAULT_REWARD_PRECISION);
+    }
+
+    fn deposit(e: Env, user: Address, amount: u128) {
+        user.require_auth();
+        if amount == 0 {
+            panic!("zero amount")
+        }
+
+        let total_staked = get_total_staked(&e);
+        let user_staked = get_user_staked(&e, &user);
+        get_rewards_manager(&e).checkpoint_user(&user, total_staked, user_staked);
+
+        TokenClient::new(&e, &get_lp_token(&e)).transfer(
+            &user,
+            &e.current_contract_address(),
+            &(amount as i128),
+        );
+        set_total_staked(&e, &(total_staked + amount));
+        set_user_staked(&e, &user, user_staked + amount);
+
+        Events::new(&e).deposit(user, amount);
+    }
+
+    fn withdraw(e: Env, user: Address, amount: u128) {
+        user.require_auth();
+        if amount == 0 {
+            panic!("zero amount")
+        }
+
+        let total_staked = get_total_staked(&e);
+        let user_staked = get_user_staked(&e, &user);
+        if amount > user_staked {
+            panic!("insufficient staked balance")
+        }
+        get_rewards_manager(&e).checkpoint_user(&user, total_staked, user_staked);
+
+        set_total_staked(&e, &(total_staked - amount));
+        set_user_staked(&e, &user, user_staked - amount);
+        TokenClient::new(&e, &get_lp_token(&e)).transfer(
+            &e.current_contract_address(),
+            &user,
+            &(amount as i128),
+        );
+
+        Events::new(&e).withdraw(user, amount);
+    }
+
+    fn get_lp_token(e: Env) -> Address {
+        get_lp_token(&e)
+    }
+
+    fn get_user_staked(e: Env, user: Address) -> u128 {
+        get_user_staked(&e, &user)
+    }
+
+    fn get_total_staked(e: Env) -> u128 {
+        get_total_staked(&e)
+    }
+}
+
+#[contractimpl]
+impl RewardsInterface for StakingGauge {
+    fn set_rewards_config(e: Env, admin: Address, expired_at: u64, tps: u128) {
+        let access_control = AccessControl::new(&e);
+        access_control.require_role(Role::RewardsAdmin, &admin);
+
+        let rewards = get_rewards_manager(&e);
+        rewards.manager().update_rewards_data(get_total_staked(&e));
+        bump_instance(&e);
+        rewards
+            .storage()
+            .set_pool_reward_config(&PoolRewardConfig { tps, expired_at });
+        if tps > 0 {
+            rewards.check_funding(get_reserved_balance(&e, &rewards));
+        }
+
+        Events::new(&e).set_rewards_config(expired_at, tps);
+    }
+
+    fn get_rewards_info(e: Env, user: Address) -> RewardsInfo {
+        get_rewards_manager(&e).get_rewards_info(
+            &user,
+            get_total_staked(&e),
+            get_user_staked(&e, &user),
+        )
+    }
+
+    fn get_user_reward(e: Env, user: Address) -> u128 {
+        Self::get_rewards_info(e, user).to_claim
+    }
+
+    fn claim(e: Env, user: Address) -> u128 {
+        user.require_auth();
+
+        let rewards = get_rewards_manager(&e);
+        let reward =
+            rewards
+                .manager()
+                .claim_reward(&user, get_total_staked(&e), get_user_staked(&e, &user));
+        rewards.storage().bump_user_reward_data(&user);
+
+        Events::new(&e).claim(user, reward);
+        reward
+    }
+
+    fn get_reward_token(e: Env) -> Address {
+        get_rewards_manager(&e).storage().get_reward_token()
+    }
+
+    fn get_rewards_funding(e: Env) -> RewardsFunding {
+        let rewards = get_rewards_manager(&e);
+        rewards.get_funding(get_reserved_balance(&e, &rewards))
+    }
+
+    fn claim_pool_rewards(e: Env, admin: Address, pool: Address, recipient: Address) -> Vec<u128> {
+        let access_control = AccessControl::new(&e);
+        access_control.require_role(Role::RewardsAdmin, &admin);
+
+        let pool_client = LiquidityPoolClient::new(&e, &pool);
+        if pool_client.share_id() != get_lp_token(&e) {
+            panic!("pool share token is not staked")
+        }
+
+        let gauge = e.current_contract_address();
+        let rewards_info = pool_client.get_all_rewards_info(&gauge);
+        let claimed = pool_client.claim_all_rewards(&gauge);
+        if recipient != gauge {
+            for (stream, amount) in claimed.iter().enumerate() {
+                if amount == 0 {
+                    continue;
+                }
+                let (token, _) = rewards_info.get(stream as u32).unwrap();
+                TokenClient::new(&e, &token).transfer(&gauge, &recipient, &(amount as i128));
+            }
+        }
+
+        Events::new(&e).claim_pool_rewards(pool, recipient, claimed.clone());
+        claimed
+    }
+}
+
+access_control::generate_transferable_contract!(StakingGauge);
+
+access_control::generate_roles_interface!(StakingGauge);
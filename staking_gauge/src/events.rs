@@ -0,0 +1,66 @@
+use soroban_sdk::{Address, Env, Symbol, Vec};
+
+#[derive(Clone)]
+pub(crate) struct Events(Env);
+
+impl Events {
+    #[inline(always)]
+    pub(crate) fn env(&self) -> &Env {
+        &self.0
+    }
+
+    #[inline(always)]
+    pub(crate) fn new(env: &Env) -> Events {
+        Events(env.clone())
+    }
+}
+
+pub(crate) trait StakingGaugeEvents {
+    fn deposit(&self, user: Address, amount: u128);
+
+    fn withdraw(&self, user: Address, amount: u128);
+
+    fn claim(&self, user: Address, reward: u128);
+
+    fn set_rewards_config(&self, expired_at: u64, tps: u128);
+
+    fn claim_pool_rewards(&self, pool: Address, recipient: Address, rewards: Vec<u128>);
+}
+
+impl StakingGaugeEvents for Events {
+    fn deposit(&self, user: Address, amount: u128) {
+        self.env()
+            .events()
+            .publish((Symbol::new(self.env(), "deposit"), user), (amount,));
+    }
+
+    fn withdraw(&self, user: Address, amount: u128) {
+        self.env()
+            .events()
+            .publish((Symbol::new(self.env(), "withdraw"), user), (amount,));
+    }
+
+    fn claim(&self, user: Address, reward: u128) {
+        self.env()
+            .events()
+            .publish((Symbol::new(self.env(), "claim"), user), (reward,));
+    }
+
+    fn set_rewards_config(&self, expired_at: u64, tps: u128) {
+        self.env().events().publish(
+            (Symbol::new(self.env(), "set_rewards_config"),),
+            (expired_at, tps),
+        );
+    }
+
+    fn claim_pool_rewards(&self, pool: Address, recipient: Address, rewards: Vec<u128>) {
+        self.env().events().publish(
+            (
+                Symbol::new(self.env(), "claim_pool_rewards"),
+                pool,
+                recipient,
+            ),
+            (rewards,),
+        );
+    }
+}
@@ -0,0 +1,52 @@
+use rewards::storage::{RewardsFunding, RewardsInfo};
+use soroban_sdk::{Address, Env, Vec};
+
+pub trait StakingGaugeInterface {
+    // Initialize gauge. Will panic if called twice
+    // lp_token: SEP-41 token users stake to earn rewards
+    // reward_token: token distributed as reward. Rewards are paid from gauge balance
+    fn initialize(e: Env, admin: Address, lp_token: Address, reward_token: Address);
+
+    // Stake amount of lp token
+    fn deposit(e: Env, user: Address, amount: u128);
+
+    // Unstake amount of lp token
+    fn withdraw(e: Env, user: Address, amount: u128);
+
+    // Get staked token address
+    fn get_lp_token(e: Env) -> Address;
+
+    // Get amount of lp token staked by the user
+    fn get_user_staked(e: Env, user: Address) -> u128;
+
+    // Get amount of lp token staked by all users
+    fn get_total_staked(e: Env) -> u128;
+}
+
+pub trait RewardsInterface {
+    // Configure rewards. Every second tps of coins
+    // being distributed across all stakers
+    // after expired_at timestamp distribution ends. Only rewards admin
+    fn set_rewards_config(e: Env, admin: Address, expired_at: u64, tps: u128);
+
+    // Get rewards status, including amount available for the user. Nothing is written
+    fn get_rewards_info(e: Env, user: Address) -> RewardsInfo;
+
+    // Get amount of reward tokens available for the user to claim
+    fn get_user_reward(e: Env, user: Address) -> u128;
+
+    // Claim reward as a user.
+    // returns amount of tokens rewarded to the user
+    fn claim(e: Env, user: Address) -> u128;
+
+    // Get reward token address
+    fn get_reward_token(e: Env) -> Address;
+
+    // Get committed, paid and available amounts of reward token
+    fn get_rewards_funding(e: Env) -> RewardsFunding;
+
+    // Claim rewards staked share tokens earn in the pool and send them to recipient.
+    // Recipient may be the gauge itself to fund its rewards. Only rewards admin
+    // returns amount of tokens claimed from every pool reward stream
+    fn claim_pool_rewards(e: Env, admin: Address, pool: Address, recipient: Address) -> Vec<u128>;
+}
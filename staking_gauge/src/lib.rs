@@ -0,0 +1,12 @@
+#![no_std]
+
+mod constants;
+mod contract;
+mod events;
+mod interface;
+mod pool;
+mod rewards;
+mod storage;
+mod test;
+
+pub use crate::contract::{StakingGauge, StakingGaugeClient};
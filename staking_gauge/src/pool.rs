@@ -0,0 +1,13 @@
+use rewards::storage::RewardsInfo;
+use soroban_sdk::{contractclient, Address, Env, Vec};
+
+// Part of liquidity pool interface used to claim rewards accrued on staked share tokens
+#[allow(dead_code)]
+#[contractclient(name = "LiquidityPoolClient")]
+pub trait LiquidityPoolInterface {
+    fn share_id(e: Env) -> Address;
+
+    fn get_all_rewards_info(e: Env, user: Address) -> Vec<(Address, RewardsInfo)>;
+
+    fn claim_all_rewards(e: Env, user: Address) -> Vec<u128>;
+}
@@ -0,0 +1,18 @@
+use crate::constants::PAGE_SIZE;
+use crate::storage::{get_lp_token, get_total_staked};
+use rewards::storage::RewardsStorageTrait;
+use rewards::Rewards;
+use soroban_sdk::Env;
+
+pub(crate) fn get_rewards_manager(e: &Env) -> Rewards {
+    Rewards::new(e, PAGE_SIZE)
+}
+
+// amount of reward token held by the gauge as staked liquidity
+pub(crate) fn get_reserved_balance(e: &Env, rewards: &Rewards) -> u128 {
+    if rewards.storage().get_reward_token() == get_lp_token(e) {
+        get_total_staked(e)
+    } else {
+        0
+    }
+}
@@ -0,0 +1,45 @@
+use paste::paste;
+use soroban_sdk::{contracttype, Address, Env};
+use utils::bump::{bump_instance, bump_persistent};
+use utils::{
+    generate_instance_storage_getter, generate_instance_storage_getter_and_setter,
+    generate_instance_storage_getter_and_setter_with_default,
+    generate_instance_storage_getter_with_default, generate_instance_storage_setter,
+};
+
+#[derive(Clone)]
+#[contracttype]
+enum DataKey {
+    LpToken,
+    TotalStaked,
+    UserStaked(Address),
+}
+
+generate_instance_storage_getter_and_setter!(lp_token, DataKey::LpToken, Address);
+generate_instance_storage_getter_and_setter_with_default!(
+    total_staked,
+    DataKey::TotalStaked,
+    u128,
+    0
+);
+
+pub fn has_lp_token(e: &Env) -> bool {
+    e.storage().instance().has(&DataKey::LpToken)
+}
+
+pub fn get_user_staked(e: &Env, user: &Address) -> u128 {
+    let key = DataKey::UserStaked(user.clone());
+    match e.storage().persistent().get(&key) {
+        Some(value) => {
+            bump_persistent(e, &key);
+            value
+        }
+        None => 0,
+    }
+}
+
+pub fn set_user_staked(e: &Env, user: &Address, value: u128) {
+    let key = DataKey::UserStaked(user.clone());
+    e.storage().persistent().set(&key, &value);
+    bump_persistent(e, &key);
+}
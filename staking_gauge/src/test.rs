@@ -0,0 +1,193 @@
+#![cfg(test)]
+extern crate std;
+
+use crate::StakingGaugeClient;
+use access_control::role::Role;
+use rewards::storage::RewardsFunding;
+use soroban_sdk::testutils::{Address as _, Events, Ledger, LedgerInfo};
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{vec, Address, Env, IntoVal, Symbol};
+
+fn jump(e: &Env, time: u64) {
+    e.ledger().set(LedgerInfo {
+        timestamp: e.ledger().timestamp().saturating_add(time),
+        protocol_version: 20,
+        sequence_number: e.ledger().sequence(),
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: 999999,
+        min_persistent_entry_ttl: 999999,
+        max_entry_ttl: u32::MAX,
+    });
+}
+
+fn create_token<'a>(e: &Env) -> (TokenClient<'a>, StellarAssetClient<'a>) {
+    let address = e.register_stellar_asset_contract(Address::generate(e));
+    (
+        TokenClient::new(e, &address),
+        StellarAssetClient::new(e, &address),
+    )
+}
+
+struct Setup<'a> {
+    env: Env,
+    admin: Address,
+    users: [Address; 2],
+    lp_token: TokenClient<'a>,
+    reward_token: TokenClient<'a>,
+    reward_token_admin: StellarAssetClient<'a>,
+    gauge: StakingGaugeClient<'a>,
+}
+
+impl Default for Setup<'_> {
+    fn default() -> Self {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let admin = Address::generate(&env);
+        let users = [Address::generate(&env), Address::generate(&env)];
+        let (lp_token, lp_token_admin) = create_token(&env);
+        let (reward_token, reward_token_admin) = create_token(&env);
+        for user in users.iter() {
+            lp_token_admin.mint(user, &1000);
+        }
+
+        let gauge =
+            StakingGaugeClient::new(&env, &env.register_contract(None, crate::StakingGauge));
+        gauge.initialize(&admin, &lp_token.address, &reward_token.address);
+
+        Setup {
+            env,
+            admin,
+            users,
+            lp_token,
+            reward_token,
+            reward_token_admin,
+            gauge,
+        }
+    }
+}
+
+#[test]
+fn test_stake_and_claim() {
+    let setup = Setup::default();
+    let e = &setup.env;
+    let gauge = &setup.gauge;
+    let [user1, user2] = &setup.users;
+
+    setup.reward_token_admin.mint(&gauge.address, &1000_0000000);
+    gauge.set_rewards_config(&setup.admin, &(e.ledger().timestamp() + 100), &1_0000000);
+
+    gauge.deposit(user1, &100);
+    assert_eq!(
+        vec![e, e.events().all().last().unwrap()],
+        vec![
+            e,
+            (
+                gauge.address.clone(),
+                (Symbol::new(e, "deposit"), user1.clone()).into_val(e),
+                (100_u128,).into_val(e),
+            )
+        ]
+    );
+    assert_eq!(setup.lp_token.balance(user1), 900);
+    assert_eq!(setup.lp_token.balance(&gauge.address), 100);
+
+    jump(e, 10);
+    gauge.deposit(user2, &300);
+    assert_eq!(gauge.get_total_staked(), 400);
+    assert_eq!(gauge.get_user_staked(user2), 300);
+
+    jump(e, 20);
+    assert_eq!(gauge.get_user_reward(user1), 10_0000000 + 5_0000000);
+    assert_eq!(gauge.get_user_reward(user2), 15_0000000);
+    assert_eq!(gauge.claim(user1), 15_0000000);
+    assert_eq!(setup.reward_token.balance(user1), 15_0000000);
+    assert_eq!(gauge.get_user_reward(user1), 0);
+
+    gauge.withdraw(user2, &300);
+    assert_eq!(setup.lp_token.balance(user2), 1000);
+    jump(e, 10);
+    // the only staker receives everything
+    assert_eq!(gauge.get_user_reward(user1), 10_0000000);
+    assert_eq!(gauge.claim(user2), 15_0000000);
+    assert_eq!(
+        gauge.get_rewards_funding(),
+        RewardsFunding {
+            committed: 100_0000000,
+            paid: 30_0000000,
+            available: 970_0000000,
+        }
+    );
+}
+
+#[test]
+fn test_withdraw_more_than_staked() {
+    let setup = Setup::default();
+    let [user1, _user2] = &setup.users;
+
+    setup.gauge.deposit(user1, &100);
+    assert!(setup.gauge.try_withdraw(user1, &101).is_err());
+    setup.gauge.withdraw(user1, &100);
+    assert_eq!(setup.gauge.get_user_staked(user1), 0);
+}
+
+#[test]
+fn test_rewards_config_funding_check() {
+    let setup = Setup::default();
+    let e = &setup.env;
+    let gauge = &setup.gauge;
+    let expired_at = e.ledger().timestamp() + 100;
+
+    setup.reward_token_admin.mint(&gauge.address, &99_0000000);
+    assert!(gauge
+        .try_set_rewards_config(&setup.admin, &expired_at, &1_0000000)
+        .is_err());
+    setup.reward_token_admin.mint(&gauge.address, &1_0000000);
+    gauge.set_rewards_config(&setup.admin, &expired_at, &1_0000000);
+}
+
+#[test]
+fn test_staked_reward_token_is_reserved() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+    let (token, token_admin) = create_token(&env);
+    let gauge = StakingGaugeClient::new(&env, &env.register_contract(None, crate::StakingGauge));
+    gauge.initialize(&admin, &token.address, &token.address);
+
+    token_admin.mint(&user, &100_0000000);
+    gauge.deposit(&user, &100_0000000);
+    // staked tokens can't be used to pay rewards
+    let expired_at = env.ledger().timestamp() + 100;
+    assert!(gauge
+        .try_set_rewards_config(&admin, &expired_at, &1_0000000)
+        .is_err());
+}
+
+#[test]
+fn test_rewards_config_requires_role() {
+    let setup = Setup::default();
+    let e = &setup.env;
+    let gauge = &setup.gauge;
+    let manager = Address::generate(e);
+    let expired_at = e.ledger().timestamp() + 100;
+
+    assert!(gauge
+        .try_set_rewards_config(&manager, &expired_at, &0)
+        .is_err());
+    gauge.grant_role(&setup.admin, &Role::RewardsAdmin, &manager);
+    gauge.set_rewards_config(&manager, &expired_at, &0);
+}
+
+#[test]
+#[should_panic(expected = "already initialized")]
+fn test_initialize_twice() {
+    let setup = Setup::default();
+    setup.gauge.initialize(
+        &setup.admin,
+        &setup.lp_token.address,
+        &setup.reward_token.address,
+    );
+}
@@ -0,0 +1,3 @@
+pub(crate) const MAX_DELAY: u64 = 30 * 86400;
+// ready operation can be executed within grace period, after that it expires
+pub(crate) const GRACE_PERIOD: u64 = 14 * 86400;
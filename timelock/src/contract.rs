@@ -0,0 +1,204 @@
+use crate::constants::{GRACE_PERIOD, MAX_DELAY};
+use crate::events::{Events, TimelockEvents};
+use crate::interface::TimelockInterface;
+use crate::storage::{
+    get_min_delay, get_operation, has_min_delay, remove_operation, set_min_delay, set_operation,
+    Operation, OperationState,
+};
+use access_control::access::{AccessControl, AccessControlTrait};
+use access_control::interface::{RolesInterface, TransferableContract};
+use access_control::role::Role;
+use access_control::transfer::TransferOwnershipTrait;
+use soroban_sdk::{
+    contract, contractimpl, xdr::ToXdr, Address, Bytes, BytesN, Env, FromVal, Symbol, Val, Vec,
+};
+
+#[contract]
+pub struct Timelock;
+
+fn check_delay(delay: u64) {
+    if delay == 0 || delay > MAX_DELAY {
+        panic!("delay is out of allowed range")
+    }
+}
+
+fn operation_state(e: &Env, operation: &Option<Operation>) -> OperationState {
+    match operation {
+        None => OperationState::Unset,
+        Some(operation) => {
+            let now = e.ledger().timestamp();
+            if operation.executed {
+                OperationState::Done
+            } else if now < operation.eta {
+                OperationState::Pending
+            } else if now > operation.eta + GRACE_PERIOD {
+                OperationState::Expired
+            } else {
+                OperationState::Ready
+            }
+        }
+    }
+}
+
+// calls to timelock itself are handled in place as contract re-entry is not allowed.
+// roles and ownership calls take the same arguments as the entrypoints and are accepted
+// once timelock is its own admin, so they can only be done with a delay
+fn execute_self_call(e: &Env, function: &Symbol, args: &Vec<Val>) -> Val {
+    let arg = |index: u32| args.get(index).expect("call argument is missing");
+    if function == &Symbol::new(e, "set_min_delay") {
+        let delay = u64::from_val(e, &arg(0));
+        check_delay(delay);
+        set_min_delay(e, &delay);
+        Events::new(e).set_min_delay(delay);
+        return ().into();
+    }
+
+    // operation is authorized by proposer, admin argument should be timelock itself
+    let admin = Address::from_val(e, &arg(0));
+    if admin != e.current_contract_address() {
+        panic!("timelock is not the admin")
+    }
+    let access_control = AccessControl::new(e);
+    access_control.check_admin(&admin);
+    if function == &Symbol::new(e, "grant_role") {
+        access_control.grant_role(Role::from_val(e, &arg(1)), &Address::from_val(e, &arg(2)));
+    } else if function == &Symbol::new(e, "revoke_role") {
+        access_control.revoke_role(Role::from_val(e, &arg(1)), &Address::from_val(e, &arg(2)));
+    } else if function == &Symbol::new(e, "commit_transfer_ownership") {
+        access_control.commit_transfer(&Address::from_val(e, &arg(1)));
+    } else if function == &Symbol::new(e, "apply_transfer_ownership") {
+        access_control.apply_transfer();
+    } else if function == &Symbol::new(e, "revert_transfer_ownership") {
+        access_control.revert_transfer();
+    } else if function == &Symbol::new(e, "set_transfer_delay") {
+        access_control.set_transfer_delay(u64::from_val(e, &arg(1)));
+    } else {
+        panic!("unsupported timelock call")
+    }
+    ().into()
+}
+
+#[contractimpl]
+impl TimelockInterface for Timelock {
+    fn initialize(e: Env, admin: Address, min_delay: u64) {
+        if has_min_delay(&e) {
+            panic!("already initialized")
+        }
+        check_delay(min_delay);
+
+        let access_control = AccessControl::new(&e);
+        access_control.set_admin(&admin);
+        set_min_delay(&e, &min_delay);
+    }
+
+    fn schedule(
+        e: Env,
+        proposer: Address,
+        contract: Address,
+        function: Symbol,
+        args: Vec<Val>,
+        salt: BytesN<32>,
+        delay: u64,
+    ) -> BytesN<32> {
+        let access_control = AccessControl::new(&e);
+        access_control.require_role(Role::Proposer, &proposer);
+
+        if delay < get_min_delay(&e) {
+            panic!("insufficient delay")
+        }
+        check_delay(delay);
+
+        let id = Self::hash_operation(
+            e.clone(),
+            contract.clone(),
+            function.clone(),
+            args.clone(),
+            salt.clone(),
+        );
+        if get_operation(&e, &id).is_some() {
+            panic!("operation already scheduled")
+        }
+
+        let eta = e.ledger().timestamp() + delay;
+        set_operation(
+            &e,
+            &id,
+            &Operation {
+                contract: contract.clone(),
+                function: function.clone(),
+                args: args.clone(),
+                salt,
+                eta,
+                executed: false,
+            },
+        );
+        Events::new(&e).schedule(id.clone(), contract, function, args, eta);
+        id
+    }
+
+    fn execute(e: Env, id: BytesN<32>) -> Val {
+        let operation = get_operation(&e, &id);
+        match operation_state(&e, &operation) {
+            OperationState::Ready => {}
+            OperationState::Unset => panic!("operation not found"),
+            OperationState::Pending => panic!("operation is not ready"),
+            OperationState::Done => panic!("operation already executed"),
+            OperationState::Expired => panic!("operation expired"),
+        }
+
+        let mut operation = operation.unwrap();
+        operation.executed = true;
+        set_operation(&e, &id, &operation);
+        Events::new(&e).execute(id, operation.contract.clone(), operation.function.clone());
+
+        if operation.contract == e.current_contract_address() {
+            return execute_self_call(&e, &operation.function, &operation.args);
+        }
+        e.invoke_contract(&operation.contract, &operation.function, operation.args)
+    }
+
+    fn cancel(e: Env, proposer: Address, id: BytesN<32>) {
+        let access_control = AccessControl::new(&e);
+        access_control.require_role(Role::Proposer, &proposer);
+
+        match operation_state(&e, &get_operation(&e, &id)) {
+            OperationState::Pending | OperationState::Ready | OperationState::Expired => {}
+            OperationState::Unset => panic!("operation not found"),
+            OperationState::Done => panic!("operation already executed"),
+        }
+
+        remove_operation(&e, &id);
+        Events::new(&e).cancel(id);
+    }
+
+    fn hash_operation(
+        e: Env,
+        contract: Address,
+        function: Symbol,
+        args: Vec<Val>,
+        salt: BytesN<32>,
+    ) -> BytesN<32> {
+        let mut data = Bytes::new(&e);
+        data.append(&contract.to_xdr(&e));
+        data.append(&function.to_xdr(&e));
+        data.append(&args.to_xdr(&e));
+        data.append(&salt.to_xdr(&e));
+        e.crypto().sha256(&data)
+    }
+
+    fn get_operation(e: Env, id: BytesN<32>) -> Operation {
+        get_operation(&e, &id).expect("operation not found")
+    }
+
+    fn get_operation_state(e: Env, id: BytesN<32>) -> OperationState {
+        operation_state(&e, &get_operation(&e, &id))
+    }
+
+    fn get_min_delay(e: Env) -> u64 {
+        get_min_delay(&e)
+    }
+}
+
+access_control::generate_transferable_contract!(Timelock);
+
+access_control::generate_roles_interface!(Timelock);
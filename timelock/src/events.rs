@@ -0,0 +1,68 @@
+use soroban_sdk::{Address, BytesN, Env, Symbol, Val, Vec};
+
+#[derive(Clone)]
+pub(crate) struct Events(Env);
+
+impl Events {
+    #[inline(always)]
+    pub(crate) fn env(&self) -> &Env {
+        &self.0
+    }
+
+    #[inline(always)]
+    pub(crate) fn new(env: &Env) -> Events {
+        Events(env.clone())
+    }
+}
+
+pub(crate) trait TimelockEvents {
+    fn schedule(
+        &self,
+        id: BytesN<32>,
+        contract: Address,
+        function: Symbol,
+        args: Vec<Val>,
+        eta: u64,
+    );
+
+    fn execute(&self, id: BytesN<32>, contract: Address, function: Symbol);
+
+    fn cancel(&self, id: BytesN<32>);
+
+    fn set_min_delay(&self, delay: u64);
+}
+
+impl TimelockEvents for Events {
+    fn schedule(
+        &self,
+        id: BytesN<32>,
+        contract: Address,
+        function: Symbol,
+        args: Vec<Val>,
+        eta: u64,
+    ) {
+        self.env().events().publish(
+            (Symbol::new(self.env(), "schedule"), id),
+            (contract, function, args, eta),
+        );
+    }
+
+    fn execute(&self, id: BytesN<32>, contract: Address, function: Symbol) {
+        self.env().events().publish(
+            (Symbol::new(self.env(), "execute"), id),
+            (contract, function),
+        );
+    }
+
+    fn cancel(&self, id: BytesN<32>) {
+        self.env()
+            .events()
+            .publish((Symbol::new(self.env(), "cancel"), id), ());
+    }
+
+    fn set_min_delay(&self, delay: u64) {
+        self.env()
+            .events()
+            .publish((Symbol::new(self.env(), "set_min_delay"),), (delay,));
+    }
+}
@@ -0,0 +1,50 @@
+use crate::storage::{Operation, OperationState};
+use soroban_sdk::{Address, BytesN, Env, Symbol, Val, Vec};
+
+pub trait TimelockInterface {
+    // Initialize timelock with admin and positive minimal delay for operations.
+    // Will panic if called twice
+    fn initialize(e: Env, admin: Address, min_delay: u64);
+
+    // Queue contract function call. Only proposer
+    // delay: seconds before operation can be executed, not less than minimal delay
+    // salt: allows to queue several identical calls
+    // Call to timelock itself with `set_min_delay` function changes minimal delay.
+    // Roles and ownership calls to timelock itself are supported too, so they are delayed
+    // once timelock is its own admin.
+    // Returns operation id
+    fn schedule(
+        e: Env,
+        proposer: Address,
+        contract: Address,
+        function: Symbol,
+        args: Vec<Val>,
+        salt: BytesN<32>,
+        delay: u64,
+    ) -> BytesN<32>;
+
+    // Execute ready operation. Anyone is able to execute operation once its delay passes.
+    // Returns call result
+    fn execute(e: Env, id: BytesN<32>) -> Val;
+
+    // Cancel pending, ready or expired operation. Only proposer
+    fn cancel(e: Env, proposer: Address, id: BytesN<32>);
+
+    // Get operation id for given call
+    fn hash_operation(
+        e: Env,
+        contract: Address,
+        function: Symbol,
+        args: Vec<Val>,
+        salt: BytesN<32>,
+    ) -> BytesN<32>;
+
+    // Get queued operation
+    fn get_operation(e: Env, id: BytesN<32>) -> Operation;
+
+    // Get operation state: unset, pending, ready, done or expired
+    fn get_operation_state(e: Env, id: BytesN<32>) -> OperationState;
+
+    // Get minimal delay for operations
+    fn get_min_delay(e: Env) -> u64;
+}
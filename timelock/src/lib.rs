@@ -0,0 +1,10 @@
+#![no_std]
+
+mod constants;
+mod contract;
+mod events;
+mod interface;
+mod storage;
+mod test;
+
+pub use crate::contract::{Timelock, TimelockClient};
@@ -0,0 +1,64 @@
+use paste::paste;
+use soroban_sdk::{contracttype, Address, BytesN, Env, Symbol, Val, Vec};
+use utils::bump::{bump_instance, bump_persistent};
+use utils::{
+    generate_instance_storage_getter, generate_instance_storage_getter_and_setter,
+    generate_instance_storage_setter,
+};
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum OperationState {
+    Unset = 0,
+    Pending = 1,
+    Ready = 2,
+    Done = 3,
+    Expired = 4,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Operation {
+    pub contract: Address,
+    pub function: Symbol,
+    pub args: Vec<Val>,
+    pub salt: BytesN<32>,
+    // timestamp after which operation can be executed
+    pub eta: u64,
+    pub executed: bool,
+}
+
+#[derive(Clone)]
+#[contracttype]
+enum DataKey {
+    MinDelay,
+    Operation(BytesN<32>),
+}
+
+generate_instance_storage_getter_and_setter!(min_delay, DataKey::MinDelay, u64);
+
+pub fn has_min_delay(e: &Env) -> bool {
+    e.storage().instance().has(&DataKey::MinDelay)
+}
+
+pub fn get_operation(e: &Env, id: &BytesN<32>) -> Option<Operation> {
+    let key = DataKey::Operation(id.clone());
+    let value = e.storage().persistent().get(&key);
+    if value.is_some() {
+        bump_persistent(e, &key);
+    }
+    value
+}
+
+pub fn set_operation(e: &Env, id: &BytesN<32>, operation: &Operation) {
+    let key = DataKey::Operation(id.clone());
+    e.storage().persistent().set(&key, operation);
+    bump_persistent(e, &key);
+}
+
+pub fn remove_operation(e: &Env, id: &BytesN<32>) {
+    e.storage()
+        .persistent()
+        .remove(&DataKey::Operation(id.clone()));
+}
@@ -0,0 +1,419 @@
+#![cfg(test)]
+extern crate std;
+
+use crate::storage::OperationState;
+use crate::TimelockClient;
+use access_control::role::Role;
+use soroban_sdk::testutils::{Address as _, Events, Ledger, LedgerInfo};
+use soroban_sdk::token::Client as TokenClient;
+use soroban_sdk::{vec, Address, BytesN, Env, IntoVal, Symbol, Val, Vec};
+
+const MIN_DELAY: u64 = 2 * 86400;
+
+fn create_timelock_contract<'a>(e: &Env) -> TimelockClient<'a> {
+    TimelockClient::new(e, &e.register_contract(None, crate::Timelock {}))
+}
+
+fn jump(e: &Env, time: u64) {
+    e.ledger().set(LedgerInfo {
+        timestamp: e.ledger().timestamp().saturating_add(time),
+        protocol_version: 20,
+        sequence_number: e.ledger().sequence(),
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: 999999,
+        min_persistent_entry_ttl: 999999,
+        max_entry_ttl: u32::MAX,
+    });
+}
+
+struct Setup<'a> {
+    env: Env,
+    admin: Address,
+    proposer: Address,
+    user: Address,
+    token: Address,
+    timelock: TimelockClient<'a>,
+}
+
+impl Default for Setup<'_> {
+    fn default() -> Self {
+        let e = Env::default();
+        e.mock_all_auths();
+        jump(&e, 1_700_000_000);
+
+        let admin = Address::generate(&e);
+        let proposer = Address::generate(&e);
+        let user = Address::generate(&e);
+
+        let timelock = create_timelock_contract(&e);
+        timelock.initialize(&admin, &MIN_DELAY);
+        timelock.grant_role(&admin, &Role::Proposer, &proposer);
+
+        // timelock administrates token
+        let token = e.register_stellar_asset_contract(timelock.address.clone());
+
+        Setup {
+            env: e,
+            admin,
+            proposer,
+            user,
+            token,
+            timelock,
+        }
+    }
+}
+
+fn mint_args(e: &Env, user: &Address, amount: i128) -> Vec<Val> {
+    Vec::from_array(e, [user.into_val(e), amount.into_val(e)])
+}
+
+#[test]
+fn test_schedule_execute() {
+    let setup = Setup::default();
+    let e = setup.env;
+    let timelock = setup.timelock;
+    let salt = BytesN::from_array(&e, &[0; 32]);
+    let mint = Symbol::new(&e, "mint");
+
+    let id = timelock.schedule(
+        &setup.proposer,
+        &setup.token,
+        &mint,
+        &mint_args(&e, &setup.user, 100),
+        &salt,
+        &MIN_DELAY,
+    );
+    assert_eq!(
+        id,
+        timelock.hash_operation(&setup.token, &mint, &mint_args(&e, &setup.user, 100), &salt)
+    );
+    let eta = e.ledger().timestamp() + MIN_DELAY;
+    assert_eq!(
+        vec![&e, e.events().all().last().unwrap()],
+        vec![
+            &e,
+            (
+                timelock.address.clone(),
+                (Symbol::new(&e, "schedule"), id.clone()).into_val(&e),
+                (
+                    setup.token.clone(),
+                    mint.clone(),
+                    mint_args(&e, &setup.user, 100),
+                    eta
+                )
+                    .into_val(&e),
+            ),
+        ]
+    );
+    assert_eq!(timelock.get_operation(&id).eta, eta);
+    assert_eq!(timelock.get_operation_state(&id), OperationState::Pending);
+
+    jump(&e, MIN_DELAY);
+    assert_eq!(timelock.get_operation_state(&id), OperationState::Ready);
+    timelock.execute(&id);
+    assert_eq!(timelock.get_operation_state(&id), OperationState::Done);
+    assert_eq!(TokenClient::new(&e, &setup.token).balance(&setup.user), 100);
+
+    // same call can be queued again with different salt
+    let id2 = timelock.schedule(
+        &setup.proposer,
+        &setup.token,
+        &mint,
+        &mint_args(&e, &setup.user, 100),
+        &BytesN::from_array(&e, &[1; 32]),
+        &MIN_DELAY,
+    );
+    assert_ne!(id, id2);
+}
+
+#[test]
+#[should_panic(expected = "operation is not ready")]
+fn test_execute_too_early() {
+    let setup = Setup::default();
+    let e = setup.env;
+
+    let id = setup.timelock.schedule(
+        &setup.proposer,
+        &setup.token,
+        &Symbol::new(&e, "mint"),
+        &mint_args(&e, &setup.user, 100),
+        &BytesN::from_array(&e, &[0; 32]),
+        &MIN_DELAY,
+    );
+    jump(&e, MIN_DELAY - 1);
+    setup.timelock.execute(&id);
+}
+
+#[test]
+#[should_panic(expected = "operation already executed")]
+fn test_execute_twice() {
+    let setup = Setup::default();
+    let e = setup.env;
+
+    let id = setup.timelock.schedule(
+        &setup.proposer,
+        &setup.token,
+        &Symbol::new(&e, "mint"),
+        &mint_args(&e, &setup.user, 100),
+        &BytesN::from_array(&e, &[0; 32]),
+        &MIN_DELAY,
+    );
+    jump(&e, MIN_DELAY);
+    setup.timelock.execute(&id);
+    setup.timelock.execute(&id);
+}
+
+#[test]
+#[should_panic(expected = "operation expired")]
+fn test_execute_expired() {
+    let setup = Setup::default();
+    let e = setup.env;
+
+    let id = setup.timelock.schedule(
+        &setup.proposer,
+        &setup.token,
+        &Symbol::new(&e, "mint"),
+        &mint_args(&e, &setup.user, 100),
+        &BytesN::from_array(&e, &[0; 32]),
+        &MIN_DELAY,
+    );
+    jump(&e, MIN_DELAY + 14 * 86400 + 1);
+    assert_eq!(
+        setup.timelock.get_operation_state(&id),
+        OperationState::Expired
+    );
+    setup.timelock.execute(&id);
+}
+
+#[test]
+#[should_panic(expected = "insufficient delay")]
+fn test_schedule_insufficient_delay() {
+    let setup = Setup::default();
+    let e = setup.env;
+
+    setup.timelock.schedule(
+        &setup.proposer,
+        &setup.token,
+        &Symbol::new(&e, "mint"),
+        &mint_args(&e, &setup.user, 100),
+        &BytesN::from_array(&e, &[0; 32]),
+        &(MIN_DELAY - 1),
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #103)")]
+fn test_schedule_not_proposer() {
+    let setup = Setup::default();
+    let e = setup.env;
+
+    setup.timelock.schedule(
+        &setup.user,
+        &setup.token,
+        &Symbol::new(&e, "mint"),
+        &mint_args(&e, &setup.user, 100),
+        &BytesN::from_array(&e, &[0; 32]),
+        &MIN_DELAY,
+    );
+}
+
+#[test]
+#[should_panic(expected = "operation not found")]
+fn test_cancel() {
+    let setup = Setup::default();
+    let e = setup.env;
+    let timelock = setup.timelock;
+
+    let id = timelock.schedule(
+        &setup.proposer,
+        &setup.token,
+        &Symbol::new(&e, "mint"),
+        &mint_args(&e, &setup.user, 100),
+        &BytesN::from_array(&e, &[0; 32]),
+        &MIN_DELAY,
+    );
+    // admin has proposer role implicitly
+    timelock.cancel(&setup.admin, &id);
+    assert_eq!(
+        vec![&e, e.events().all().last().unwrap()],
+        vec![
+            &e,
+            (
+                timelock.address.clone(),
+                (Symbol::new(&e, "cancel"), id.clone()).into_val(&e),
+                ().into_val(&e),
+            ),
+        ]
+    );
+    assert_eq!(timelock.get_operation_state(&id), OperationState::Unset);
+
+    jump(&e, MIN_DELAY);
+    timelock.execute(&id);
+}
+
+#[test]
+fn test_set_min_delay() {
+    let setup = Setup::default();
+    let e = setup.env;
+    let timelock = setup.timelock;
+
+    // minimal delay is changed through timelock only
+    let id = timelock.schedule(
+        &setup.proposer,
+        &timelock.address,
+        &Symbol::new(&e, "set_min_delay"),
+        &Vec::from_array(&e, [(3 * 86400_u64).into_val(&e)]),
+        &BytesN::from_array(&e, &[0; 32]),
+        &MIN_DELAY,
+    );
+    jump(&e, MIN_DELAY);
+    timelock.execute(&id);
+    assert_eq!(timelock.get_min_delay(), 3 * 86400);
+    assert_eq!(
+        vec![&e, e.events().all().last().unwrap()],
+        vec![
+            &e,
+            (
+                timelock.address.clone(),
+                (Symbol::new(&e, "set_min_delay"),).into_val(&e),
+                (3 * 86400_u64,).into_val(&e),
+            ),
+        ]
+    );
+}
+
+#[test]
+#[should_panic(expected = "already initialized")]
+fn test_initialize_twice() {
+    let setup = Setup::default();
+    setup.timelock.initialize(&setup.user, &0);
+}
+
+#[test]
+#[should_panic(expected = "delay is out of allowed range")]
+fn test_initialize_zero_delay() {
+    let e = Env::default();
+    create_timelock_contract(&e).initialize(&Address::generate(&e), &0);
+}
+
+#[test]
+fn test_cancel_expired() {
+    let setup = Setup::default();
+    let e = setup.env;
+    let timelock = setup.timelock;
+
+    let id = timelock.schedule(
+        &setup.proposer,
+        &setup.token,
+        &Symbol::new(&e, "mint"),
+        &mint_args(&e, &setup.user, 100),
+        &BytesN::from_array(&e, &[0; 32]),
+        &MIN_DELAY,
+    );
+    jump(&e, MIN_DELAY + 14 * 86400 + 1);
+    assert_eq!(timelock.get_operation_state(&id), OperationState::Expired);
+    timelock.cancel(&setup.proposer, &id);
+    assert_eq!(timelock.get_operation_state(&id), OperationState::Unset);
+}
+
+#[test]
+fn test_self_call_roles_and_ownership() {
+    let setup = Setup::default();
+    let e = setup.env;
+    let timelock = setup.timelock;
+
+    // timelock becomes its own admin
+    timelock.commit_transfer_ownership(&setup.admin, &timelock.address);
+    jump(&e, timelock.get_transfer_delay());
+    timelock.apply_transfer_ownership(&setup.admin);
+    assert!(timelock
+        .try_grant_role(&setup.admin, &Role::Proposer, &setup.user)
+        .is_err());
+
+    // roles are changed through timelock only
+    let grant_args: Vec<Val> = Vec::from_array(
+        &e,
+        [
+            timelock.address.into_val(&e),
+            Role::Proposer.into_val(&e),
+            setup.user.into_val(&e),
+        ],
+    );
+    let id = timelock.schedule(
+        &setup.proposer,
+        &timelock.address,
+        &Symbol::new(&e, "grant_role"),
+        &grant_args,
+        &BytesN::from_array(&e, &[0; 32]),
+        &MIN_DELAY,
+    );
+    jump(&e, MIN_DELAY);
+    // executed without any authorization
+    e.set_auths(&[]);
+    timelock.execute(&id);
+    assert!(timelock.has_role(&Role::Proposer, &setup.user));
+    e.mock_all_auths();
+
+    let id = timelock.schedule(
+        &setup.user,
+        &timelock.address,
+        &Symbol::new(&e, "revoke_role"),
+        &Vec::from_array(
+            &e,
+            [
+                timelock.address.into_val(&e),
+                Role::Proposer.into_val(&e),
+                setup.proposer.into_val(&e),
+            ],
+        ),
+        &BytesN::from_array(&e, &[0; 32]),
+        &MIN_DELAY,
+    );
+    jump(&e, MIN_DELAY);
+    timelock.execute(&id);
+    assert!(!timelock.has_role(&Role::Proposer, &setup.proposer));
+
+    // ownership is handed back through timelock as well
+    let id = timelock.schedule(
+        &setup.user,
+        &timelock.address,
+        &Symbol::new(&e, "commit_transfer_ownership"),
+        &Vec::from_array(
+            &e,
+            [timelock.address.into_val(&e), setup.admin.into_val(&e)],
+        ),
+        &BytesN::from_array(&e, &[0; 32]),
+        &MIN_DELAY,
+    );
+    jump(&e, MIN_DELAY);
+    timelock.execute(&id);
+    assert_eq!(timelock.get_future_admin(), Some(setup.admin.clone()));
+}
+
+#[test]
+#[should_panic(expected = "timelock is not the admin")]
+fn test_self_call_not_admin() {
+    let setup = Setup::default();
+    let e = setup.env;
+    let timelock = setup.timelock;
+
+    // proposer can't act as external admin
+    let id = timelock.schedule(
+        &setup.proposer,
+        &timelock.address,
+        &Symbol::new(&e, "grant_role"),
+        &Vec::from_array(
+            &e,
+            [
+                setup.admin.into_val(&e),
+                Role::Proposer.into_val(&e),
+                setup.user.into_val(&e),
+            ],
+        ),
+        &BytesN::from_array(&e, &[0; 32]),
+        &MIN_DELAY,
+    );
+    jump(&e, MIN_DELAY);
+    timelock.execute(&id);
+}
@@ -0,0 +1,61 @@
+use crate::balance::read_balance;
+use access_control::access::{AccessControl, AccessControlTrait};
+use soroban_sdk::{contracttype, Address, Env, IntoVal, Symbol, Vec};
+use utils::bump::bump_instance;
+
+#[derive(Clone)]
+#[contracttype]
+enum DataKey {
+    RewardsCheckpoint,
+}
+
+pub fn read_rewards_checkpoint(e: &Env) -> bool {
+    e.storage()
+        .instance()
+        .get(&DataKey::RewardsCheckpoint)
+        .unwrap_or(false)
+}
+
+pub fn write_rewards_checkpoint(e: &Env, enabled: bool) {
+    bump_instance(e);
+    e.storage()
+        .instance()
+        .set(&DataKey::RewardsCheckpoint, &enabled)
+}
+
+fn checkpoint_reward(e: &Env, pool: &Address, user: &Address, before: i128, after: i128) {
+    e.invoke_contract::<()>(
+        pool,
+        &Symbol::new(e, "checkpoint_reward"),
+        Vec::from_array(
+            e,
+            [
+                user.into_val(e),
+                (before as u128).into_val(e),
+                (after as u128).into_val(e),
+            ],
+        ),
+    );
+}
+
+// notify pool (token admin) about upcoming balances change so it's able to checkpoint rewards.
+// movements involving pool itself are initiated by the pool which handles rewards on its own
+pub fn checkpoint_balances(e: &Env, from: &Address, to: Option<&Address>, amount: i128) {
+    if !read_rewards_checkpoint(e) || to == Some(from) {
+        return;
+    }
+    let pool = AccessControl::new(e).get_admin().unwrap();
+    if from == &pool || to == Some(&pool) {
+        return;
+    }
+
+    let from_balance = read_balance(e, from.clone());
+    if from_balance < amount {
+        panic!("insufficient balance");
+    }
+    checkpoint_reward(e, &pool, from, from_balance, from_balance - amount);
+    if let Some(to) = to {
+        let to_balance = read_balance(e, to.clone());
+        checkpoint_reward(e, &pool, to, to_balance, to_balance + amount);
+    }
+}
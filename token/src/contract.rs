@@ -1,6 +1,7 @@
 //! Implementation of the Soroban token interface.
 use crate::allowance::{read_allowance, spend_allowance, write_allowance};
 use crate::balance::{read_balance, receive_balance, spend_balance};
+use crate::checkpoint::{checkpoint_balances, read_rewards_checkpoint, write_rewards_checkpoint};
 use crate::metadata::{read_decimal, read_name, read_symbol, write_metadata};
 use access_control::access::{AccessControl, AccessControlTrait};
 use soroban_sdk::token::{self, Interface as _};
@@ -52,6 +53,20 @@ impl Token {
         TokenUtils::new(&e).events().mint(admin, to, amount);
     }
 
+    // Make token notify its admin pool about every user balance change,
+    // so pool rewards are checkpointed on share transfers
+    pub fn set_rewards_checkpoint(e: Env, enabled: bool) {
+        let access_control = AccessControl::new(&e);
+        let admin = access_control.get_admin().unwrap();
+        admin.require_auth();
+
+        write_rewards_checkpoint(&e, enabled);
+    }
+
+    pub fn get_rewards_checkpoint(e: Env) -> bool {
+        read_rewards_checkpoint(&e)
+    }
+
     pub fn set_admin(e: Env, new_admin: Address) {
         let access_control = AccessControl::new(&e);
         let admin = access_control.get_admin().unwrap();
@@ -96,6 +111,7 @@ impl token::Interface for Token {
 
         bump_instance(&e);
 
+        checkpoint_balances(&e, &from, Some(&to), amount);
         spend_balance(&e, from.clone(), amount);
         receive_balance(&e, to.clone(), amount);
         TokenUtils::new(&e).events().transfer(from, to, amount);
@@ -109,6 +125,7 @@ impl token::Interface for Token {
         bump_instance(&e);
 
         spend_allowance(&e, from.clone(), spender, amount);
+        checkpoint_balances(&e, &from, Some(&to), amount);
         spend_balance(&e, from.clone(), amount);
         receive_balance(&e, to.clone(), amount);
         TokenUtils::new(&e).events().transfer(from, to, amount)
@@ -121,6 +138,7 @@ impl token::Interface for Token {
 
         bump_instance(&e);
 
+        checkpoint_balances(&e, &from, None, amount);
         spend_balance(&e, from.clone(), amount);
         TokenUtils::new(&e).events().burn(from, amount);
     }
@@ -133,6 +151,7 @@ impl token::Interface for Token {
         bump_instance(&e);
 
         spend_allowance(&e, from.clone(), spender, amount);
+        checkpoint_balances(&e, &from, None, amount);
         spend_balance(&e, from.clone(), amount);
         TokenUtils::new(&e).events().burn(from, amount)
     }
@@ -3,6 +3,7 @@
 
 mod allowance;
 mod balance;
+mod checkpoint;
 mod contract;
 mod metadata;
 mod test;